@@ -0,0 +1,767 @@
+use std::{collections::HashMap, sync::Arc};
+
+use crate::{
+    functions::{calc::unbound_wildcard, differentiation::wildcard_derivative},
+    gamma_function, polygamma_function,
+    utils::rational::Number,
+    Condition, Constant,
+    Elementary::{self, *},
+    Factorial, Rational, Relation,
+};
+
+/// The identifier of an expression that has been interned in an
+/// [ExpressionArena](crate::ExpressionArena).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NodeId(pub(crate) usize);
+
+// the kind of a node, that is, an Elementary variant without its children
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Op {
+    Sin,
+    Cos,
+    Tan,
+    Sec,
+    Csc,
+    Cot,
+    Asin,
+    Acos,
+    Atan,
+    Acot,
+    Asec,
+    Acsc,
+    Sinh,
+    Cosh,
+    Tanh,
+    Sech,
+    Csch,
+    Coth,
+    Asinh,
+    Acosh,
+    Atanh,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Log,
+    Factorial,
+    Gamma,
+    Polygamma(usize),
+    Abs,
+    Cbrt,
+    Floor,
+    Ceil,
+    // the children are the sides of each condition followed by the value of its branch, and
+    // finally the value otherwise
+    Piecewise(Vec<Relation>),
+    // floats are stored by their bits so that the nodes can be hashed
+    Con(u64),
+    Rat(Rational),
+    Sym(Constant),
+    X,
+    Wildcard(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct Node {
+    pub(crate) op: Op,
+    pub(crate) children: Vec<NodeId>,
+}
+
+/// An arena of hash-consed expressions, where identical subexpressions share a single node.
+///
+/// Expressions are stored as a directed acyclic graph rather than as a tree, which keeps repeated
+/// differentiation from growing exponentially: the derivative of every node is computed once
+/// and reused wherever the node appears. Likewise, [evaluate()](crate::ExpressionArena::evaluate)
+/// computes the value of every distinct subexpression only once.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Elementary, ExpressionArena};
+/// let mut arena = ExpressionArena::new();
+/// let function = arena.intern(&Elementary::from("sin(x)^2 + sin(x)"));
+///
+/// // sin(x) is only stored once
+/// assert_eq!(arena.size(function), 5);
+///
+/// let derivative = arena.derivative(function);
+/// assert_eq!(arena.evaluate(derivative, 0.), 1.);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ExpressionArena {
+    nodes: Vec<Node>,
+    ids: HashMap<Node, NodeId>,
+    // whether the node is constant, that is, doesn't depend on x
+    constant: Vec<bool>,
+    derivatives: HashMap<NodeId, NodeId>,
+}
+
+impl ExpressionArena {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of distinct nodes in the arena.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    // the nodes in topological order, that is, every node comes after its children
+    pub(crate) fn nodes(&self) -> &[Node] {
+        &self.nodes
+    }
+
+    /// The number of distinct nodes that the expression consists of.
+    pub fn size(&self, id: NodeId) -> usize {
+        let mut visited = vec![false; self.nodes.len()];
+        let mut stack = vec![id];
+        let mut size = 0;
+        while let Some(id) = stack.pop() {
+            if !visited[id.0] {
+                visited[id.0] = true;
+                size += 1;
+                stack.extend(&self.nodes[id.0].children);
+            }
+        }
+        size
+    }
+
+    /// Adds the expression to the arena, returning the identifier of its root node.
+    pub fn intern(&mut self, function: &Elementary) -> NodeId {
+        // subtrees that share their Arc (such as those returned by to_elementary()) are only
+        // traversed once
+        let mut interned = HashMap::new();
+        self.intern_shared(function, &mut interned)
+    }
+
+    fn intern_shared(
+        &mut self,
+        function: &Elementary,
+        interned: &mut HashMap<*const Elementary, NodeId>,
+    ) -> NodeId {
+        if let Some(id) = interned.get(&(function as *const Elementary)) {
+            return *id;
+        }
+        let children = function
+            .children()
+            .into_iter()
+            .map(|child| self.intern_shared(child, interned))
+            .collect();
+        let id = self.insert(Node {
+            op: op_of(function),
+            children,
+        });
+        interned.insert(function as *const Elementary, id);
+        id
+    }
+
+    /// Converts the node back into an [Elementary](crate::Elementary), where shared nodes are
+    /// represented by shared Arcs.
+    pub fn to_elementary(&self, id: NodeId) -> Elementary {
+        let mut built: HashMap<NodeId, Arc<Elementary>> = HashMap::new();
+        (*self.build(id, &mut built)).clone()
+    }
+
+    fn build(&self, id: NodeId, built: &mut HashMap<NodeId, Arc<Elementary>>) -> Arc<Elementary> {
+        if let Some(function) = built.get(&id) {
+            return function.clone();
+        }
+        let node = &self.nodes[id.0];
+        let children: Vec<Arc<Elementary>> = node
+            .children
+            .iter()
+            .map(|child| self.build(*child, built))
+            .collect();
+        let function = Arc::new(build_elementary(&node.op, children));
+        built.insert(id, function.clone());
+        function
+    }
+
+    /// Evaluates the expression at x, where every distinct subexpression is only evaluated once.
+    pub fn evaluate(&self, id: NodeId, x: f64) -> f64 {
+        let mut values: Vec<Option<f64>> = vec![None; self.nodes.len()];
+        self.evaluate_shared(id, x, &mut values)
+    }
+
+    fn evaluate_shared(&self, id: NodeId, x: f64, values: &mut Vec<Option<f64>>) -> f64 {
+        if let Some(value) = values[id.0] {
+            return value;
+        }
+        let node = &self.nodes[id.0];
+        let arguments: Vec<f64> = node
+            .children
+            .iter()
+            .map(|child| self.evaluate_shared(*child, x, values))
+            .collect();
+        let value = apply(&node.op, &arguments, x);
+        values[id.0] = Some(value);
+        value
+    }
+
+    /// Returns the derivative of the expression. The derivatives of all subexpressions are
+    /// memoized, so that differentiating a node that has been differentiated before is free.
+    pub fn derivative(&mut self, id: NodeId) -> NodeId {
+        if let Some(derivative) = self.derivatives.get(&id) {
+            return *derivative;
+        }
+        let derivative = self.differentiate(id);
+        self.derivatives.insert(id, derivative);
+        derivative
+    }
+
+    fn differentiate(&mut self, id: NodeId) -> NodeId {
+        if self.constant[id.0] {
+            return self.con(0.);
+        }
+        let node = self.nodes[id.0].clone();
+        let child = |index: usize| node.children[index];
+
+        match node.op {
+            Op::X => self.con(1.),
+            // patterns have no derivative
+            Op::Wildcard(name) => panic!("{}", wildcard_derivative(&name)),
+            Op::Con(_) | Op::Rat(_) | Op::Sym(_) => self.con(0.),
+
+            Op::Add | Op::Sub => {
+                let derivative1 = self.derivative(child(0));
+                let derivative2 = self.derivative(child(1));
+                if node.op == Op::Add {
+                    self.add(derivative1, derivative2)
+                } else {
+                    self.sub(derivative1, derivative2)
+                }
+            } // f'(x) ± g'(x)
+            Op::Mul => {
+                let (func1, func2) = (child(0), child(1));
+                let derivative1 = self.derivative(func1);
+                let derivative2 = self.derivative(func2);
+                let term1 = self.mul(derivative1, func2);
+                let term2 = self.mul(derivative2, func1);
+                self.add(term1, term2)
+            } // f'(x)g(x) + g'(x)f(x)
+            Op::Div => {
+                let (func1, func2) = (child(0), child(1));
+                let derivative1 = self.derivative(func1);
+                let derivative2 = self.derivative(func2);
+                let term1 = self.mul(derivative1, func2);
+                let term2 = self.mul(derivative2, func1);
+                let numerator = self.sub(term1, term2);
+                let two = self.con(2.);
+                let denominator = self.pow(func2, two);
+                self.div(numerator, denominator)
+            } // (f'(x)g(x) - g'(x)f(x)) / g(x)^2
+            Op::Pow if self.constant[child(1).0] => {
+                let (base, exp) = (child(0), child(1));
+                let one = self.con(1.);
+                let lowered = self.sub(exp, one);
+                let power = self.pow(base, lowered);
+                let derivative = self.derivative(base);
+                let product = self.mul(exp, power);
+                self.mul(product, derivative)
+            } // g * f(x)^(g - 1) * f'(x)
+            Op::Pow => {
+                let (base, exp) = (child(0), child(1));
+                let ln = self.ln(base);
+                let base_derivative = self.derivative(base);
+                let exp_derivative = self.derivative(exp);
+                // f^g * (g' ln(f) + g f'/f)
+                let term1 = self.mul(exp_derivative, ln);
+                let quotient = self.div(base_derivative, base);
+                let term2 = self.mul(exp, quotient);
+                let sum = self.add(term1, term2);
+                self.mul(id, sum)
+            }
+            Op::Log => {
+                // log_b(f) = ln(f) / ln(b), where ln(f)' = f'/f
+                let (base, arg) = (child(0), child(1));
+                let arg_derivative = self.derivative(arg);
+                let arg_term = self.div(arg_derivative, arg);
+                let ln_base = self.ln(base);
+                if self.constant[base.0] {
+                    return self.div(arg_term, ln_base);
+                }
+                // (f'/f ln(b) - ln(f) b'/b) / ln(b)^2
+                let base_derivative = self.derivative(base);
+                let base_term = self.div(base_derivative, base);
+                let ln_arg = self.ln(arg);
+                let term1 = self.mul(arg_term, ln_base);
+                let term2 = self.mul(ln_arg, base_term);
+                let numerator = self.sub(term1, term2);
+                let two = self.con(2.);
+                let denominator = self.pow(ln_base, two);
+                self.div(numerator, denominator)
+            }
+
+            Op::Sin => self.chain(child(0), |arena, func| arena.unary(Op::Cos, func)),
+            Op::Cos => self.chain(child(0), |arena, func| {
+                let sin = arena.unary(Op::Sin, func);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, sin)
+            }),
+            Op::Tan => self.chain(child(0), |arena, func| {
+                let cos = arena.unary(Op::Cos, func);
+                let two = arena.con(2.);
+                let square = arena.pow(cos, two);
+                let one = arena.con(1.);
+                arena.div(one, square)
+            }),
+            Op::Sec => self.chain(child(0), |arena, func| {
+                let tan = arena.unary(Op::Tan, func);
+                let sec = arena.unary(Op::Sec, func);
+                arena.mul(tan, sec)
+            }),
+            Op::Csc => self.chain(child(0), |arena, func| {
+                let cot = arena.unary(Op::Cot, func);
+                let csc = arena.unary(Op::Csc, func);
+                let product = arena.mul(cot, csc);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, product)
+            }),
+            Op::Cot => self.chain(child(0), |arena, func| {
+                let csc = arena.unary(Op::Csc, func);
+                let two = arena.con(2.);
+                let square = arena.pow(csc, two);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, square)
+            }),
+            Op::Asin | Op::Acos => {
+                let sign = if node.op == Op::Asin { 1. } else { -1. };
+                self.chain(child(0), |arena, func| {
+                    // ±1 / (1 - f^2)^(1/2)
+                    let two = arena.con(2.);
+                    let square = arena.pow(func, two);
+                    let one = arena.con(1.);
+                    let difference = arena.sub(one, square);
+                    let half = arena.insert_leaf(Op::Rat(Rational::HALF));
+                    let root = arena.pow(difference, half);
+                    let sign = arena.con(sign);
+                    arena.div(sign, root)
+                })
+            }
+            Op::Atan => self.chain(child(0), |arena, func| {
+                let two = arena.con(2.);
+                let square = arena.pow(func, two);
+                let one = arena.con(1.);
+                let sum = arena.add(square, one);
+                arena.div(one, sum)
+            }),
+            Op::Acot => self.chain(child(0), |arena, func| {
+                let two = arena.con(2.);
+                let square = arena.pow(func, two);
+                let one = arena.con(1.);
+                let sum = arena.add(square, one);
+                let minus_one = arena.con(-1.);
+                arena.div(minus_one, sum)
+            }),
+            Op::Asec | Op::Acsc => {
+                let sign = if node.op == Op::Asec { 1. } else { -1. };
+                self.chain(child(0), |arena, func| {
+                    // ±1 / (|f| (f^2 - 1)^(1/2))
+                    let two = arena.con(2.);
+                    let square = arena.pow(func, two);
+                    let one = arena.con(1.);
+                    let difference = arena.sub(square, one);
+                    let half = arena.insert_leaf(Op::Rat(Rational::HALF));
+                    let root = arena.pow(difference, half);
+                    let abs = arena.unary(Op::Abs, func);
+                    let denominator = arena.mul(abs, root);
+                    let sign = arena.con(sign);
+                    arena.div(sign, denominator)
+                })
+            }
+            Op::Sinh => self.chain(child(0), |arena, func| arena.unary(Op::Cosh, func)),
+            Op::Cosh => self.chain(child(0), |arena, func| arena.unary(Op::Sinh, func)),
+            Op::Tanh => self.chain(child(0), |arena, func| {
+                let cosh = arena.unary(Op::Cosh, func);
+                let two = arena.con(2.);
+                let square = arena.pow(cosh, two);
+                let one = arena.con(1.);
+                arena.div(one, square)
+            }),
+            Op::Sech => self.chain(child(0), |arena, func| {
+                let tanh = arena.unary(Op::Tanh, func);
+                let product = arena.mul(tanh, id);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, product)
+            }),
+            Op::Csch => self.chain(child(0), |arena, func| {
+                let coth = arena.unary(Op::Coth, func);
+                let product = arena.mul(coth, id);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, product)
+            }),
+            Op::Coth => self.chain(child(0), |arena, func| {
+                let csch = arena.unary(Op::Csch, func);
+                let two = arena.con(2.);
+                let square = arena.pow(csch, two);
+                let minus_one = arena.con(-1.);
+                arena.mul(minus_one, square)
+            }),
+            Op::Asinh | Op::Acosh => {
+                let shift = if node.op == Op::Asinh { 1. } else { -1. };
+                self.chain(child(0), |arena, func| {
+                    // 1 / (f^2 ± 1)^(1/2)
+                    let two = arena.con(2.);
+                    let square = arena.pow(func, two);
+                    let shift = arena.con(shift);
+                    let sum = arena.add(square, shift);
+                    let half = arena.insert_leaf(Op::Rat(Rational::HALF));
+                    let root = arena.pow(sum, half);
+                    let one = arena.con(1.);
+                    arena.div(one, root)
+                })
+            }
+            Op::Atanh => self.chain(child(0), |arena, func| {
+                let two = arena.con(2.);
+                let square = arena.pow(func, two);
+                let one = arena.con(1.);
+                let difference = arena.sub(one, square);
+                arena.div(one, difference)
+            }),
+
+            // f(x)! * 𝝍0(f(x) + 1) * f'(x)
+            Op::Factorial => self.chain(child(0), |arena, func| {
+                let one = arena.con(1.);
+                let shifted = arena.add(func, one);
+                let polygamma = arena.unary(Op::Polygamma(0), shifted);
+                arena.mul(id, polygamma)
+            }),
+            // 𝜞(f(x)) * 𝝍0(f(x)) * f'(x)
+            Op::Gamma => self.chain(child(0), |arena, func| {
+                let polygamma = arena.unary(Op::Polygamma(0), func);
+                arena.mul(id, polygamma)
+            }),
+            // 𝝍(m + 1)(f(x)) * f'(x)
+            Op::Polygamma(order) => self.chain(child(0), |arena, func| {
+                arena.unary(Op::Polygamma(order + 1), func)
+            }),
+            // sign(f(x)) * f'(x)
+            Op::Abs => self.chain(child(0), |arena, func| {
+                let (zero, minus_one, one) = (arena.con(0.), arena.con(-1.), arena.con(1.));
+                arena.insert(Node {
+                    op: Op::Piecewise(vec![Relation::Less, Relation::Greater]),
+                    children: vec![func, zero, minus_one, func, zero, one, zero],
+                })
+            }),
+            // 1 / (3 cbrt(f(x))^2) * f'(x)
+            Op::Cbrt => self.chain(child(0), |arena, _| {
+                let two = arena.con(2.);
+                let square = arena.pow(id, two);
+                let three = arena.con(3.);
+                let product = arena.mul(three, square);
+                let one = arena.con(1.);
+                arena.div(one, product)
+            }),
+            // the rounding functions are constant between their jumps
+            Op::Floor | Op::Ceil => self.con(0.),
+            // the conditions are kept while the value of each branch is differentiated
+            Op::Piecewise(_) => {
+                let mut children = Vec::with_capacity(node.children.len());
+                for (index, func) in node.children.iter().enumerate() {
+                    if index % 3 == 2 || index == node.children.len() - 1 {
+                        children.push(self.derivative(*func));
+                    } else {
+                        children.push(*func);
+                    }
+                }
+                self.insert(Node {
+                    op: node.op.clone(),
+                    children,
+                })
+            }
+        }
+    }
+
+    // the chain rule: outer'(f(x)) * f'(x)
+    fn chain(&mut self, func: NodeId, outer: impl Fn(&mut Self, NodeId) -> NodeId) -> NodeId {
+        let outer_derivative = outer(self, func);
+        let inner_derivative = self.derivative(func);
+        self.mul(outer_derivative, inner_derivative)
+    }
+
+    fn insert(&mut self, node: Node) -> NodeId {
+        if let Some(id) = self.ids.get(&node) {
+            return *id;
+        }
+        let id = NodeId(self.nodes.len());
+        let constant = match node.op {
+            Op::X | Op::Wildcard(_) => false,
+            _ => node.children.iter().all(|child| self.constant[child.0]),
+        };
+        self.nodes.push(node.clone());
+        self.constant.push(constant);
+        self.ids.insert(node, id);
+        id
+    }
+
+    fn insert_leaf(&mut self, op: Op) -> NodeId {
+        self.insert(Node {
+            op,
+            children: Vec::new(),
+        })
+    }
+
+    fn unary(&mut self, op: Op, func: NodeId) -> NodeId {
+        self.insert(Node {
+            op,
+            children: vec![func],
+        })
+    }
+
+    fn binary(&mut self, op: Op, func1: NodeId, func2: NodeId) -> NodeId {
+        self.insert(Node {
+            op,
+            children: vec![func1, func2],
+        })
+    }
+
+    fn con(&mut self, value: f64) -> NodeId {
+        self.insert_leaf(op_of(&Con(value)))
+    }
+
+    fn number(&mut self, number: Number) -> NodeId {
+        self.insert_leaf(op_of(&Elementary::from_number(number)))
+    }
+
+    fn ln(&mut self, func: NodeId) -> NodeId {
+        let e = self.insert_leaf(Op::Sym(Constant::E));
+        self.binary(Op::Log, e, func)
+    }
+
+    // the value of the node if it is a number
+    fn as_number(&self, id: NodeId) -> Option<Number> {
+        match self.nodes[id.0].op {
+            Op::Con(bits) => Some(Number::from(f64::from_bits(bits))),
+            Op::Rat(rational) => Some(Number::Exact(rational)),
+            _ => None,
+        }
+    }
+
+    // The arithmetic below folds numbers and gets rid of additions of 0 as well as multiplications
+    // by 0 and 1, which would otherwise make up most of the nodes of higher derivatives
+
+    fn add(&mut self, func1: NodeId, func2: NodeId) -> NodeId {
+        match (self.as_number(func1), self.as_number(func2)) {
+            (Some(numb1), Some(numb2)) => self.number(numb1 + numb2),
+            (Some(numb), _) if numb.is_zero() => func2,
+            (_, Some(numb)) if numb.is_zero() => func1,
+            _ => self.binary(Op::Add, func1, func2),
+        }
+    }
+
+    fn sub(&mut self, func1: NodeId, func2: NodeId) -> NodeId {
+        match (self.as_number(func1), self.as_number(func2)) {
+            (Some(numb1), Some(numb2)) => self.number(numb1 - numb2),
+            (_, Some(numb)) if numb.is_zero() => func1,
+            _ if func1 == func2 => self.con(0.),
+            _ => self.binary(Op::Sub, func1, func2),
+        }
+    }
+
+    fn mul(&mut self, func1: NodeId, func2: NodeId) -> NodeId {
+        match (self.as_number(func1), self.as_number(func2)) {
+            (Some(numb1), Some(numb2)) => self.number(numb1 * numb2),
+            (Some(numb), _) | (_, Some(numb)) if numb.is_zero() => self.con(0.),
+            (Some(numb), _) if numb.is_one() => func2,
+            (_, Some(numb)) if numb.is_one() => func1,
+            _ => self.binary(Op::Mul, func1, func2),
+        }
+    }
+
+    fn div(&mut self, func1: NodeId, func2: NodeId) -> NodeId {
+        match (self.as_number(func1), self.as_number(func2)) {
+            (Some(numb1), Some(numb2)) if !numb2.is_zero() => self.number(numb1 / numb2),
+            (_, Some(numb)) if numb.is_one() => func1,
+            _ => self.binary(Op::Div, func1, func2),
+        }
+    }
+
+    fn pow(&mut self, base: NodeId, exp: NodeId) -> NodeId {
+        match self.as_number(exp) {
+            Some(numb) if numb.is_zero() => self.con(1.),
+            Some(numb) if numb.is_one() => base,
+            _ => self.binary(Op::Pow, base, exp),
+        }
+    }
+}
+
+impl Elementary {
+    /// Returns the number of distinct subexpressions of the function, where subexpressions that
+    /// occur several times are only counted once.
+    pub fn dag_size(&self) -> usize {
+        let mut arena = ExpressionArena::new();
+        let id = arena.intern(self);
+        arena.size(id)
+    }
+}
+
+fn op_of(function: &Elementary) -> Op {
+    match function {
+        Sin(_) => Op::Sin,
+        Cos(_) => Op::Cos,
+        Tan(_) => Op::Tan,
+        Sec(_) => Op::Sec,
+        Csc(_) => Op::Csc,
+        Cot(_) => Op::Cot,
+        Asin(_) => Op::Asin,
+        Acos(_) => Op::Acos,
+        Atan(_) => Op::Atan,
+        Acot(_) => Op::Acot,
+        Asec(_) => Op::Asec,
+        Acsc(_) => Op::Acsc,
+        Sinh(_) => Op::Sinh,
+        Cosh(_) => Op::Cosh,
+        Tanh(_) => Op::Tanh,
+        Sech(_) => Op::Sech,
+        Csch(_) => Op::Csch,
+        Coth(_) => Op::Coth,
+        Asinh(_) => Op::Asinh,
+        Acosh(_) => Op::Acosh,
+        Atanh(_) => Op::Atanh,
+        Add(_, _) => Op::Add,
+        Sub(_, _) => Op::Sub,
+        Mul(_, _) => Op::Mul,
+        Div(_, _) => Op::Div,
+        Pow(_, _) => Op::Pow,
+        Log(_, _) => Op::Log,
+        Factorial(_) => Op::Factorial,
+        Gamma(_) => Op::Gamma,
+        Polygamma(_, order) => Op::Polygamma(*order),
+        Abs(_) => Op::Abs,
+        Cbrt(_) => Op::Cbrt,
+        Floor(_) => Op::Floor,
+        Ceil(_) => Op::Ceil,
+        Piecewise(branches, _) => Op::Piecewise(
+            branches
+                .iter()
+                .map(|(condition, _)| condition.relation)
+                .collect(),
+        ),
+        // -0 and 0 are the same node
+        Con(numb) => Op::Con((numb + 0.).to_bits()),
+        Rat(rational) => Op::Rat(*rational),
+        Sym(constant) => Op::Sym(*constant),
+        X => Op::X,
+        Wildcard(name) => Op::Wildcard(name.clone()),
+    }
+}
+
+fn build_elementary(op: &Op, mut children: Vec<Arc<Elementary>>) -> Elementary {
+    let mut child = || children.remove(0);
+    match op {
+        Op::Sin => Sin(child()),
+        Op::Cos => Cos(child()),
+        Op::Tan => Tan(child()),
+        Op::Sec => Sec(child()),
+        Op::Csc => Csc(child()),
+        Op::Cot => Cot(child()),
+        Op::Asin => Asin(child()),
+        Op::Acos => Acos(child()),
+        Op::Atan => Atan(child()),
+        Op::Acot => Acot(child()),
+        Op::Asec => Asec(child()),
+        Op::Acsc => Acsc(child()),
+        Op::Sinh => Sinh(child()),
+        Op::Cosh => Cosh(child()),
+        Op::Tanh => Tanh(child()),
+        Op::Sech => Sech(child()),
+        Op::Csch => Csch(child()),
+        Op::Coth => Coth(child()),
+        Op::Asinh => Asinh(child()),
+        Op::Acosh => Acosh(child()),
+        Op::Atanh => Atanh(child()),
+        Op::Add => Add(child(), child()),
+        Op::Sub => Sub(child(), child()),
+        Op::Mul => Mul(child(), child()),
+        Op::Div => Div(child(), child()),
+        Op::Pow => Pow(child(), child()),
+        Op::Log => Log(child(), child()),
+        Op::Factorial => Factorial(child()),
+        Op::Gamma => Gamma(child()),
+        Op::Polygamma(order) => Polygamma(child(), *order),
+        Op::Abs => Abs(child()),
+        Op::Cbrt => Cbrt(child()),
+        Op::Floor => Floor(child()),
+        Op::Ceil => Ceil(child()),
+        Op::Piecewise(relations) => {
+            let branches = relations
+                .iter()
+                .map(|relation| {
+                    let (lhs, rhs) = (child(), child());
+                    let condition = Condition {
+                        lhs,
+                        relation: *relation,
+                        rhs,
+                    };
+                    (condition, child())
+                })
+                .collect();
+            Piecewise(branches, child())
+        }
+        Op::Con(bits) => Con(f64::from_bits(*bits)),
+        Op::Rat(rational) => Rat(*rational),
+        Op::Sym(constant) => Sym(*constant),
+        Op::X => X,
+        Op::Wildcard(name) => Wildcard(name.clone()),
+    }
+}
+
+// evaluates the node given the values of its children, in the same way as Elementary::call()
+pub(crate) fn apply(op: &Op, arguments: &[f64], x: f64) -> f64 {
+    let argument = |index: usize| arguments[index];
+    match op {
+        Op::Sin => argument(0).sin(),
+        Op::Cos => argument(0).cos(),
+        Op::Tan => argument(0).tan(),
+        Op::Sec => 1. / argument(0).cos(),
+        Op::Csc => 1. / argument(0).sin(),
+        Op::Cot => 1. / argument(0).tan(),
+        Op::Asin => argument(0).asin(),
+        Op::Acos => argument(0).acos(),
+        Op::Atan => argument(0).atan(),
+        Op::Acot => (1. / argument(0)).atan(),
+        Op::Asec => (1. / argument(0)).acos(),
+        Op::Acsc => (1. / argument(0)).asin(),
+        Op::Sinh => argument(0).sinh(),
+        Op::Cosh => argument(0).cosh(),
+        Op::Tanh => argument(0).tanh(),
+        Op::Sech => 1. / argument(0).cosh(),
+        Op::Csch => 1. / argument(0).sinh(),
+        Op::Coth => 1. / argument(0).tanh(),
+        Op::Asinh => argument(0).asinh(),
+        Op::Acosh => argument(0).acosh(),
+        Op::Atanh => argument(0).atanh(),
+        Op::Add => argument(0) + argument(1),
+        Op::Sub => argument(0) - argument(1),
+        Op::Mul => argument(0) * argument(1),
+        Op::Div => argument(0) / argument(1),
+        Op::Pow => argument(0).powf(argument(1)),
+        Op::Log => argument(1).log(argument(0)),
+        Op::Factorial => argument(0).factorial(),
+        Op::Gamma => gamma_function(argument(0)),
+        Op::Polygamma(order) => polygamma_function(argument(0), *order),
+        Op::Abs => argument(0).abs(),
+        Op::Cbrt => argument(0).cbrt(),
+        Op::Floor => argument(0).floor(),
+        Op::Ceil => argument(0).ceil(),
+        Op::Piecewise(relations) => argument(selected_branch(relations, arguments)),
+        Op::Con(bits) => f64::from_bits(*bits),
+        Op::Rat(rational) => rational.to_f64(),
+        Op::Sym(constant) => constant.value(),
+        Op::X => x,
+        Op::Wildcard(name) => panic!("{}", unbound_wildcard(name)),
+    }
+}
+
+// the index of the argument holding the value of the branch of a piecewise node that applies
+pub(crate) fn selected_branch(relations: &[Relation], arguments: &[f64]) -> usize {
+    relations
+        .iter()
+        .enumerate()
+        .find(|(index, relation)| relation.holds(arguments[3 * index], arguments[3 * index + 1]))
+        .map_or(arguments.len() - 1, |(index, _)| 3 * index + 2)
+}
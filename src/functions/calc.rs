@@ -1,5 +1,7 @@
-use crate::{gamma_function, polygamma_function, Elementary::*, Factorial};
-use std::{f64::consts::E, sync::Arc};
+use crate::{
+    gamma_function, polygamma_function, Condition, Constant, Elementary::*, Factorial, Rational,
+};
+use std::{f64::consts::E, mem::discriminant, sync::Arc};
 
 use crate::{Error, Func};
 
@@ -10,7 +12,7 @@ fn f() -> Func {
     Box::new(move |x| x)
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub enum Elementary {
     // Standard trig functions
     Sin(Arc<Elementary>), // of the type sin(f(x))
@@ -26,11 +28,25 @@ pub enum Elementary {
     Acos(Arc<Elementary>), // of the type arccos(f(x))
     Atan(Arc<Elementary>), // of the type arctan(f(x))
 
+    // reciprocal arcus functions
+    Acot(Arc<Elementary>), // of the type arccot(f(x)), taking values in (-π/2, π/2]
+    Asec(Arc<Elementary>), // of the type arcsec(f(x))
+    Acsc(Arc<Elementary>), // of the type arccsc(f(x))
+
     // hyperbolic trig functions
     Sinh(Arc<Elementary>), // of the type sinh(f(x))
     Cosh(Arc<Elementary>), // of the type cosh(f(x))
     Tanh(Arc<Elementary>), // of the type tanh(f(x))
 
+    Sech(Arc<Elementary>), // of the type sech(f(x))
+    Csch(Arc<Elementary>), // of the type csch(f(x))
+    Coth(Arc<Elementary>), // of the type coth(f(x))
+
+    // inverse hyperbolic functions
+    Asinh(Arc<Elementary>), // of the type arsinh(f(x))
+    Acosh(Arc<Elementary>), // of the type arcosh(f(x))
+    Atanh(Arc<Elementary>), // of the type artanh(f(x))
+
     // Standard operations
     Add(Arc<Elementary>, Arc<Elementary>), // of the type f(x) + g(x)
     Sub(Arc<Elementary>, Arc<Elementary>), // of the type f(x) - g(x)
@@ -47,12 +63,59 @@ pub enum Elementary {
 
     // Absolute value function
     Abs(Arc<Elementary>),
+
+    // real cube root, which unlike f(x)^(1/3) is defined for negative values
+    Cbrt(Arc<Elementary>), // of the type ∛f(x)
+
+    // rounding functions
+    Floor(Arc<Elementary>), // of the type ⌊f(x)⌋
+    Ceil(Arc<Elementary>),  // of the type ⌈f(x)⌉
+
+    // function defined by cases, of the type f1(x) if c1(x), f2(x) if c2(x), ..., g(x) otherwise,
+    // where the first branch whose condition holds applies
+    Piecewise(Vec<(Condition, Arc<Elementary>)>, Arc<Elementary>),
     // Constant function
     Con(f64), // of the type c
 
     X, // unit function f(x) = x. Any function dependant on a variable must include this
-       // function as it returns a function of type Func which returns the input value.
-       // X will represent the independant variable in each function
+    // function as it returns a function of type Func which returns the input value.
+    // X will represent the independant variable in each function
+
+    // placeholder for an arbitrary subexpression, used in the patterns of rewrite rules. Written
+    // as ?name when parsing. Patterns have no value, so evaluating or differentiating them panics
+    // (whereas try_call and derivative return an error)
+    Wildcard(String),
+
+    // exact rational constant p/q, which is only converted into a float upon evaluation
+    Rat(Rational),
+
+    // symbolic constant such as π or e
+    Sym(Constant),
+}
+
+// functions are compared structurally, except for constants which are compared by value so that
+// an exact or symbolic constant equals the float with the same value
+impl PartialEq for Elementary {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Con(numb1), Con(numb2)) => numb1 == numb2,
+            (Rat(rational1), Rat(rational2)) => rational1 == rational2,
+            (Con(numb), Rat(rational)) | (Rat(rational), Con(numb)) => *numb == rational.to_f64(),
+            (Sym(constant1), Sym(constant2)) => constant1 == constant2,
+            (Sym(constant), Con(numb)) | (Con(numb), Sym(constant)) => *numb == constant.value(),
+            (Sym(constant), Rat(rational)) | (Rat(rational), Sym(constant)) => {
+                rational.to_f64() == constant.value()
+            }
+            (Wildcard(name1), Wildcard(name2)) => name1 == name2,
+            (Polygamma(func1, order1), Polygamma(func2, order2)) => {
+                order1 == order2 && func1 == func2
+            }
+            (Piecewise(branches1, otherwise1), Piecewise(branches2, otherwise2)) => {
+                branches1 == branches2 && otherwise1 == otherwise2
+            }
+            _ => discriminant(self) == discriminant(other) && self.children() == other.children(),
+        }
+    }
 }
 impl Elementary {
     pub fn call(self) -> Func {
@@ -69,6 +132,10 @@ impl Elementary {
             Acos(func) => (*func).clone().call()(x).acos(),
             Atan(func) => (*func).clone().call()(x).atan(),
 
+            Acot(func) => (1. / (*func).clone().call()(x)).atan(),
+            Asec(func) => (1. / (*func).clone().call()(x)).acos(),
+            Acsc(func) => (1. / (*func).clone().call()(x)).asin(),
+
             Sinh(func) => {
                 (E.powf((*func).clone().call()(x)) - E.powf(-(*func).clone().call()(x))) / 2.
             }
@@ -77,6 +144,14 @@ impl Elementary {
             }
             Tanh(func) => Sinh(func.clone()).call()(x) / Cosh(func).call()(x),
 
+            Sech(func) => 1. / (*func).clone().call()(x).cosh(),
+            Csch(func) => 1. / (*func).clone().call()(x).sinh(),
+            Coth(func) => 1. / (*func).clone().call()(x).tanh(),
+
+            Asinh(func) => (*func).clone().call()(x).asinh(),
+            Acosh(func) => (*func).clone().call()(x).acosh(),
+            Atanh(func) => (*func).clone().call()(x).atanh(),
+
             Add(func1, func2) => (*func1).clone().call()(x) + (*func2).clone().call()(x),
             Sub(func1, func2) => (*func1).clone().call()(x) - (*func2).clone().call()(x),
             Mul(func1, func2) => (*func1).clone().call()(x) * (*func2).clone().call()(x),
@@ -92,13 +167,38 @@ impl Elementary {
 
             Abs(func) => (*func).clone().call()(x).abs(),
 
+            Cbrt(func) => (*func).clone().call()(x).cbrt(),
+
+            Floor(func) => (*func).clone().call()(x).floor(),
+            Ceil(func) => (*func).clone().call()(x).ceil(),
+
+            Piecewise(branches, otherwise) => {
+                let func = branches
+                    .iter()
+                    .find(|(condition, _)| condition.holds(x))
+                    .map_or(otherwise, |(_, func)| func.clone());
+                (*func).clone().call()(x)
+            }
+
             Con(numb) => numb,
 
             X => f()(x),
+
+            // patterns cannot be evaluated
+            Wildcard(name) => panic!("{}", unbound_wildcard(&name)),
+
+            Rat(rational) => rational.to_f64(),
+
+            Sym(constant) => constant.value(),
         })
     }
 }
 
+// the message of the panic upon evaluating a pattern
+pub(crate) fn unbound_wildcard(name: &str) -> String {
+    format!("The wildcard ?{name} has no value, so the pattern cannot be evaluated")
+}
+
 // returns the inner Elementary value of the Arc or returns a clone
 pub fn force_unwrap(element: &Arc<Elementary>) -> Elementary {
     if let Ok(inner) = Arc::try_unwrap(element.clone()) {
@@ -245,6 +345,52 @@ pub fn atan(func: Function) -> Function {
     Function::from(new_function)
 }
 
+// reciprocal arcus functions
+/// Creates a [Function](crate::Function) equal to the arccotangent of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ acot(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{acot, Function};
+/// let acot_of_x = acot(Function::default());
+/// assert_eq!(acot_of_x.elementary().call()(1.), std::f64::consts::FRAC_PI_4);
+/// ```
+pub fn acot(func: Function) -> Function {
+    let new_function = Acot(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the arcsecant of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ asec(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{asec, Function};
+/// let asec_of_x = asec(Function::default());
+/// assert_eq!(asec_of_x.elementary().call()(1.), 0.);
+/// ```
+pub fn asec(func: Function) -> Function {
+    let new_function = Asec(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the arccosecant of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ acsc(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{acsc, Function};
+/// let acsc_of_x = acsc(Function::default());
+/// assert_eq!(acsc_of_x.elementary().call()(1.), std::f64::consts::FRAC_PI_2);
+/// ```
+pub fn acsc(func: Function) -> Function {
+    let new_function = Acsc(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
 // hyperbolic functions
 
 /// Creates a [Function](crate::Function) equal to the hyperbolic sine of the passed [Function](crate::Function)
@@ -291,6 +437,97 @@ pub fn tanh(func: Function) -> Function {
     let new_function = Tanh(Arc::new(func.elementary()));
     Function::from(new_function)
 }
+
+/// Creates a [Function](crate::Function) equal to the hyperbolic secant of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ sech(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{sech, Function};
+/// let sech_of_x = sech(Function::default());
+/// assert_eq!(sech_of_x.elementary().call()(0.), 1.);
+/// ```
+pub fn sech(func: Function) -> Function {
+    let new_function = Sech(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the hyperbolic cosecant of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ csch(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{csch, Function};
+/// let csch_of_x = csch(Function::default());
+/// assert_eq!(csch_of_x.elementary().call()(0.), f64::INFINITY);
+/// ```
+pub fn csch(func: Function) -> Function {
+    let new_function = Csch(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the hyperbolic cotangent of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ coth(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{coth, Function};
+/// let coth_of_x = coth(Function::default());
+/// assert_eq!(coth_of_x.elementary().call()(0.), f64::INFINITY);
+/// ```
+pub fn coth(func: Function) -> Function {
+    let new_function = Coth(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+// inverse hyperbolic functions
+/// Creates a [Function](crate::Function) equal to the inverse hyperbolic sine of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ asinh(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{asinh, Function};
+/// let asinh_of_x = asinh(Function::default());
+/// assert_eq!(asinh_of_x.elementary().call()(0.), 0.);
+/// ```
+pub fn asinh(func: Function) -> Function {
+    let new_function = Asinh(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the inverse hyperbolic cosine of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ acosh(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{acosh, Function};
+/// let acosh_of_x = acosh(Function::default());
+/// assert_eq!(acosh_of_x.elementary().call()(1.), 0.);
+/// ```
+pub fn acosh(func: Function) -> Function {
+    let new_function = Acosh(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the inverse hyperbolic tangent of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ atanh(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{atanh, Function};
+/// let atanh_of_x = atanh(Function::default());
+/// assert_eq!(atanh_of_x.elementary().call()(0.), 0.);
+/// ```
+pub fn atanh(func: Function) -> Function {
+    let new_function = Atanh(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
 // abs function
 /// Creates a [Function](crate::Function) equal to the absolute value of the passed [Function](crate::Function)
 ///
@@ -319,7 +556,7 @@ pub fn abs(func: Function) -> Function {
 ///     assert_eq!(ln_of_x.call(E), 1.);
 /// ```
 pub fn ln(func: Function) -> Function {
-    let new_function = Log(Con(E).into(), func.elementary().into());
+    let new_function = Log(Sym(Constant::E).into(), func.elementary().into());
     Function::from(new_function)
 }
 
@@ -368,6 +605,83 @@ pub fn nth_root(func: Function, n: f64) -> Function {
     Function::from(new_function)
 }
 
+/// Creates a [Function](crate::Function) equal to the real cube root of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ ∛f(x), which is also defined for negative values of f(x)
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{cbrt, Function};
+/// let cbrt_of_x = cbrt(Function::default());
+/// assert_eq!(cbrt_of_x.elementary().call()(-8.), -2.);
+/// ```
+pub fn cbrt(func: Function) -> Function {
+    let new_function = Cbrt(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+// rounding functions
+/// Creates a [Function](crate::Function) equal to the floor of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ ⌊f(x)⌋, the largest integer not greater than f(x)
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{floor, Function};
+/// let floor_of_x = floor(Function::default());
+/// assert_eq!(floor_of_x.elementary().call()(-1.5), -2.);
+/// ```
+pub fn floor(func: Function) -> Function {
+    let new_function = Floor(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the ceiling of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ ⌈f(x)⌉, the smallest integer not less than f(x)
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{ceil, Function};
+/// let ceil_of_x = ceil(Function::default());
+/// assert_eq!(ceil_of_x.elementary().call()(-1.5), -1.);
+/// ```
+pub fn ceil(func: Function) -> Function {
+    let new_function = Ceil(Arc::new(func.elementary()));
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the exponential function of the passed [Function](crate::Function)
+///
+/// i.e f(x) ⟹ e^f(x)
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{exp, Function};
+/// let exp_of_x = exp(Function::default());
+/// assert_eq!(exp_of_x.elementary().call()(0.), 1.);
+/// ```
+pub fn exp(func: Function) -> Function {
+    let new_function = Pow(Sym(Constant::E).into(), func.elementary().into());
+    Function::from(new_function)
+}
+
+/// Creates a [Function](crate::Function) equal to the logarithm of the second passed
+/// [Function](crate::Function) to the base of the first
+///
+/// i.e b(x), f(x) ⟹ log_b(x)(f(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{log, Function};
+/// let log_of_x = log(Function::from("2"), Function::default());
+/// assert_eq!(log_of_x.elementary().call()(8.), 3.);
+/// ```
+pub fn log(base: Function, func: Function) -> Function {
+    let new_function = Log(base.elementary().into(), func.elementary().into());
+    Function::from(new_function)
+}
+
 pub struct Function {
     func: Elementary,
 }
@@ -0,0 +1,254 @@
+use std::{f64::consts::E, fmt};
+
+use crate::{
+    gamma_function, polygamma_function,
+    Elementary::{self, *},
+    Factorial, Function,
+};
+
+/// The ways in which a subexpression can leave its domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EvalErrorKind {
+    /// The denominator of a division, or a power of 0 with a negative exponent.
+    DivisionByZero,
+    /// The argument of a logarithm is 0 or negative.
+    NonPositiveLogarithm,
+    /// The base of a logarithm is 0, negative or 1.
+    InvalidLogarithmBase,
+    /// The argument of asin or acos lies outside [-1, 1], or that of asec or acsc inside (-1, 1).
+    InverseTrigonometricRange,
+    /// The argument of acosh is less than 1, or that of atanh lies outside [-1, 1].
+    InverseHyperbolicRange,
+    /// A negative number raised to a power that isn't an integer, such as the square root of a
+    /// negative number.
+    NegativeBase,
+    /// The factorial of a negative integer.
+    NegativeIntegerFactorial,
+    /// A pole of tan, sec, csc, cot, csch, coth, atanh or the gamma and polygamma functions.
+    Pole,
+    /// A wildcard, which has no value.
+    UnboundWildcard,
+}
+
+impl fmt::Display for EvalErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self {
+            Self::DivisionByZero => "division by zero",
+            Self::NonPositiveLogarithm => "logarithm of a non-positive number",
+            Self::InvalidLogarithmBase => "logarithm with a base that is non-positive or 1",
+            Self::InverseTrigonometricRange => "inverse trigonometric function outside its domain",
+            Self::InverseHyperbolicRange => "inverse hyperbolic function outside its domain",
+            Self::NegativeBase => "non-integer power of a negative number",
+            Self::NegativeIntegerFactorial => "factorial of a negative integer",
+            Self::Pole => "pole",
+            Self::UnboundWildcard => "wildcard without a value",
+        };
+        write!(f, "{description}")
+    }
+}
+
+/// The error returned by [try_call()](crate::Function::try_call) when a subexpression is
+/// evaluated outside of its domain.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalError {
+    pub kind: EvalErrorKind,
+    /// The subexpression that was evaluated outside of its domain, e.g. ln(x - 1) in
+    /// sin(ln(x - 1)).
+    pub subexpression: Elementary,
+    /// The value that left the domain, e.g. the argument of the logarithm or the denominator of
+    /// the division.
+    pub argument: f64,
+}
+
+impl EvalError {
+    fn new(kind: EvalErrorKind, subexpression: &Elementary, argument: f64) -> Self {
+        Self {
+            kind,
+            subexpression: subexpression.clone(),
+            argument,
+        }
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} in {:?} (at the value {})",
+            self.kind, self.subexpression, self.argument
+        )
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl Elementary {
+    /// Evaluates the function at x like [call()](crate::Elementary::call), but returns an
+    /// [EvalError](crate::EvalError) describing the innermost subexpression that was evaluated
+    /// outside of its domain instead of NaN or ±∞.
+    pub fn try_call(&self, x: f64) -> Result<f64, EvalError> {
+        use EvalErrorKind::*;
+        let error = |kind, argument| Err(EvalError::new(kind, self, argument));
+
+        // the values are computed the same way as call() computes them
+        Ok(match self {
+            Sin(func) => func.try_call(x)?.sin(),
+            Cos(func) => func.try_call(x)?.cos(),
+            Tan(func) => match func.try_call(x)? {
+                value if value.cos() == 0. => return error(Pole, value),
+                value => value.tan(),
+            },
+            Sec(func) => match func.try_call(x)? {
+                value if value.cos() == 0. => return error(Pole, value),
+                value => 1. / value.cos(),
+            },
+            Csc(func) => match func.try_call(x)? {
+                value if value.sin() == 0. => return error(Pole, value),
+                value => 1. / value.sin(),
+            },
+            Cot(func) => match func.try_call(x)? {
+                value if value.sin() == 0. => return error(Pole, value),
+                value => 1. / value.tan(),
+            },
+            Asin(func) => match func.try_call(x)? {
+                value if value.abs() > 1. => return error(InverseTrigonometricRange, value),
+                value => value.asin(),
+            },
+            Acos(func) => match func.try_call(x)? {
+                value if value.abs() > 1. => return error(InverseTrigonometricRange, value),
+                value => value.acos(),
+            },
+            Atan(func) => func.try_call(x)?.atan(),
+            Acot(func) => (1. / func.try_call(x)?).atan(),
+            Asec(func) => match func.try_call(x)? {
+                value if value.abs() < 1. => return error(InverseTrigonometricRange, value),
+                value => (1. / value).acos(),
+            },
+            Acsc(func) => match func.try_call(x)? {
+                value if value.abs() < 1. => return error(InverseTrigonometricRange, value),
+                value => (1. / value).asin(),
+            },
+            Sinh(func) => {
+                let value = func.try_call(x)?;
+                (E.powf(value) - E.powf(-value)) / 2.
+            }
+            Cosh(func) => {
+                let value = func.try_call(x)?;
+                (E.powf(value) + E.powf(-value)) / 2.
+            }
+            Tanh(func) => {
+                let value = func.try_call(x)?;
+                ((E.powf(value) - E.powf(-value)) / 2.) / ((E.powf(value) + E.powf(-value)) / 2.)
+            }
+            Sech(func) => 1. / func.try_call(x)?.cosh(),
+            Csch(func) => match func.try_call(x)? {
+                0. => return error(Pole, 0.),
+                value => 1. / value.sinh(),
+            },
+            Coth(func) => match func.try_call(x)? {
+                0. => return error(Pole, 0.),
+                value => 1. / value.tanh(),
+            },
+            Asinh(func) => func.try_call(x)?.asinh(),
+            Acosh(func) => match func.try_call(x)? {
+                value if value < 1. => return error(InverseHyperbolicRange, value),
+                value => value.acosh(),
+            },
+            Atanh(func) => match func.try_call(x)? {
+                value if value.abs() == 1. => return error(Pole, value),
+                value if value.abs() > 1. => return error(InverseHyperbolicRange, value),
+                value => value.atanh(),
+            },
+
+            Add(func1, func2) => func1.try_call(x)? + func2.try_call(x)?,
+            Sub(func1, func2) => func1.try_call(x)? - func2.try_call(x)?,
+            Mul(func1, func2) => func1.try_call(x)? * func2.try_call(x)?,
+            Div(func1, func2) => {
+                let numerator = func1.try_call(x)?;
+                match func2.try_call(x)? {
+                    0. => return error(DivisionByZero, 0.),
+                    denominator => numerator / denominator,
+                }
+            }
+            Pow(base, exp) => {
+                let (base, exp) = (base.try_call(x)?, exp.try_call(x)?);
+                if base < 0. && exp.fract() != 0. {
+                    return error(NegativeBase, base);
+                }
+                if base == 0. && exp < 0. {
+                    return error(DivisionByZero, base);
+                }
+                base.powf(exp)
+            }
+            Log(base, func) => {
+                let (base, value) = (base.try_call(x)?, func.try_call(x)?);
+                if value <= 0. {
+                    return error(NonPositiveLogarithm, value);
+                }
+                if base <= 0. || base == 1. {
+                    return error(InvalidLogarithmBase, base);
+                }
+                value.log(base)
+            }
+
+            Factorial(func) => match func.try_call(x)? {
+                value if value < 0. && value.fract() == 0. => {
+                    return error(NegativeIntegerFactorial, value)
+                }
+                value => value.factorial(),
+            },
+            Gamma(func) => match func.try_call(x)? {
+                value if value <= 0. && value.fract() == 0. => return error(Pole, value),
+                value => gamma_function(value),
+            },
+            Polygamma(func, order) => match func.try_call(x)? {
+                value if value <= 0. && value.fract() == 0. => return error(Pole, value),
+                value => polygamma_function(value, *order),
+            },
+            Abs(func) => func.try_call(x)?.abs(),
+            Cbrt(func) => func.try_call(x)?.cbrt(),
+            Floor(func) => func.try_call(x)?.floor(),
+            Ceil(func) => func.try_call(x)?.ceil(),
+            Piecewise(branches, otherwise) => {
+                // the conditions must be defined for the branch that applies to be known
+                for (condition, func) in branches {
+                    let (lhs, rhs) = (condition.lhs.try_call(x)?, condition.rhs.try_call(x)?);
+                    if condition.relation.holds(lhs, rhs) {
+                        return func.try_call(x);
+                    }
+                }
+                otherwise.try_call(x)?
+            }
+
+            Con(numb) => *numb,
+            Rat(rational) => rational.to_f64(),
+            Sym(constant) => constant.value(),
+            X => x,
+            Wildcard(_) => return error(UnboundWildcard, f64::NAN),
+        })
+    }
+}
+
+impl Function {
+    /// Evaluates the function at x, returning an [EvalError](crate::EvalError) if any of its
+    /// subexpressions leaves its domain.
+    ///
+    /// Unlike [call()](crate::Function::call), which returns NaN or ±∞ in these cases, this tells
+    /// domain errors apart from legitimately infinite values and reports which subexpression
+    /// caused them.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{EvalErrorKind, Function};
+    /// let function = Function::from("sin(ln(x - 1))");
+    ///
+    /// assert_eq!(function.try_call(3.).unwrap(), 2_f64.ln().sin());
+    ///
+    /// let error = function.try_call(0.).unwrap_err();
+    /// assert_eq!(error.kind, EvalErrorKind::NonPositiveLogarithm);
+    /// assert_eq!(error.argument, -1.);
+    /// ```
+    pub fn try_call(&self, x: f64) -> Result<f64, EvalError> {
+        self.elementary().try_call(x)
+    }
+}
@@ -0,0 +1,432 @@
+use std::sync::Arc;
+
+use crate::{
+    functions::{
+        limits::{Limit, Target},
+        roots::{is_same_point, sign_changes},
+    },
+    Direction,
+    Elementary::{self, *},
+    Error, Func, Function,
+};
+
+/// The end of the real line that an asymptote is approached at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Towards {
+    PositiveInfinity,
+    NegativeInfinity,
+}
+
+/// A line that the graph of a function approaches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Asymptote {
+    /// The line x = a, where the function diverges as x approaches a from at least one side.
+    Vertical(f64),
+    /// The line y = value, which the function approaches as x goes towards ±∞.
+    Horizontal { value: f64, towards: Towards },
+    /// The line y = slope * x + intercept, which the function approaches as x goes towards ±∞.
+    Oblique {
+        slope: f64,
+        intercept: f64,
+        towards: Towards,
+    },
+}
+
+/// The result of analysing the graph of a function on an interval.
+///
+/// See [Function::analyze()](crate::Function::analyze) for usage and examples.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CurveReport {
+    /// The intervals (start, end) on which the function is undefined. Isolated points at which
+    /// the function is undefined are given as (x, x).
+    pub domain_gaps: Vec<(f64, f64)>,
+    /// The points where the graph crosses or touches the x-axis.
+    pub x_intercepts: Vec<f64>,
+    /// The value of the function at x = 0, provided that 0 lies in the analysed interval and
+    /// that the function is defined there.
+    pub y_intercept: Option<f64>,
+    pub increasing: Vec<(f64, f64)>,
+    pub decreasing: Vec<(f64, f64)>,
+    pub concave_up: Vec<(f64, f64)>,
+    pub concave_down: Vec<(f64, f64)>,
+    /// The points where the concavity of the function changes.
+    pub inflection_points: Vec<f64>,
+    pub asymptotes: Vec<Asymptote>,
+}
+
+// the number of points that the interval is sampled at when looking for domain gaps
+const SAMPLES: usize = 1000;
+// the number of bisection steps used to locate the boundary of a domain gap
+const BISECTION_STEPS: usize = 100;
+// values larger than this (in absolute value) indicate a pole
+const POLE_THRESHOLD: f64 = 1e10;
+
+type Intervals = Vec<(f64, f64)>;
+
+// a part of the analysed interval on which the sign of a derivative is constant
+#[derive(Clone, Copy)]
+struct Segment {
+    start: f64,
+    end: f64,
+    sign: f64,
+}
+
+impl Elementary {
+    /// Analyses the graph of the function on the interval [a, b].
+    pub fn analyze(&self, a: f64, b: f64) -> Result<CurveReport, Error> {
+        if !(a.is_finite() && b.is_finite()) || a >= b {
+            return Err(Error::InputError(format!(
+                "Cannot analyse the function on the interval [{a}, {b}]"
+            )));
+        }
+
+        let function = self.clone().call();
+        let first_derivative = self.clone().derivative_unsimplified();
+        let second_derivative = first_derivative.clone().derivative_unsimplified();
+
+        let singularities =
+            self.singularities(&function, &first_derivative.clone().call(), a, b)?;
+        let domain_gaps = domain_gaps(&function, a, b, &singularities);
+
+        // monotonicity and concavity can only change at points where the derivatives vanish or
+        // where the function is undefined
+        let mut breaks: Vec<f64> = singularities.clone();
+        for (start, end) in &domain_gaps {
+            breaks.push(*start);
+            breaks.push(*end);
+        }
+
+        let monotonicity = sign_segments(&function, &first_derivative, a, b, &breaks)?;
+        let (increasing, decreasing) = merge_segments(&monotonicity, &breaks);
+
+        let concavity = sign_segments(&function, &second_derivative, a, b, &breaks)?;
+        let (concave_up, concave_down) = merge_segments(&concavity, &breaks);
+
+        let inflection_points = concavity
+            .windows(2)
+            .filter(|pair| {
+                pair[0].end == pair[1].start
+                    && pair[0].sign != pair[1].sign
+                    && !is_break(pair[0].end, &breaks)
+            })
+            .map(|pair| pair[0].end)
+            .collect();
+
+        let x_intercepts = self
+            .find_all_roots(a, b)?
+            .iter()
+            .map(|root| root.value)
+            .collect();
+
+        let y_intercept = if a <= 0. && b >= 0. && function(0.).is_finite() {
+            Some(function(0.))
+        } else {
+            None
+        };
+
+        let mut asymptotes = self.vertical_asymptotes(a, b, &domain_gaps);
+        for towards in [Towards::NegativeInfinity, Towards::PositiveInfinity] {
+            if let Some(asymptote) = self.asymptote_towards(towards) {
+                asymptotes.push(asymptote);
+            }
+        }
+
+        Ok(CurveReport {
+            domain_gaps,
+            x_intercepts,
+            y_intercept,
+            increasing,
+            decreasing,
+            concave_up,
+            concave_down,
+            inflection_points,
+            asymptotes,
+        })
+    }
+
+    // finds isolated points where the function is undefined. These are found as the sign changes
+    // of the function (like 1/x at x = 0) or its derivative (like 1/x² at x = 0) at which the
+    // function blows up
+    fn singularities(
+        &self,
+        function: &Func,
+        derivative: &Func,
+        a: f64,
+        b: f64,
+    ) -> Result<Vec<f64>, Error> {
+        let mut candidates = sign_changes(function, a, b)?;
+        candidates.append(&mut sign_changes(derivative, a, b)?);
+
+        let mut singularities: Vec<f64> = candidates
+            .into_iter()
+            .map(|x| snap(function, x))
+            .filter(|x| {
+                let value = function(*x);
+                !value.is_finite() || value.abs() > POLE_THRESHOLD
+            })
+            .collect();
+
+        singularities.sort_by(|x1, x2| x1.total_cmp(x2));
+        singularities.dedup_by(|x1, x2| is_same_point(*x1, *x2));
+        Ok(singularities)
+    }
+
+    // checks whether the function diverges as x approaches the boundaries of the domain gaps
+    fn vertical_asymptotes(&self, a: f64, b: f64, domain_gaps: &[(f64, f64)]) -> Vec<Asymptote> {
+        let diverges = |point: f64, direction: Direction| {
+            matches!(
+                self.limit_towards(Target::Point(point, direction)),
+                Ok(limit) if limit.is_infinite()
+            )
+        };
+
+        let mut asymptotes = Vec::new();
+        for (start, end) in domain_gaps {
+            if start == end {
+                if diverges(*start, Direction::Left) || diverges(*start, Direction::Right) {
+                    asymptotes.push(Asymptote::Vertical(*start));
+                }
+                continue;
+            }
+            if *start > a && diverges(*start, Direction::Left) {
+                asymptotes.push(Asymptote::Vertical(*start));
+            }
+            if *end < b && diverges(*end, Direction::Right) {
+                asymptotes.push(Asymptote::Vertical(*end));
+            }
+        }
+
+        asymptotes
+    }
+
+    // a horizontal asymptote y = L exists if f(x) ⟶ L. Otherwise, an oblique asymptote y = mx + c
+    // exists if f(x)/x ⟶ m ≠ 0 and f(x) - mx ⟶ c
+    fn asymptote_towards(&self, towards: Towards) -> Option<Asymptote> {
+        let target = match towards {
+            Towards::PositiveInfinity => Target::PositiveInfinity,
+            Towards::NegativeInfinity => Target::NegativeInfinity,
+        };
+
+        match self.limit_towards(target).ok()? {
+            Limit::Finite(value) => Some(Asymptote::Horizontal {
+                value: value + 0.,
+                towards,
+            }),
+            _ => {
+                let slope = match Div(Arc::new(self.clone()), Arc::new(X))
+                    .limit_towards(target)
+                    .ok()?
+                {
+                    Limit::Finite(slope) if slope != 0. => slope,
+                    _ => return None,
+                };
+                match Sub(
+                    Arc::new(self.clone()),
+                    Arc::new(Mul(Arc::new(Con(slope)), Arc::new(X))),
+                )
+                .limit_towards(target)
+                .ok()?
+                {
+                    Limit::Finite(intercept) => Some(Asymptote::Oblique {
+                        slope,
+                        intercept: intercept + 0.,
+                        towards,
+                    }),
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+impl Function {
+    /// Analyses the graph of the function on the interval [a, b], the way one would when
+    /// sketching a curve by hand.
+    ///
+    /// The returned [CurveReport](crate::CurveReport) lists
+    /// - the intervals and points on which the function is undefined
+    /// - the x- and y-intercepts
+    /// - the intervals on which the function is increasing and decreasing
+    /// - the intervals on which the function is concave up and concave down, along with the
+    ///   inflection points separating them
+    /// - the vertical asymptotes within the interval as well as the horizontal and oblique
+    ///   asymptotes at ±∞
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Asymptote, Function, Towards};
+    /// let function = Function::from("(2x + 1)/(x + 3)");
+    ///
+    /// let report = function.analyze(-10., 10.).unwrap();
+    ///
+    /// assert!(report.asymptotes.contains(&Asymptote::Vertical(-3.)));
+    /// assert!(report.asymptotes.contains(&Asymptote::Horizontal {
+    ///     value: 2.,
+    ///     towards: Towards::PositiveInfinity
+    /// }));
+    /// assert_eq!(report.increasing, vec![(-10., -3.), (-3., 10.)]);
+    /// ```
+    pub fn analyze(&self, a: f64, b: f64) -> Result<CurveReport, Error> {
+        self.elementary().analyze(a, b)
+    }
+}
+
+// roots of derivatives are found to within floating point accuracy, meaning that a pole located at
+// a "nice" value (such as x = 0 for 1/x) is missed by a tiny margin. If rounding the point makes
+// the function blow up further, the rounded point is used instead
+fn snap(function: &Func, x: f64) -> f64 {
+    let rounded = (x * 1e10).round() / 1e10 + 0.;
+    let (value, rounded_value) = (function(x), function(rounded));
+
+    if !rounded_value.is_finite() || rounded_value.abs() >= value.abs() {
+        rounded
+    } else {
+        x
+    }
+}
+
+// samples the interval for points where the function is undefined and determines the boundaries
+// of the domain gaps using bisection
+fn domain_gaps(function: &Func, a: f64, b: f64, singularities: &[f64]) -> Vec<(f64, f64)> {
+    let step = (b - a) / SAMPLES as f64;
+    let points: Vec<f64> = (0..=SAMPLES).map(|i| a + i as f64 * step).collect();
+    let defined: Vec<bool> = points.iter().map(|x| function(*x).is_finite()).collect();
+
+    let mut gaps: Vec<(f64, f64)> = Vec::new();
+    let mut i = 0;
+    while i <= SAMPLES {
+        if defined[i] {
+            i += 1;
+            continue;
+        }
+
+        let mut j = i;
+        while j < SAMPLES && !defined[j + 1] {
+            j += 1;
+        }
+
+        let start = if i == 0 {
+            a
+        } else {
+            snap(function, gap_boundary(function, points[i - 1], points[i]))
+        };
+        let end = if j == SAMPLES {
+            b
+        } else {
+            snap(function, gap_boundary(function, points[j + 1], points[j]))
+        };
+        gaps.push((start, end));
+
+        i = j + 1;
+    }
+
+    // points where the function is undefined that fall between the sampled points
+    for x in singularities {
+        if !gaps
+            .iter()
+            .any(|(start, end)| is_same_point(*start, *x) || (*start <= *x && *x <= *end))
+        {
+            gaps.push((*x, *x));
+        }
+    }
+
+    gaps.sort_by(|gap1, gap2| gap1.0.total_cmp(&gap2.0));
+    gaps
+}
+
+// finds the point between a (where the function is defined) and b (where it is undefined) at which
+// the function becomes undefined
+fn gap_boundary(function: &Func, defined: f64, undefined: f64) -> f64 {
+    let (mut defined, mut undefined) = (defined, undefined);
+    for _ in 0..BISECTION_STEPS {
+        let middle = (defined + undefined) / 2.;
+        if middle == defined || middle == undefined {
+            break;
+        }
+        if function(middle).is_finite() {
+            defined = middle;
+        } else {
+            undefined = middle;
+        }
+    }
+    undefined
+}
+
+// splits the interval at the roots of the derivative as well as at the provided break points and
+// determines the sign of the derivative on each part. Parts on which the function is undefined are
+// skipped
+fn sign_segments(
+    function: &Func,
+    derivative: &Elementary,
+    a: f64,
+    b: f64,
+    breaks: &[f64],
+) -> Result<Vec<Segment>, Error> {
+    let callable = derivative.clone().call();
+
+    let mut points = vec![a, b];
+    points.extend(breaks.iter().filter(|x| **x > a && **x < b));
+
+    // a derivative that vanishes everywhere has no roots to split the interval at
+    let step = (b - a) / SAMPLES as f64;
+    if (0..=SAMPLES).any(|i| callable(a + i as f64 * step) != 0.) {
+        points.extend(
+            derivative
+                .find_all_roots(a, b)?
+                .iter()
+                .map(|root| root.value)
+                .filter(|x| *x > a && *x < b),
+        );
+    }
+
+    points.sort_by(|x1, x2| x1.total_cmp(x2));
+    points.dedup_by(|x1, x2| is_same_point(*x1, *x2));
+
+    Ok(points
+        .windows(2)
+        .filter_map(|pair| {
+            let middle = (pair[0] + pair[1]) / 2.;
+            let value = callable(middle);
+            if !function(middle).is_finite() || !value.is_finite() {
+                return None;
+            }
+            Some(Segment {
+                start: pair[0],
+                end: pair[1],
+                sign: if value == 0. { 0. } else { value.signum() },
+            })
+        })
+        .collect())
+}
+
+// merges neighbouring segments on which the derivative has the same sign, unless they are separated
+// by a point where the function is undefined. Returns the intervals where the derivative is
+// positive and negative respectively
+fn merge_segments(segments: &[Segment], breaks: &[f64]) -> (Intervals, Intervals) {
+    let mut merged: Vec<Segment> = Vec::new();
+    for segment in segments {
+        match merged.last_mut() {
+            Some(previous)
+                if previous.end == segment.start
+                    && previous.sign == segment.sign
+                    && !is_break(segment.start, breaks) =>
+            {
+                previous.end = segment.end;
+            }
+            _ => merged.push(*segment),
+        }
+    }
+
+    let intervals = |sign: f64| {
+        merged
+            .iter()
+            .filter(|segment| segment.sign == sign)
+            .map(|segment| (segment.start, segment.end))
+            .collect()
+    };
+    (intervals(1.), intervals(-1.))
+}
+
+fn is_break(x: f64, breaks: &[f64]) -> bool {
+    breaks.iter().any(|point| is_same_point(*point, x))
+}
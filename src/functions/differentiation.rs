@@ -1,8 +1,11 @@
 use crate::{
+    functions::piecewise::signum,
+    utils::rational::{Number, Rational},
+    Constant,
     Elementary::{self, *},
     Error, Function,
 };
-use std::{f64::consts::E, sync::Arc};
+use std::sync::Arc;
 impl Elementary {
     fn differentiate(self) -> Self {
         match self {
@@ -17,7 +20,7 @@ impl Elementary {
             Tan(func) => Mul(
                 Arc::new(Div(
                     Arc::new(Con(1.)),
-                    Arc::new(Pow(Arc::new(Cos(Arc::new(X))), Arc::new(Con(2.)))),
+                    Arc::new(Pow(Arc::new(Cos(func.clone())), Arc::new(Con(2.)))),
                 )),
                 Arc::new((*func).clone().differentiate()),
             ), // 1/cos^2(f(x)) * f'(x)
@@ -42,7 +45,7 @@ impl Elementary {
                         Arc::new(Con(1.)),
                         Arc::new(Pow(func.clone(), Arc::new(Con(2.)))),
                     )),
-                    Arc::new(Con(0.5)),
+                    Arc::new(Rat(Rational::HALF)),
                 )),
             ),
             Acos(func) => Mul(
@@ -53,7 +56,7 @@ impl Elementary {
                             Arc::new(Con(1.)),
                             Arc::new(Pow(func.clone(), Arc::new(Con(2.)))),
                         )),
-                        Arc::new(Con(0.5)),
+                        Arc::new(Rat(Rational::HALF)),
                     )),
                 )),
                 Arc::new(Con(-1.)),
@@ -65,6 +68,29 @@ impl Elementary {
                     Arc::new(Con(1.)),
                 )),
             ),
+            // -f'(x) / (1 + f(x)^2)
+            Acot(func) => {
+                (*func).clone().differentiate() * -1.
+                    / (Pow(func.clone(), Arc::new(Con(2.))) + Con(1.))
+            }
+            // f'(x) / (|f(x)| sqrt(f(x)^2 - 1))
+            Asec(func) => {
+                (*func).clone().differentiate()
+                    / (Abs(func.clone())
+                        * Pow(
+                            Arc::new(Pow(func.clone(), Arc::new(Con(2.))) - Con(1.)),
+                            Arc::new(Rat(Rational::HALF)),
+                        ))
+            }
+            // -f'(x) / (|f(x)| sqrt(f(x)^2 - 1))
+            Acsc(func) => {
+                (*func).clone().differentiate() * -1.
+                    / (Abs(func.clone())
+                        * Pow(
+                            Arc::new(Pow(func.clone(), Arc::new(Con(2.))) - Con(1.)),
+                            Arc::new(Rat(Rational::HALF)),
+                        ))
+            }
             Sinh(func) => Mul(
                 Arc::new(Cosh(func.clone())),
                 Arc::new((*func).clone().differentiate()),
@@ -77,6 +103,40 @@ impl Elementary {
                 Arc::new((*func).clone().differentiate()),
                 Arc::new(Pow(Arc::new(Cosh(func.clone())), Arc::new(Con(2.)))),
             ),
+            // -sech(f(x)) * tanh(f(x)) * f'(x)
+            Sech(func) => {
+                (*func).clone().differentiate() * Tanh(func.clone()) * Sech(func.to_owned()) * -1.
+            }
+            // -csch(f(x)) * coth(f(x)) * f'(x)
+            Csch(func) => {
+                (*func).clone().differentiate() * Coth(func.clone()) * Csch(func.to_owned()) * -1.
+            }
+            // -csch^2(f(x)) * f'(x)
+            Coth(func) => {
+                (*func).clone().differentiate()
+                    * -1.
+                    * Pow(Arc::new(Csch(func.to_owned())), Arc::new(Con(2.)))
+            }
+            // f'(x) / sqrt(f(x)^2 + 1)
+            Asinh(func) => {
+                (*func).clone().differentiate()
+                    / Pow(
+                        Arc::new(Pow(func.clone(), Arc::new(Con(2.))) + Con(1.)),
+                        Arc::new(Rat(Rational::HALF)),
+                    )
+            }
+            // f'(x) / sqrt(f(x)^2 - 1)
+            Acosh(func) => {
+                (*func).clone().differentiate()
+                    / Pow(
+                        Arc::new(Pow(func.clone(), Arc::new(Con(2.))) - Con(1.)),
+                        Arc::new(Rat(Rational::HALF)),
+                    )
+            }
+            // f'(x) / (1 - f(x)^2)
+            Atanh(func) => {
+                (*func).clone().differentiate() / (Con(1.) - Pow(func.clone(), Arc::new(Con(2.))))
+            }
 
             Add(func1, func2) => Add(
                 Arc::new((*func1).clone().differentiate()),
@@ -109,6 +169,30 @@ impl Elementary {
                 )),
                 Arc::new(Pow((func2).clone(), Arc::new(Con(2.)))),
             ), // (f'(x)g(x) - f(x)g'(x)) / (g(x))^2
+            // the general rule below involves ln(f(x)) which is undefined for negative values of
+            // f(x), so the power rule is used whenever the exponent is constant
+            Pow(func1, func2) if func2.is_constant() => {
+                // numeric exponents are lowered right away, which keeps rational exponents exact
+                // (so that x^(1/3) becomes 1/3 x^(-2/3) rather than 0.333... x^(-0.666...))
+                let lowered = match func2.as_number() {
+                    Some(exp) => Elementary::from_number(exp - Number::from(1.)),
+                    None => Sub(func2.clone(), Arc::new(Con(1.))),
+                };
+                Mul(
+                    Arc::new(Mul(
+                        func2.clone(),
+                        Arc::new(Pow(func1.clone(), Arc::new(lowered))),
+                    )),
+                    Arc::new((*func1).clone().differentiate()),
+                )
+            } // g * f(x)^(g - 1) * f'(x)
+            Pow(func1, func2) if func1.is_constant() => Mul(
+                Arc::new(Mul(
+                    Arc::new(Pow(func1.clone(), func2.clone())),
+                    Arc::new(Log(Arc::new(Sym(Constant::E)), func1.clone())),
+                )),
+                Arc::new((*func2).clone().differentiate()),
+            ), // a^g(x) * ln(a) * g'(x)
             Pow(func1, func2) => Mul(
                 Arc::new(Pow(
                     func1.clone(),
@@ -122,32 +206,42 @@ impl Elementary {
                     Arc::new(Mul(
                         func1.clone(), // f(x)
                         Arc::new(Mul(
-                            Arc::new(Log(Arc::new(Con(E)), func1.clone())), // ln(f(x))
+                            Arc::new(Log(Arc::new(Sym(Constant::E)), func1.clone())), // ln(f(x))
                             Arc::new((*func2).clone().differentiate()),
                         )),
                     )),
                 )),
             ), // g'(x)
             // f(x)^(g(x) - 1) (g(x) f'(x) + f(x) log(f(x)) g'(x))
+            Log(func1, func2) if matches!(*func1, Sym(Constant::E)) => {
+                Div(Arc::new((*func2).clone().differentiate()), func2.clone())
+            } // f'(x) / f(x)
+            Log(func1, func2) if func1.is_constant() => Div(
+                Arc::new((*func2).clone().differentiate()),
+                Arc::new(Mul(
+                    func2.clone(),
+                    Arc::new(Log(Arc::new(Sym(Constant::E)), func1.clone())),
+                )),
+            ), // f'(x) / (f(x) ln(b))
             Log(func1, func2) => Div(
                 Arc::new(Sub(
                     Arc::new(Div(
                         Arc::new(Mul(
-                            Arc::new(Log(Arc::new(Con(E)), func1.clone())),
+                            Arc::new(Log(Arc::new(Sym(Constant::E)), func1.clone())),
                             Arc::new((*func2).clone().differentiate()),
                         )),
                         func2.clone(),
                     )),
                     Arc::new(Div(
                         Arc::new(Mul(
-                            Arc::new(Log(Arc::new(Con(E)), func2.clone())),
+                            Arc::new(Log(Arc::new(Sym(Constant::E)), func2.clone())),
                             Arc::new((*func1).clone().differentiate()),
                         )),
                         func1.clone(),
                     )),
                 )),
                 Arc::new(Pow(
-                    Arc::new(Log(Arc::new(Con(E)), func1.clone())),
+                    Arc::new(Log(Arc::new(Sym(Constant::E)), func1.clone())),
                     Arc::new(Con(2.)),
                 )),
             ),
@@ -166,15 +260,36 @@ impl Elementary {
             // 𝝍(m)'(f(x)) = f'(x)*𝝍(m+1)(f(x))
             Polygamma(func, order) => (*func).clone().differentiate() * Polygamma(func, order + 1),
 
-            Abs(func) => Div(
-                Arc::new(Mul(func.clone(), Arc::new((*func).clone().differentiate()))),
-                Arc::new(Abs(func)),
+            // sign(f(x)) * f'(x), which unlike f(x)/|f(x)| * f'(x) is defined where f(x) = 0
+            Abs(func) => signum(func.clone()) * (*func).clone().differentiate(),
+            // f'(x) / (3 cbrt(f(x))^2)
+            Cbrt(func) => {
+                (*func).clone().differentiate()
+                    / (Pow(Arc::new(Cbrt(func.clone())), Arc::new(Con(2.))) * 3.)
+            }
+            // the rounding functions are constant between their jumps
+            Floor(_) | Ceil(_) => Con(0.),
+
+            // the branches are differentiated separately, under the same conditions
+            Piecewise(branches, otherwise) => Piecewise(
+                branches
+                    .into_iter()
+                    .map(|(condition, func)| (condition, Arc::new((*func).clone().differentiate())))
+                    .collect(),
+                Arc::new((*otherwise).clone().differentiate()),
             ),
-            Con(_) => Con(0.),
+            Con(_) | Rat(_) | Sym(_) => Con(0.),
             X => Con(1.),
+            // patterns have no derivative
+            Wildcard(name) => panic!("{}", wildcard_derivative(&name)),
         }
     }
     pub fn derivative(self) -> Result<Elementary, Error> {
+        let mut wildcards = Vec::new();
+        self.wildcards(&mut wildcards);
+        if let Some(name) = wildcards.first() {
+            return Err(Error::InputError(wildcard_derivative(name)));
+        }
         self.differentiate().simplify()
     }
     pub fn derivative_unsimplified(self) -> Elementary {
@@ -182,6 +297,11 @@ impl Elementary {
     }
 }
 
+// the message of the error upon differentiating a pattern
+pub(crate) fn wildcard_derivative(name: &str) -> String {
+    format!("The wildcard ?{name} stands for an arbitrary function, so the pattern cannot be differentiated")
+}
+
 /// Returns the derivative of the passed [Function](crate::Function).
 ///
 /// Example:
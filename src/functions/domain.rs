@@ -0,0 +1,621 @@
+use std::{f64::consts::PI, sync::Arc};
+
+use crate::{
+    functions::roots::is_same_point,
+    Elementary::{self, *},
+    Function, Interval, IntervalSet, Lattice, Periodic, Polynomial, RationalFunction, Relation,
+    Span,
+};
+
+// constraints on arguments that are neither rational nor periodic functions of x are resolved
+// numerically on [-WINDOW, WINDOW] (or as far beyond the finite end of a span)
+const WINDOW: f64 = 100.;
+// the largest ratio p/q (in lowest terms) of the periods of two periodic functions whose sum is
+// taken to be periodic
+const MAX_PERIOD_RATIO: usize = 12;
+// the largest number of points of a lattice that are solved for separately on a span of the domain
+const MAX_CROSSINGS: f64 = 10000.;
+
+impl Elementary {
+    /// Derives the set of real numbers where the function is defined, i.e. where
+    /// [call()](crate::Elementary::call) returns a real number rather than NaN.
+    ///
+    /// Each node restricts the values of its arguments: the arguments of logarithms must be
+    /// positive, denominators must be non-zero, non-integer powers need non-negative bases,
+    /// asin and acos need arguments in [-1, 1] (asec and acsc outside of (-1, 1)), acosh needs
+    /// arguments of at least 1 and atanh in (-1, 1), tan, sec, csc, cot, csch and coth exclude
+    /// their poles, and the factorial and gamma functions exclude the negative integers. The restrictions are
+    /// solved exactly when the argument is a rational function of x (or linear in x for the
+    /// periodic poles). Arguments that are periodic in x (such as sin(x) + 0.5) are solved
+    /// numerically on a single period, and the result is repeated as a
+    /// [Periodic](crate::Periodic) restriction or a [Lattice](crate::Lattice). Other arguments are
+    /// solved numerically on [-100, 100], beyond which the domain only extends where interval
+    /// arithmetic shows that the restriction holds throughout, so the domain may be bounded where
+    /// the function itself isn't (such as that of tan(x²)). Restrictions on floor and ceil are
+    /// moved onto their arguments, e.g. floor(f(x)) ≠ 0 where f(x) ∉ [0, 1). Piecewise functions
+    /// are defined where the branch that applies is defined, and where the conditions deciding it
+    /// are defined.
+    pub fn domain(&self) -> IntervalSet {
+        match self {
+            Sin(func) | Cos(func) | Atan(func) | Acot(func) | Sinh(func) | Cosh(func)
+            | Tanh(func) | Sech(func) | Asinh(func) | Abs(func) | Cbrt(func) | Floor(func)
+            | Ceil(func) => func.domain(),
+            Tan(func) | Sec(func) => {
+                lattice_preimage(func, Lattice::new(PI / 2., PI, false), &func.domain())
+            }
+            Csc(func) | Cot(func) => {
+                lattice_preimage(func, Lattice::new(0., PI, false), &func.domain())
+            }
+            Asin(func) | Acos(func) => {
+                preimage(func, &Span::closed(-1., 1.).into(), &func.domain())
+            }
+            Asec(func) | Acsc(func) => {
+                let allowed = IntervalSet::from_spans(vec![
+                    Span::new(f64::NEG_INFINITY, -1., false, true),
+                    Span::new(1., f64::INFINITY, true, false),
+                ]);
+                preimage(func, &allowed, &func.domain())
+            }
+            Csch(func) | Coth(func) => preimage(func, &non_zero(), &func.domain()),
+            Acosh(func) => preimage(
+                func,
+                &Span::new(1., f64::INFINITY, true, false).into(),
+                &func.domain(),
+            ),
+            Atanh(func) => preimage(func, &Span::open(-1., 1.).into(), &func.domain()),
+
+            Add(func1, func2) | Sub(func1, func2) | Mul(func1, func2) => {
+                func1.domain().intersection(&func2.domain())
+            }
+            Div(func1, func2) => {
+                func1
+                    .domain()
+                    .intersection(&preimage(func2, &non_zero(), &func2.domain()))
+            }
+            Pow(base, exp) => {
+                let exp_domain = exp.domain();
+                let base_domain = base.domain();
+                let allowed = if exp.is_constant() {
+                    let exp = exp.constant_value().to_f64();
+                    match (exp.fract() == 0., exp < 0.) {
+                        (true, false) => return base_domain.intersection(&exp_domain),
+                        (true, true) => non_zero(),
+                        // non-integer powers of negative numbers aren't real
+                        (false, false) => Span::new(0., f64::INFINITY, true, false).into(),
+                        (false, true) => Span::open(0., f64::INFINITY).into(),
+                    }
+                } else {
+                    Span::open(0., f64::INFINITY).into()
+                };
+                preimage(base, &allowed, &base_domain).intersection(&exp_domain)
+            }
+            Log(base, func) => {
+                let valid_base = IntervalSet::from_spans(vec![
+                    Span::open(0., 1.),
+                    Span::open(1., f64::INFINITY),
+                ]);
+                preimage(func, &Span::open(0., f64::INFINITY).into(), &func.domain())
+                    .intersection(&preimage(base, &valid_base, &base.domain()))
+            }
+
+            // x! is undefined at the negative integers and 𝜞(x) at the non-positive ones
+            Factorial(func) => lattice_preimage(func, Lattice::new(-1., -1., true), &func.domain()),
+            Gamma(func) | Polygamma(func, _) => {
+                lattice_preimage(func, Lattice::new(0., -1., true), &func.domain())
+            }
+
+            // each branch is defined where it applies, i.e. where its condition holds and the
+            // earlier ones fail
+            Piecewise(branches, otherwise) => {
+                let mut domain = IntervalSet::empty();
+                let mut unclaimed = IntervalSet::real_line();
+                for (condition, func) in branches {
+                    let difference = condition.difference();
+                    let defined = difference.domain();
+                    let relation =
+                        |relation| preimage(&difference, &satisfying(relation), &defined);
+
+                    domain = domain.union(
+                        &unclaimed
+                            .intersection(&relation(condition.relation))
+                            .intersection(&func.domain()),
+                    );
+                    unclaimed = unclaimed.intersection(&relation(condition.relation.negated()));
+                }
+                domain.union(&unclaimed.intersection(&otherwise.domain()))
+            }
+
+            Con(numb) if numb.is_nan() => IntervalSet::empty(),
+            Con(_) | Rat(_) | Sym(_) | X | Wildcard(_) => IntervalSet::real_line(),
+        }
+    }
+}
+
+impl Function {
+    /// Returns the [IntervalSet](crate::IntervalSet) on which the function is defined. See
+    /// [Elementary::domain()](crate::Elementary::domain) for how the domain is derived.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("ln(x^2 - 1) + sqrt(x + 4)");
+    ///
+    /// assert_eq!(function.domain().to_string(), "[-4, -1) ∪ (1, ∞)");
+    ///
+    /// let function = Function::from("tan(x)");
+    ///
+    /// assert!(function.domain().contains(0.));
+    /// assert!(!function.domain().contains(std::f64::consts::FRAC_PI_2));
+    /// ```
+    pub fn domain(&self) -> IntervalSet {
+        self.elementary().domain()
+    }
+}
+
+fn non_zero() -> IntervalSet {
+    IntervalSet::from_spans(vec![
+        Span::open(f64::NEG_INFINITY, 0.),
+        Span::open(0., f64::INFINITY),
+    ])
+}
+
+// the values that compare to 0 as the relation requires
+fn satisfying(relation: Relation) -> IntervalSet {
+    match relation {
+        Relation::Less => Span::open(f64::NEG_INFINITY, 0.),
+        Relation::LessEqual => Span::new(f64::NEG_INFINITY, 0., false, true),
+        Relation::Greater => Span::open(0., f64::INFINITY),
+        Relation::GreaterEqual => Span::new(0., f64::INFINITY, true, false),
+    }
+    .into()
+}
+
+// the period of the function, where constants have a period of 0, or None if the function isn't
+// known to be periodic
+fn period(function: &Elementary) -> Option<f64> {
+    if function.is_constant() {
+        return Some(0.);
+    }
+    // a x + b has the period of the trigonometric function divided by a
+    let linear = |func: &Elementary, period: f64| {
+        Polynomial::try_from(func)
+            .ok()
+            .filter(|polynomial| polynomial.degree() == 1)
+            .map(|polynomial| period / polynomial.coefficients()[1].abs())
+    };
+    match function {
+        Sin(func) | Cos(func) | Sec(func) | Csc(func) => {
+            linear(func, 2. * PI).or_else(|| period(func))
+        }
+        Tan(func) | Cot(func) => linear(func, PI).or_else(|| period(func)),
+        X | Wildcard(_) => None,
+        _ => function
+            .children()
+            .iter()
+            .try_fold(0., |period1, child| common_period(period1, period(child)?)),
+    }
+}
+
+// the smallest common multiple of the periods, if their ratio is a simple fraction
+fn common_period(period1: f64, period2: f64) -> Option<f64> {
+    if period1 == 0. || period2 == 0. {
+        return Some(period1.max(period2));
+    }
+    // period1/period2 = m/n, so that n period1 = m period2
+    let ratio = period1 / period2;
+    (1..=MAX_PERIOD_RATIO)
+        .find(|n| {
+            let multiple = ratio * *n as f64;
+            (multiple - multiple.round()).abs() < 1e-9 * multiple
+        })
+        .map(|n| period1 * n as f64)
+}
+
+// the set of points where x mod period lies in one of the spans, which lie within [0, period).
+// Where only isolated points are missing, they are excluded as lattices instead
+fn periodic_set(spans: &[Span], period: f64) -> IntervalSet {
+    let periodic = Periodic::new(spans.to_vec(), period);
+
+    // the points from the current one onwards aren't covered yet, and neither is the current one
+    // itself unless a closed end covers it
+    let (mut current, mut uncovered) = (0., true);
+    let mut gaps = Vec::new();
+    for span in &periodic.pattern {
+        if span.lo > current {
+            return IntervalSet::real_line().restricted(periodic);
+        }
+        if uncovered && !span.lo_closed {
+            gaps.push(current);
+        }
+        (current, uncovered) = (span.hi, !span.hi_closed);
+    }
+    if periodic.pattern.is_empty() || current < period {
+        return IntervalSet::real_line().restricted(periodic);
+    }
+    gaps.into_iter().fold(IntervalSet::real_line(), |set, gap| {
+        set.excluding(Lattice::new(gap, period, false))
+    })
+}
+
+// the part of the span that is solved numerically, where an infinite end is cut off at ±WINDOW
+// (or WINDOW beyond the other end of the span)
+fn window(span: &Span) -> (f64, f64) {
+    (
+        if span.lo.is_finite() {
+            span.lo
+        } else {
+            (-WINDOW).min(span.hi - WINDOW)
+        },
+        if span.hi.is_finite() {
+            span.hi
+        } else {
+            WINDOW.max(span.lo + WINDOW)
+        },
+    )
+}
+
+// the points of the domain of the function at which it takes a value in the allowed set, which
+// must not exclude any lattices
+fn preimage(function: &Elementary, allowed: &IntervalSet, domain: &IntervalSet) -> IntervalSet {
+    // the step functions take the integer values, so the constraint is moved onto their argument
+    // (e.g. floor(f(x)) ≠ 0 where f(x) ∉ [0, 1))
+    if let Floor(func) | Ceil(func) = function {
+        let allowed = step_preimage(allowed, matches!(function, Ceil(_)));
+        return preimage(func, &allowed, domain);
+    }
+
+    if function.is_constant() {
+        return if allowed.contains(function.clone().call()(0.)) {
+            domain.clone()
+        } else {
+            IntervalSet::empty()
+        };
+    }
+
+    // periodic functions are solved on a single period, [0, period)
+    if let Some(period) = period(function) {
+        let single = domain.intersection(&Span::new(0., period, true, false).into());
+        let preimage = preimage_on_spans(function, allowed, &single);
+        return domain.intersection(&periodic_set(preimage.spans(), period));
+    }
+    // the other functions can't tell where a periodic restriction of the domain applies beyond
+    // the window, so the domain is cut off there
+    if domain.periodic().is_empty() {
+        preimage_on_spans(function, allowed, domain)
+    } else {
+        let bounded = domain.intersection(&Span::closed(-WINDOW, WINDOW).into());
+        preimage_on_spans(function, allowed, &bounded)
+    }
+}
+
+// the preimage, found from the points where the function crosses the ends of the allowed spans
+fn preimage_on_spans(
+    function: &Elementary,
+    allowed: &IntervalSet,
+    domain: &IntervalSet,
+) -> IntervalSet {
+    let call = function.clone().call();
+
+    // the points where the function reaches the ends of the allowed spans, along with whether
+    // the points themselves are allowed
+    let mut boundary_values: Vec<f64> = allowed
+        .spans()
+        .iter()
+        .flat_map(|span| [span.lo, span.hi])
+        .filter(|value| value.is_finite())
+        .collect();
+    boundary_values.dedup();
+    let mut boundary: Vec<(f64, bool)> = boundary_values
+        .iter()
+        .flat_map(|value| {
+            solve(function, *value, domain)
+                .into_iter()
+                .map(|point| (point, allowed.contains(*value)))
+        })
+        .collect();
+    boundary.sort_by(|point1, point2| point1.0.total_cmp(&point2.0));
+    boundary.dedup_by(|point1, point2| is_same_point(point1.0, point2.0));
+
+    // the rational functions are solved exactly, while the others are only solved on the window
+    let rational = RationalFunction::try_from(function).is_ok();
+
+    // between the boundary points, the function is either allowed everywhere or nowhere
+    let mut spans = Vec::new();
+    for span in domain.spans() {
+        let (lo, hi) = if rational {
+            (span.lo, span.hi)
+        } else {
+            window(span)
+        };
+        let points: Vec<(f64, bool)> = boundary
+            .iter()
+            .copied()
+            .filter(|(point, _)| span.contains(*point) && lo <= *point && *point <= hi)
+            .collect();
+
+        let mut start = lo;
+        for end in points.iter().map(|(point, _)| *point).chain([hi]) {
+            let piece = Span::open(start, end);
+            if !piece.is_empty() && allowed.contains(call(sample(&piece))) {
+                spans.push(piece);
+            }
+            start = end;
+        }
+
+        spans.extend(
+            points
+                .iter()
+                .filter(|(_, included)| *included)
+                .map(|(point, _)| Span::point(*point)),
+        );
+        // the points where the window cuts the span off lie within the span
+        for (end, closed) in [
+            (lo, span.lo_closed || lo != span.lo),
+            (hi, span.hi_closed || hi != span.hi),
+        ] {
+            if closed && allowed.contains(call(end)) {
+                spans.push(Span::point(end));
+            }
+        }
+
+        // beyond the window, the span is only kept where the enclosure of the function shows that
+        // it is allowed throughout
+        for tail in [Span::open(span.lo, lo), Span::open(hi, span.hi)] {
+            if !tail.is_empty() {
+                let range = function.eval_interval(Interval::new(tail.lo, tail.hi));
+                if !range.is_empty() && allowed.spans().iter().any(|span| covers(span, range)) {
+                    spans.push(tail);
+                }
+            }
+        }
+    }
+
+    let mut preimage = IntervalSet::from_spans(spans);
+    for lattice in domain.excluded() {
+        preimage = preimage.excluding(*lattice);
+    }
+    preimage
+}
+
+// whether the span contains every value of the interval, where the infinite ends of the interval
+// stand for arbitrarily large values
+fn covers(span: &Span, interval: Interval) -> bool {
+    let lo = span.lo < interval.lo
+        || span.lo == interval.lo && (span.lo_closed || span.lo.is_infinite());
+    let hi = interval.hi < span.hi
+        || span.hi == interval.hi && (span.hi_closed || span.hi.is_infinite());
+    lo && hi
+}
+
+// the values whose floor (or ceiling) lies in the allowed set: floor(y) = n for y in [n, n + 1) and
+// ceil(y) = n for y in (n - 1, n]
+fn step_preimage(allowed: &IntervalSet, ceil: bool) -> IntervalSet {
+    IntervalSet::from_spans(
+        allowed
+            .spans()
+            .iter()
+            .filter_map(|span| {
+                // the smallest and largest integers within the span
+                let lo = if span.lo_closed {
+                    span.lo.ceil()
+                } else {
+                    span.lo.floor() + 1.
+                };
+                let hi = if span.hi_closed {
+                    span.hi.floor()
+                } else {
+                    span.hi.ceil() - 1.
+                };
+                match (lo <= hi, ceil) {
+                    (false, _) => None,
+                    (true, false) => Some(Span::new(lo, hi + 1., lo.is_finite(), false)),
+                    (true, true) => Some(Span::new(lo - 1., hi, false, hi.is_finite())),
+                }
+            })
+            .collect(),
+    )
+}
+
+// the points of the domain of the function at which it takes the provided value
+fn solve(function: &Elementary, value: f64, domain: &IntervalSet) -> Vec<f64> {
+    // f(x) = p(x)/q(x) = value at the roots of p(x) - value * q(x)
+    if let Ok(rational) = RationalFunction::try_from(function) {
+        let polynomial = rational.numerator().clone() - rational.denominator().clone() * value;
+        if polynomial.degree() == 0 {
+            return Vec::new();
+        }
+        // Cauchy's bound on the absolute values of the roots
+        let leading = polynomial.leading_coefficient();
+        let bound = 1.
+            + polynomial.coefficients()[..polynomial.degree()]
+                .iter()
+                .map(|coefficient| (coefficient / leading).abs())
+                .fold(0., f64::max);
+        return polynomial
+            .real_roots(-bound, bound)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|root| root.value)
+            .collect();
+    }
+
+    let difference = function.clone() - Con(value);
+    numerical_roots(&difference, domain)
+}
+
+// the points of the domain of the function at which it doesn't take a value on the lattice
+fn lattice_preimage(function: &Elementary, lattice: Lattice, domain: &IntervalSet) -> IntervalSet {
+    if function.is_constant() {
+        return if lattice.contains(function.clone().call()(0.)) {
+            IntervalSet::empty()
+        } else {
+            domain.clone()
+        };
+    }
+
+    // a x + b = offset + k * period for x = (offset - b)/a + k * period/a
+    if let Ok(polynomial) = Polynomial::try_from(function) {
+        if polynomial.degree() == 1 {
+            let (b, a) = (polynomial.coefficients()[0], polynomial.coefficients()[1]);
+            return domain.clone().excluding(Lattice::new(
+                (lattice.offset - b) / a,
+                lattice.period / a,
+                lattice.one_sided,
+            ));
+        }
+    }
+
+    // the step functions only take the integers on the lattice, so the lattice is replaced by
+    // them: a lattice with a period of ±1 consists of integers if its offset is one, while those
+    // with a period that is a multiple of π contain at most the integer at their offset
+    if let Floor(_) | Ceil(_) = function {
+        let allowed = match (lattice.period.abs() == 1., lattice.offset.fract() == 0.) {
+            (true, true) if !lattice.one_sided => Some(IntervalSet::empty()),
+            (true, true) if lattice.period < 0. => {
+                Some(Span::new(lattice.offset, f64::INFINITY, false, false).into())
+            }
+            (true, true) => Some(Span::new(f64::NEG_INFINITY, lattice.offset, false, false).into()),
+            (true, false) => Some(IntervalSet::real_line()),
+            (false, _) if lattice.period.fract() == 0. => None,
+            (false, true) => Some(IntervalSet::from_spans(vec![
+                Span::open(f64::NEG_INFINITY, lattice.offset),
+                Span::open(lattice.offset, f64::INFINITY),
+            ])),
+            (false, false) => Some(IntervalSet::real_line()),
+        };
+        if let Some(allowed) = allowed {
+            return preimage(function, &allowed, domain);
+        }
+    }
+
+    // periodic functions take values on the lattice at the points of lattices with their period
+    if let Some(period) = period(function) {
+        let single = domain.intersection(&Span::new(0., period, true, false).into());
+        let (points, _) = lattice_crossings(function, lattice, &single);
+        return points.into_iter().fold(domain.clone(), |domain, point| {
+            domain.excluding(Lattice::new(point, period, false))
+        });
+    }
+
+    let domain = if domain.periodic().is_empty() {
+        domain.clone()
+    } else {
+        domain.intersection(&Span::closed(-WINDOW, WINDOW).into())
+    };
+    let (points, kept) = lattice_crossings(function, lattice, &domain);
+    domain
+        .intersection(&IntervalSet::from_spans(kept))
+        .without_points(&points)
+}
+
+// the points of the domain at which the function takes a value on the lattice, along with the
+// parts of the domain on which they are known
+fn lattice_crossings(
+    function: &Elementary,
+    lattice: Lattice,
+    domain: &IntervalSet,
+) -> (Vec<f64>, Vec<Span>) {
+    // the range of the function on each span of the domain bounds the points of the lattice it
+    // may reach, and each of them is solved for separately, which finds the crossings however
+    // densely they are packed (such as those of x^2 near the ends of the window)
+    let mut points = Vec::new();
+    let mut kept = Vec::new();
+    for span in domain.spans() {
+        let (lo, hi) = window(span);
+        kept.push(Span::closed(lo, hi));
+        // beyond the window, the span is only kept if the function can't reach the lattice there
+        for tail in [Span::open(span.lo, lo), Span::open(hi, span.hi)] {
+            if !tail.is_empty()
+                && lattice_values(
+                    lattice,
+                    function.eval_interval(Interval::new(tail.lo, tail.hi)),
+                )
+                .is_some_and(|values| values.is_empty())
+            {
+                kept.push(tail);
+            }
+        }
+
+        let span_domain = domain.intersection(&Span::closed(lo, hi).into());
+        match lattice_values(lattice, function.eval_interval(Interval::new(lo, hi))) {
+            Some(values) => points.extend(
+                values
+                    .iter()
+                    .flat_map(|value| solve(function, *value, &span_domain))
+                    .filter(|point| span_domain.contains(*point)),
+            ),
+            // otherwise the function takes a value on the lattice where
+            // sin(π (f(x) - offset)/period) = 0, which misses the crossings that are closer
+            // together than the roots can be told apart
+            None => {
+                let index = Mul(
+                    Arc::new(Con(1. / lattice.period)),
+                    Arc::new(function.clone() - Con(lattice.offset)),
+                );
+                let call = function.clone().call();
+                points.extend(
+                    numerical_roots(&Sin(Arc::new(Con(PI) * index)), &span_domain)
+                        .into_iter()
+                        .filter(|point| lattice.contains(call(*point))),
+                );
+            }
+        }
+    }
+    (points, kept)
+}
+
+// the points of the lattice within the range, as long as there are few enough of them
+fn lattice_values(lattice: Lattice, range: Interval) -> Option<Vec<f64>> {
+    if range.is_empty() {
+        return Some(Vec::new());
+    }
+    if !(range.lo.is_finite() && range.hi.is_finite()) {
+        return None;
+    }
+
+    // the indices k of the points offset + k * period within the range
+    let (start, end) = (
+        (range.lo - lattice.offset) / lattice.period,
+        (range.hi - lattice.offset) / lattice.period,
+    );
+    let (mut first, last) = (start.min(end).floor(), start.max(end).ceil());
+    if lattice.one_sided {
+        first = first.max(0.);
+    }
+    if last - first > MAX_CROSSINGS {
+        return None;
+    }
+    Some(
+        (first as i64..=last as i64)
+            .map(|index| lattice.offset + index as f64 * lattice.period)
+            .collect(),
+    )
+}
+
+// the roots of the function within the domain and the window
+fn numerical_roots(function: &Elementary, domain: &IntervalSet) -> Vec<f64> {
+    domain
+        .spans()
+        .iter()
+        .flat_map(|span| {
+            let (lo, hi) = window(span);
+            if lo < hi {
+                function.find_all_roots(lo, hi).unwrap_or_default()
+            } else {
+                Vec::new()
+            }
+        })
+        .map(|root| root.value)
+        .filter(|point| domain.contains(*point))
+        .collect()
+}
+
+// a point within the (non-empty) open span
+fn sample(span: &Span) -> f64 {
+    match (span.lo.is_finite(), span.hi.is_finite()) {
+        (true, true) => (span.lo + span.hi) / 2.,
+        (true, false) => span.lo + 1.,
+        (false, true) => span.hi - 1.,
+        (false, false) => 0.,
+    }
+}
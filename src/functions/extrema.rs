@@ -0,0 +1,183 @@
+use crate::{Elementary, Error, Function};
+
+/// The nature of a [CriticalPoint](crate::CriticalPoint).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CriticalPointKind {
+    LocalMinimum,
+    LocalMaximum,
+    /// A point where the derivative vanishes without the function attaining an extremum, such as
+    /// x = 0 for x³.
+    Saddle,
+}
+
+/// A point x where the derivative of a function vanishes, i.e. f'(x) = 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CriticalPoint {
+    pub x: f64,
+    pub value: f64,
+    pub kind: CriticalPointKind,
+}
+
+// the highest order derivative that is used when classifying a critical point
+const MAX_DERIVATIVE_ORDER: usize = 8;
+// derivatives whose absolute value is smaller than this are considered to vanish
+const VANISHING_TOLERANCE: f64 = 1e-6;
+
+impl Elementary {
+    /// Finds and classifies all critical points of the function within the interval [a, b].
+    pub fn critical_points(&self, a: f64, b: f64) -> Result<Vec<CriticalPoint>, Error> {
+        let derivative = self.clone().derivative_unsimplified();
+
+        // every point is a critical point of a constant function, so there is nothing to classify
+        if derivative.is_constant() {
+            return Ok(Vec::new());
+        }
+
+        let function = self.clone().call();
+        Ok(derivative
+            .find_all_roots(a, b)?
+            .into_iter()
+            .map(|root| CriticalPoint {
+                x: root.value,
+                value: function(root.value),
+                kind: self.classify_critical_point(root.value, &derivative),
+            })
+            .collect())
+    }
+
+    /// Returns the point (x, f(x)) at which the function attains its smallest value on the
+    /// interval [a, b].
+    pub fn global_min_on(&self, a: f64, b: f64) -> Result<(f64, f64), Error> {
+        self.global_extremum_on(a, b, |candidate, best| candidate < best)
+    }
+
+    /// Returns the point (x, f(x)) at which the function attains its largest value on the
+    /// interval [a, b].
+    pub fn global_max_on(&self, a: f64, b: f64) -> Result<(f64, f64), Error> {
+        self.global_extremum_on(a, b, |candidate, best| candidate > best)
+    }
+
+    // compares the values of the function at the endpoints and the critical points
+    fn global_extremum_on(
+        &self,
+        a: f64,
+        b: f64,
+        is_better: impl Fn(f64, f64) -> bool,
+    ) -> Result<(f64, f64), Error> {
+        let function = self.clone().call();
+
+        let mut candidates = vec![a, b];
+        candidates.extend(self.critical_points(a, b)?.iter().map(|point| point.x));
+
+        let mut best: Option<(f64, f64)> = None;
+        for x in candidates {
+            let value = function(x);
+            if !value.is_finite() {
+                continue;
+            }
+            match best {
+                Some((_, best_value)) if !is_better(value, best_value) => {}
+                _ => best = Some((x, value)),
+            }
+        }
+
+        best.ok_or(Error::InputError(format!(
+            "The function is not defined at the endpoints or the critical points of the interval [{a}, {b}]"
+        )))
+    }
+
+    // classifies the critical point using the first non-vanishing higher order derivative. If it
+    // is of even order, its sign decides whether the point is a minimum or a maximum. If it is of
+    // odd order, the point is a saddle point
+    fn classify_critical_point(&self, x: f64, first_derivative: &Elementary) -> CriticalPointKind {
+        let mut derivative = first_derivative.clone();
+
+        for order in 2..=MAX_DERIVATIVE_ORDER {
+            derivative = derivative.derivative_unsimplified();
+            let value = derivative.clone().call()(x);
+
+            if value.is_finite() && value.abs() >= VANISHING_TOLERANCE {
+                return if order % 2 == 1 {
+                    CriticalPointKind::Saddle
+                } else if value > 0. {
+                    CriticalPointKind::LocalMinimum
+                } else {
+                    CriticalPointKind::LocalMaximum
+                };
+            }
+        }
+
+        // all derivatives vanish (or are undefined), so the point is classified by comparing the
+        // value of the function to its neighbouring values instead
+        let function = self.clone().call();
+        let step = 1e-4 * x.abs().max(1.);
+        let (left, centre, right) = (function(x - step), function(x), function(x + step));
+        if left > centre && right > centre {
+            CriticalPointKind::LocalMinimum
+        } else if left < centre && right < centre {
+            CriticalPointKind::LocalMaximum
+        } else {
+            CriticalPointKind::Saddle
+        }
+    }
+}
+
+impl Function {
+    /// Finds all critical points of the function within the interval [a, b], i.e. all points
+    /// where the derivative of the function is zero.
+    ///
+    /// Every critical point is classified as a local minimum, a local maximum or a saddle point
+    /// using the second derivative. If the second derivative vanishes as well, higher order
+    /// derivatives are used instead.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{CriticalPointKind, Function};
+    /// // x³ - 3x has a local maximum at x = -1 and a local minimum at x = 1
+    /// let function = Function::from("x^3 - 3x");
+    ///
+    /// let critical_points = function.critical_points(-2., 2.).unwrap();
+    ///
+    /// assert_eq!(critical_points.len(), 2);
+    /// assert_eq!(critical_points[0].kind, CriticalPointKind::LocalMaximum);
+    /// assert_eq!(critical_points[1].kind, CriticalPointKind::LocalMinimum);
+    /// ```
+    pub fn critical_points(&self, a: f64, b: f64) -> Result<Vec<CriticalPoint>, Error> {
+        self.elementary().critical_points(a, b)
+    }
+
+    /// Returns the point (x, f(x)) at which the function attains its smallest value on the
+    /// closed interval [a, b]. Both the critical points and the endpoints of the interval are
+    /// taken into account.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("x^2");
+    ///
+    /// let (x, value) = function.global_min_on(-1., 2.).unwrap();
+    /// assert!(x.abs() < 1e-10);
+    /// assert!(value.abs() < 1e-10);
+    ///
+    /// // the minimum is attained at the endpoint of the interval
+    /// assert_eq!(function.global_min_on(1., 2.).unwrap(), (1., 1.));
+    /// ```
+    pub fn global_min_on(&self, a: f64, b: f64) -> Result<(f64, f64), Error> {
+        self.elementary().global_min_on(a, b)
+    }
+
+    /// Returns the point (x, f(x)) at which the function attains its largest value on the
+    /// closed interval [a, b]. Both the critical points and the endpoints of the interval are
+    /// taken into account.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("x^2");
+    ///
+    /// assert_eq!(function.global_max_on(-1., 2.).unwrap(), (2., 4.));
+    /// ```
+    pub fn global_max_on(&self, a: f64, b: f64) -> Result<(f64, f64), Error> {
+        self.elementary().global_max_on(a, b)
+    }
+}
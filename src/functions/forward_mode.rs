@@ -0,0 +1,83 @@
+use crate::{Dual, Elementary, Function, HyperDual, Jet};
+
+impl Elementary {
+    /// Evaluates the function at a [Dual](crate::Dual) number. Evaluating at
+    /// [Dual::variable(x)](crate::Dual::variable) yields the value of the function and its
+    /// derivative at x.
+    ///
+    /// Like [call()](crate::Elementary::call), this panics if the function is a pattern
+    /// containing [wildcards](crate::Elementary::Wildcard), since a pattern has no value.
+    pub fn call_dual(&self, x: Dual) -> Dual {
+        self.eval(x)
+    }
+
+    /// Evaluates the function at a [HyperDual](crate::HyperDual) number. Evaluating at
+    /// [HyperDual::variable(x)](crate::HyperDual::variable) yields the value of the function as
+    /// well as its first and second derivatives at x.
+    ///
+    /// Panics if the function is a pattern, just like [call_dual()](crate::Elementary::call_dual).
+    pub fn call_hyper_dual(&self, x: HyperDual) -> HyperDual {
+        self.eval(x)
+    }
+
+    /// Evaluates the function at a [Jet](crate::Jet), i.e. a truncated Taylor series. Evaluating
+    /// at [Jet::variable(x, n)](crate::Jet::variable) yields all derivatives of the function up
+    /// to the nth at x.
+    ///
+    /// Panics if the function is a pattern, just like [call_dual()](crate::Elementary::call_dual).
+    pub fn call_jet(&self, x: &Jet) -> Jet {
+        self.eval(x.clone())
+    }
+}
+
+impl Function {
+    /// Returns the value of the function and its derivative at x.
+    ///
+    /// The derivative is found using [dual
+    /// numbers](https://en.wikipedia.org/wiki/Automatic_differentiation), which is exact up to
+    /// floating point precision and doesn't require building the symbolic derivative.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("x^2*sin(x)");
+    ///
+    /// let (value, derivative) = function.value_and_derivative(2.);
+    ///
+    /// assert_eq!(value, 4. * 2_f64.sin());
+    /// assert!((derivative - (4. * 2_f64.sin() + 4. * 2_f64.cos())).abs() < 1e-15);
+    /// ```
+    pub fn value_and_derivative(&self, x: f64) -> (f64, f64) {
+        let dual = self.elementary().call_dual(Dual::variable(x));
+        (dual.value, dual.derivative)
+    }
+
+    /// Returns the value of the function and its first and second derivatives at x, found using
+    /// [HyperDual](crate::HyperDual) numbers.
+    pub fn value_and_second_derivative(&self, x: f64) -> (f64, f64, f64) {
+        let hyper_dual = self.elementary().call_hyper_dual(HyperDual::variable(x));
+        (
+            hyper_dual.value,
+            hyper_dual.derivative(),
+            hyper_dual.second_derivative(),
+        )
+    }
+
+    /// Returns the value of the function followed by its first n derivatives at x, i.e. [f(x),
+    /// f'(x), ..., f⁽ⁿ⁾(x)].
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("e^(2*x)");
+    ///
+    /// let derivatives = function.value_and_derivatives(0., 4);
+    ///
+    /// assert_eq!(derivatives, vec![1., 2., 4., 8., 16.]);
+    /// ```
+    pub fn value_and_derivatives(&self, x: f64, n: usize) -> Vec<f64> {
+        self.elementary()
+            .call_jet(&Jet::variable(x, n))
+            .derivatives()
+    }
+}
@@ -52,6 +52,9 @@ pub trait Integrate {
 }
 
 const STANDARD_PRECISION: usize = 1000;
+// the pieces between breakpoints are integrated this far (relative to their width) inside their
+// ends, so that the values at the ends belong to the branch that applies within the piece
+const BREAKPOINT_OFFSET: f64 = 1e-12;
 
 /// See [Integrate documentation](crate::Integrate) for usage and examples
 pub struct Integral {
@@ -59,6 +62,8 @@ pub struct Integral {
     lower_bound: Option<f64>,
     upper_bound: Option<f64>,
     precision: usize,
+    // the function that is integrated, if it is known, which is split at its breakpoints
+    elementary: Option<Elementary>,
 }
 
 impl Integral {
@@ -68,6 +73,7 @@ impl Integral {
             lower_bound: None,
             upper_bound: None,
             precision: STANDARD_PRECISION,
+            elementary: None,
         }
     }
 
@@ -88,12 +94,51 @@ impl Integral {
 
     pub fn evaluate(&self) -> Result<f64, Error> {
         if let (Some(lower_bound), Some(upper_bound)) = (self.lower_bound, self.upper_bound) {
-            Ok(simpsons_rule(
-                &self.function,
-                lower_bound,
-                upper_bound,
-                self.precision,
-            ))
+            let breakpoints = match &self.elementary {
+                Some(elementary) if lower_bound.is_finite() && upper_bound.is_finite() => {
+                    elementary
+                        .breakpoints(lower_bound.min(upper_bound), lower_bound.max(upper_bound))
+                }
+                _ => Vec::new(),
+            };
+            let inner: Vec<f64> = breakpoints
+                .into_iter()
+                .filter(|x| lower_bound.min(upper_bound) < *x && *x < lower_bound.max(upper_bound))
+                .collect();
+            if inner.is_empty() {
+                return Ok(simpsons_rule(
+                    &self.function,
+                    lower_bound,
+                    upper_bound,
+                    self.precision,
+                ));
+            }
+
+            // Simpson's rule assumes a smooth function, so the function is integrated separately
+            // between its breakpoints, where it may jump or have a kink
+            let mut points = vec![lower_bound.min(upper_bound)];
+            points.extend(inner);
+            points.push(lower_bound.max(upper_bound));
+            let width = points[points.len() - 1] - points[0];
+            let value: f64 = points
+                .windows(2)
+                .map(|piece| {
+                    let offset = (piece[1] - piece[0]) * BREAKPOINT_OFFSET;
+                    let precision =
+                        (self.precision as f64 * (piece[1] - piece[0]) / width).ceil() as usize;
+                    simpsons_rule(
+                        &self.function,
+                        piece[0] + offset,
+                        piece[1] - offset,
+                        precision.max(1),
+                    )
+                })
+                .sum();
+            Ok(if lower_bound > upper_bound {
+                -value
+            } else {
+                value
+            })
         } else {
             Err(Error::InternalError(String::from(
                 "Bounds of integration must be set in order to evaluate the integral",
@@ -105,7 +150,10 @@ impl Integral {
 /// See [Integrate](crate::Integrate) for usage and examples.
 impl Integrate for Elementary {
     fn integrate(&self) -> Integral {
-        Integral::vacant(self.clone().call())
+        Integral {
+            elementary: Some(self.clone()),
+            ..Integral::vacant(self.clone().call())
+        }
     }
     /// Evaluating the integral gives a value of the integral with eight decimal places
     fn evaluate_integral(&self, lower_bound: f64, upper_bound: f64) -> f64 {
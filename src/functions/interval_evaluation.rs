@@ -0,0 +1,104 @@
+use crate::{
+    functions::calc::unbound_wildcard,
+    Elementary::{self, *},
+    Function, Interval,
+};
+
+impl Elementary {
+    /// Evaluates the function on every point of the provided [Interval](crate::Interval) at
+    /// once, returning an interval that is guaranteed to contain f(x) for every x in the interval
+    /// where f is defined.
+    ///
+    /// The enclosure may be wider than the exact range of the function, most notably when the same
+    /// variable occurs several times (x - x evaluates to [-1, 1] on [0, 1] rather than to 0).
+    pub fn eval_interval(&self, x: Interval) -> Interval {
+        match self {
+            Sin(func) => func.eval_interval(x).sin(),
+            Cos(func) => func.eval_interval(x).cos(),
+            Tan(func) => func.eval_interval(x).tan(),
+            Sec(func) => func.eval_interval(x).sec(),
+            Csc(func) => func.eval_interval(x).csc(),
+            Cot(func) => func.eval_interval(x).cot(),
+            Asin(func) => func.eval_interval(x).asin(),
+            Acos(func) => func.eval_interval(x).acos(),
+            Atan(func) => func.eval_interval(x).atan(),
+            Acot(func) => func.eval_interval(x).acot(),
+            Asec(func) => func.eval_interval(x).asec(),
+            Acsc(func) => func.eval_interval(x).acsc(),
+            Sinh(func) => func.eval_interval(x).sinh(),
+            Cosh(func) => func.eval_interval(x).cosh(),
+            Tanh(func) => func.eval_interval(x).tanh(),
+            Sech(func) => func.eval_interval(x).sech(),
+            Csch(func) => func.eval_interval(x).csch(),
+            Coth(func) => func.eval_interval(x).coth(),
+            Asinh(func) => func.eval_interval(x).asinh(),
+            Acosh(func) => func.eval_interval(x).acosh(),
+            Atanh(func) => func.eval_interval(x).atanh(),
+
+            Add(func1, func2) => func1.eval_interval(x) + func2.eval_interval(x),
+            Sub(func1, func2) => func1.eval_interval(x) - func2.eval_interval(x),
+            Mul(func1, func2) => func1.eval_interval(x) * func2.eval_interval(x),
+            Div(func1, func2) => func1.eval_interval(x) / func2.eval_interval(x),
+            Pow(base, exp) => match &**exp {
+                // exponents that are exactly representable keep the power defined for negative
+                // bases, e.g. x^2 and x^(1/2)
+                Con(exp) => base.eval_interval(x).powf(*exp),
+                Rat(rational) if (rational.denominator() as u128).is_power_of_two() => {
+                    base.eval_interval(x).powf(rational.to_f64())
+                }
+                _ => base.eval_interval(x).pow(exp.eval_interval(x)),
+            },
+            Log(base, func) => func.eval_interval(x).ln() / base.eval_interval(x).ln(),
+
+            Factorial(func) => (func.eval_interval(x) + Interval::point(1.)).gamma(),
+            Gamma(func) => func.eval_interval(x).gamma(),
+            Polygamma(func, order) => func.eval_interval(x).polygamma(*order),
+            Abs(func) => func.eval_interval(x).abs(),
+            Cbrt(func) => func.eval_interval(x).cbrt(),
+            Floor(func) => func.eval_interval(x).floor(),
+            Ceil(func) => func.eval_interval(x).ceil(),
+            // the enclosure covers every branch that may apply somewhere on the interval
+            Piecewise(branches, otherwise) => {
+                let mut enclosure = Interval::empty();
+                for (condition, func) in branches {
+                    let difference =
+                        condition.lhs.eval_interval(x) - condition.rhs.eval_interval(x);
+                    match condition.relation.holds_on(difference) {
+                        Some(true) => return enclosure.hull(&func.eval_interval(x)),
+                        Some(false) => {}
+                        None => enclosure = enclosure.hull(&func.eval_interval(x)),
+                    }
+                }
+                enclosure.hull(&otherwise.eval_interval(x))
+            }
+
+            Con(numb) => Interval::point(*numb),
+            Rat(rational) => Interval::point(rational.to_f64()).widen(1),
+            Sym(constant) => Interval::point(constant.value()).widen(1),
+            X => x,
+            Wildcard(name) => panic!("{}", unbound_wildcard(name)),
+        }
+    }
+}
+
+impl Function {
+    /// Returns an [Interval](crate::Interval) that is guaranteed to contain the value of the
+    /// function at every point of [lo, hi] where it is defined.
+    ///
+    /// Since the bounds are rigorous, they can be used to prove properties of the function that
+    /// sampling can't, such as the function having no roots on the interval.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("x^2 + sin(x) + 2");
+    ///
+    /// let bounds = function.eval_interval(-10., 10.);
+    ///
+    /// // the function is positive everywhere on [-10, 10]
+    /// assert!(bounds.lo > 0.);
+    /// ```
+    pub fn eval_interval(&self, lo: f64, hi: f64) -> Interval {
+        self.elementary().eval_interval(Interval::new(lo, hi))
+    }
+}
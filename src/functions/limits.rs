@@ -0,0 +1,997 @@
+use std::{f64::consts::PI, fmt, sync::Arc};
+
+use crate::{
+    Condition, Constant,
+    Elementary::{self, *},
+    Error, Function,
+};
+
+/// The side from which x approaches the point of a limit.
+///
+/// See [Function::limit()](crate::Function::limit) for usage and examples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Direction {
+    /// x approaches the point from below, i.e. x ⟶ a⁻
+    Left,
+    /// x approaches the point from above, i.e. x ⟶ a⁺
+    Right,
+    /// Both one-sided limits must exist and be equal, i.e. x ⟶ a
+    Both,
+}
+
+// the value of a limit as tracked internally. Unlike the public methods (that report divergent
+// limits as errors), the engine has to keep track of the sign of a divergent limit in order to
+// resolve indeterminate forms such as ∞/∞ or 0*∞
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Limit {
+    Finite(f64),
+    PositiveInfinity,
+    NegativeInfinity,
+}
+
+// the value that the independent variable approaches
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Target {
+    // the direction is always either Direction::Left or Direction::Right
+    Point(f64, Direction),
+    PositiveInfinity,
+    NegativeInfinity,
+}
+
+// the maximum number of times that L'Hôpital's rule is applied in a row before falling back to a
+// series expansion
+const MAX_LHOPITAL_DEPTH: usize = 6;
+// the order of the Taylor expansions used when L'Hôpital's rule fails
+const SERIES_ORDER: u8 = 6;
+// values closer to zero than this are treated as zero when looking for indeterminate forms
+const ZERO_TOLERANCE: f64 = 1e-12;
+// the tolerance used when judging whether a sequence of sampled values converges
+const NUMERIC_TOLERANCE: f64 = 1e-6;
+// trigonometric functions evaluated at their poles return very large (but finite) values due to
+// floating point rounding
+const POLE_THRESHOLD: f64 = 1e12;
+
+impl Limit {
+    fn value(&self) -> f64 {
+        match self {
+            Self::Finite(value) => *value,
+            Self::PositiveInfinity => f64::INFINITY,
+            Self::NegativeInfinity => f64::NEG_INFINITY,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        matches!(self, Self::Finite(value) if value.abs() < ZERO_TOLERANCE)
+    }
+
+    pub(crate) fn is_infinite(&self) -> bool {
+        !matches!(self, Self::Finite(_))
+    }
+
+    fn signum(&self) -> f64 {
+        self.value().signum()
+    }
+
+    // returns an infinite limit with the provided sign
+    fn infinity(sign: f64) -> Self {
+        if sign < 0. {
+            Self::NegativeInfinity
+        } else {
+            Self::PositiveInfinity
+        }
+    }
+
+    // converts the limit into the value returned by the public methods
+    fn finite(self, func: &Elementary, target: Target) -> Result<f64, Error> {
+        match self {
+            // avoid returning negative zero
+            Self::Finite(value) => Ok(value + 0.),
+            infinite => Err(Error::LimitError(format!(
+                "The limit of {func:?} diverges to {infinite} as {target}"
+            ))),
+        }
+    }
+
+    fn negate(self) -> Self {
+        match self {
+            Self::Finite(value) => Self::Finite(-value),
+            Self::PositiveInfinity => Self::NegativeInfinity,
+            Self::NegativeInfinity => Self::PositiveInfinity,
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Finite(value) => write!(f, "{value}"),
+            Self::PositiveInfinity => write!(f, "+∞"),
+            Self::NegativeInfinity => write!(f, "-∞"),
+        }
+    }
+}
+
+impl Target {
+    // returns the kth point in a sequence of points approaching the target
+    fn sample(&self, k: i32) -> f64 {
+        match self {
+            Self::Point(point, direction) => {
+                let step = 10_f64.powi(-k) * point.abs().max(1.);
+                if *direction == Direction::Left {
+                    point - step
+                } else {
+                    point + step
+                }
+            }
+            Self::PositiveInfinity => 10_f64.powi(k),
+            Self::NegativeInfinity => -(10_f64.powi(k)),
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Point(point, Direction::Left) => write!(f, "x → {point}⁻"),
+            Self::Point(point, Direction::Right) => write!(f, "x → {point}⁺"),
+            Self::Point(point, Direction::Both) => write!(f, "x → {point}"),
+            Self::PositiveInfinity => write!(f, "x → +∞"),
+            Self::NegativeInfinity => write!(f, "x → -∞"),
+        }
+    }
+}
+
+impl Elementary {
+    /// Returns the limit of the function as x approaches the provided point from the provided
+    /// [Direction](crate::Direction).
+    ///
+    /// If the limit diverges or does not exist, an [Error::LimitError](crate::Error) is returned.
+    pub fn limit(&self, point: f64, direction: Direction) -> Result<f64, Error> {
+        if direction != Direction::Both {
+            let target = Target::Point(point, direction);
+            return self.limit_towards(target)?.finite(self, target);
+        }
+
+        let left = self.limit_towards(Target::Point(point, Direction::Left))?;
+        let right = self.limit_towards(Target::Point(point, Direction::Right))?;
+
+        match (left, right) {
+            (Limit::Finite(left_value), Limit::Finite(right_value))
+                if (left_value - right_value).abs()
+                    <= NUMERIC_TOLERANCE * (1. + left_value.abs().max(right_value.abs())) =>
+            {
+                Ok(right_value)
+            }
+            _ if left == right => right.finite(self, Target::Point(point, Direction::Both)),
+            _ => Err(Error::LimitError(format!(
+                "The limit of {self:?} does not exist as x → {point}. The left-hand limit is {left} while the right-hand limit is {right}"
+            ))),
+        }
+    }
+
+    /// Returns the limit of the function as x approaches positive infinity.
+    ///
+    /// If the limit diverges or does not exist, an [Error::LimitError](crate::Error) is returned.
+    pub fn limit_at_infinity(&self) -> Result<f64, Error> {
+        self.limit_towards(Target::PositiveInfinity)?
+            .finite(self, Target::PositiveInfinity)
+    }
+
+    /// Returns the limit of the function as x approaches negative infinity.
+    ///
+    /// If the limit diverges or does not exist, an [Error::LimitError](crate::Error) is returned.
+    pub fn limit_at_negative_infinity(&self) -> Result<f64, Error> {
+        self.limit_towards(Target::NegativeInfinity)?
+            .finite(self, Target::NegativeInfinity)
+    }
+
+    // computes the (possibly infinite) limit of the function as x approaches the target
+    pub(crate) fn limit_towards(&self, target: Target) -> Result<Limit, Error> {
+        // a function that is undefined on the approached side cannot have a limit there. The values
+        // may also be NaN because of overflow (such as e^(-x) x^100 = 0 * ∞ for large x), so the
+        // side is only rejected if the domain excludes it as well
+        let callable = self.clone().call();
+        if (4..=8).all(|k| callable(target.sample(k)).is_nan()) {
+            let domain = self.domain();
+            if !(4..=8).any(|k| domain.contains(target.sample(k))) {
+                return Err(Error::LimitError(format!(
+                    "{self:?} is undefined as {target}, meaning that the limit does not exist"
+                )));
+            }
+        }
+
+        self.limit_value(target, 0)
+    }
+
+    fn limit_value(&self, target: Target, depth: usize) -> Result<Limit, Error> {
+        match self {
+            X => Ok(match target {
+                Target::Point(point, _) => Limit::Finite(point),
+                Target::PositiveInfinity => Limit::PositiveInfinity,
+                Target::NegativeInfinity => Limit::NegativeInfinity,
+            }),
+            Con(numb) => Ok(Limit::Finite(*numb)),
+            Rat(rational) => Ok(Limit::Finite(rational.to_f64())),
+            Sym(constant) => Ok(Limit::Finite(constant.value())),
+
+            Add(func1, func2) => self.limit_sum(func1, func2, 1., target, depth),
+            Sub(func1, func2) => self.limit_sum(func1, func2, -1., target, depth),
+            Mul(func1, func2) => self.limit_product(func1, func2, target, depth),
+            Div(func1, func2) => self.limit_quotient(func1, func2, target, depth),
+            Pow(func1, func2) => self.limit_power(func1, func2, target, depth),
+            Log(func1, func2) => self.limit_logarithm(func1, func2, target, depth),
+
+            Sin(func)
+            | Cos(func)
+            | Tan(func)
+            | Sec(func)
+            | Csc(func)
+            | Cot(func)
+            | Asin(func)
+            | Acos(func)
+            | Atan(func)
+            | Acot(func)
+            | Asec(func)
+            | Acsc(func)
+            | Sinh(func)
+            | Cosh(func)
+            | Tanh(func)
+            | Sech(func)
+            | Csch(func)
+            | Coth(func)
+            | Asinh(func)
+            | Acosh(func)
+            | Atanh(func)
+            | Factorial(func)
+            | Gamma(func)
+            | Polygamma(func, _)
+            | Abs(func)
+            | Cbrt(func)
+            | Floor(func)
+            | Ceil(func) => self.limit_composition(func, target, depth),
+
+            Piecewise(branches, otherwise) => {
+                self.limit_piecewise(branches, otherwise, target, depth)
+            }
+
+            Wildcard(_) => Err(Error::LimitError(format!(
+                "{self:?} is a pattern, which has no limit"
+            ))),
+        }
+    }
+
+    fn limit_sum(
+        &self,
+        func1: &Arc<Elementary>,
+        func2: &Arc<Elementary>,
+        sign: f64,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        let (limit1, limit2) = match (
+            func1.limit_value(target, depth),
+            func2.limit_value(target, depth),
+        ) {
+            (Ok(limit1), Ok(limit2)) => (limit1, limit2),
+            _ => return self.numeric_limit(target),
+        };
+        let limit2 = if sign < 0. { limit2.negate() } else { limit2 };
+
+        match (limit1, limit2) {
+            (Limit::Finite(value1), Limit::Finite(value2)) => Ok(Limit::Finite(value1 + value2)),
+            (Limit::Finite(_), infinite) | (infinite, Limit::Finite(_)) => Ok(infinite),
+            (infinite1, infinite2) if infinite1 == infinite2 => Ok(infinite1),
+            // ∞ - ∞ is an indeterminate form
+            _ => self.numeric_limit(target),
+        }
+    }
+
+    fn limit_product(
+        &self,
+        func1: &Arc<Elementary>,
+        func2: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        // a function that is identically zero will remain zero no matter what it's multiplied by
+        if func1.is_zero_constant() || func2.is_zero_constant() {
+            return Ok(Limit::Finite(0.));
+        }
+
+        let (limit1, limit2) = match (
+            func1.limit_value(target, depth),
+            func2.limit_value(target, depth),
+        ) {
+            (Ok(limit1), Ok(limit2)) => (limit1, limit2),
+            // squeeze theorem: a bounded function times a function approaching zero
+            (Err(_), Ok(limit2)) if func1.is_bounded() && limit2.is_zero() => {
+                return Ok(Limit::Finite(0.))
+            }
+            (Ok(limit1), Err(_)) if func2.is_bounded() && limit1.is_zero() => {
+                return Ok(Limit::Finite(0.))
+            }
+            _ => return self.numeric_limit(target),
+        };
+
+        match (limit1, limit2) {
+            (Limit::Finite(value1), Limit::Finite(value2)) => Ok(Limit::Finite(value1 * value2)),
+            (zero, _) | (_, zero) if zero.is_zero() => {
+                // 0*∞ is an indeterminate form which can be rewritten as either 0/0 or ∞/∞.
+                // Logarithms are kept in the numerator since the reciprocal of a logarithm yields
+                // derivatives that only get more complicated
+                let (zero_factor, infinite_factor) = if limit1.is_zero() {
+                    (func1, func2)
+                } else {
+                    (func2, func1)
+                };
+
+                let zero_over_zero = Div(
+                    zero_factor.clone(),
+                    Arc::new(Div(Arc::new(Con(1.)), infinite_factor.clone())),
+                );
+                let infinity_over_infinity = Div(
+                    infinite_factor.clone(),
+                    Arc::new(Div(Arc::new(Con(1.)), zero_factor.clone())),
+                );
+
+                let rewritten = if infinite_factor.contains_logarithm() {
+                    [infinity_over_infinity, zero_over_zero]
+                } else {
+                    [zero_over_zero, infinity_over_infinity]
+                };
+                rewritten
+                    .iter()
+                    .find_map(|quotient| quotient.limit_value(target, depth).ok())
+                    .or_else(|| self.logarithmic_product(func1, func2, target, depth).ok())
+                    .map_or_else(|| self.numeric_limit(target), Ok)
+            }
+            (limit1, limit2) => Ok(Limit::infinity(limit1.signum() * limit2.signum())),
+        }
+    }
+
+    // resolves 0*∞ by comparing the factors on a logarithmic scale, since ln|fg| = ln|f| + ln|g|.
+    // This covers products such as e^(-x) x^100, where L'Hôpital's rule would have to be applied
+    // a hundred times and where both factors overflow long before the product settles
+    fn logarithmic_product(
+        &self,
+        func1: &Arc<Elementary>,
+        func2: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        let log_magnitude = Add(
+            Arc::new(func1.log_magnitude()),
+            Arc::new(func2.log_magnitude()),
+        );
+
+        match log_magnitude.limit_value(target, depth)? {
+            Limit::NegativeInfinity => Ok(Limit::Finite(0.)),
+            Limit::Finite(value) => Ok(Limit::Finite(self.divergence_sign(target)? * value.exp())),
+            Limit::PositiveInfinity => self.divergence_sign(target).map(Limit::infinity),
+        }
+    }
+
+    fn limit_quotient(
+        &self,
+        numerator: &Arc<Elementary>,
+        denominator: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        if numerator.is_zero_constant() {
+            return Ok(Limit::Finite(0.));
+        }
+
+        let (limit1, limit2) = match (
+            numerator.limit_value(target, depth),
+            denominator.limit_value(target, depth),
+        ) {
+            (Ok(limit1), Ok(limit2)) => (limit1, limit2),
+            // squeeze theorem: a bounded function divided by a function approaching ±∞
+            (Err(_), Ok(limit2)) if numerator.is_bounded() && limit2.is_infinite() => {
+                return Ok(Limit::Finite(0.))
+            }
+            _ => return self.numeric_limit(target),
+        };
+
+        if (limit1.is_zero() && limit2.is_zero()) || (limit1.is_infinite() && limit2.is_infinite())
+        {
+            // indeterminate forms 0/0 and ∞/∞
+            return self.indeterminate_quotient(numerator, denominator, target, depth);
+        }
+
+        match (limit1, limit2) {
+            (_, zero) if zero.is_zero() => {
+                // a non-zero value divided by a value approaching zero. The sign of the divergent
+                // limit is found by looking at the values of the quotient close to the target
+                self.divergence_sign(target).map(Limit::infinity)
+            }
+            (Limit::Finite(value1), Limit::Finite(value2)) => Ok(Limit::Finite(value1 / value2)),
+            (Limit::Finite(_), _) => Ok(Limit::Finite(0.)),
+            (infinite, finite) => Ok(Limit::infinity(infinite.signum() * finite.signum())),
+        }
+    }
+
+    // resolves 0/0 and ∞/∞ using L'Hôpital's rule, falling back to series expansions
+    fn indeterminate_quotient(
+        &self,
+        numerator: &Arc<Elementary>,
+        denominator: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        if depth < MAX_LHOPITAL_DEPTH {
+            // the derivatives are tidied up before taking the limit of their quotient. Without
+            // this, ∞/∞ forms such as ln(x)/(1/x) would keep reappearing after differentiation
+            let quotient = Div(
+                Arc::new(
+                    (**numerator)
+                        .clone()
+                        .derivative_unsimplified()
+                        .fold_constants(),
+                ),
+                Arc::new(
+                    (**denominator)
+                        .clone()
+                        .derivative_unsimplified()
+                        .fold_constants(),
+                ),
+            )
+            .collect_factors();
+            if let Ok(limit) = quotient.limit_value(target, depth + 1) {
+                return Ok(limit);
+            }
+        }
+
+        if let Target::Point(point, direction) = target {
+            if let Some(limit) = series_quotient(numerator, denominator, point, direction) {
+                return Ok(limit);
+            }
+        }
+
+        self.numeric_limit(target)
+    }
+
+    fn limit_power(
+        &self,
+        base: &Arc<Elementary>,
+        exp: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        let (base_limit, exp_limit) = match (
+            base.limit_value(target, depth),
+            exp.limit_value(target, depth),
+        ) {
+            (Ok(base_limit), Ok(exp_limit)) => (base_limit, exp_limit),
+            _ => return self.numeric_limit(target),
+        };
+
+        if let (Limit::Finite(base_value), Limit::Finite(exp_value)) = (base_limit, exp_limit) {
+            let value = base_value.powf(exp_value);
+            if value.is_finite() && !(base_limit.is_zero() && exp_limit.is_zero()) {
+                return Ok(Limit::Finite(value));
+            }
+        }
+
+        if base_limit.signum() < 0. && !base_limit.is_zero() {
+            // negative bases are only defined for integer powers, in which case the sign of the
+            // power depends on the parity of the exponent
+            return match exp_limit {
+                Limit::Finite(exp_value) if exp_value.fract() == 0. => {
+                    let magnitude =
+                        Pow(Arc::new(Mul(base.clone(), Arc::new(Con(-1.)))), exp.clone())
+                            .limit_value(target, depth)?;
+                    if exp_value % 2. == 0. {
+                        Ok(magnitude)
+                    } else {
+                        Ok(magnitude.negate())
+                    }
+                }
+                _ => self.numeric_limit(target),
+            };
+        }
+
+        // f(x)^g(x) = e^(g(x)ln(f(x))) which turns the indeterminate forms 0⁰, ∞⁰ and 1^∞ into
+        // products
+        let exponent = Mul(
+            exp.clone(),
+            Arc::new(Log(Arc::new(Sym(Constant::E)), base.clone())),
+        );
+        match exponent.limit_value(target, depth)? {
+            Limit::Finite(value) => Ok(Limit::Finite(value.exp())),
+            Limit::PositiveInfinity => Ok(Limit::PositiveInfinity),
+            Limit::NegativeInfinity => Ok(Limit::Finite(0.)),
+        }
+    }
+
+    fn limit_logarithm(
+        &self,
+        base: &Arc<Elementary>,
+        argument: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        if !base.is_constant() {
+            // change of base to the natural logarithm
+            let quotient = Div(
+                Arc::new(Log(Arc::new(Sym(Constant::E)), argument.clone())),
+                Arc::new(Log(Arc::new(Sym(Constant::E)), base.clone())),
+            );
+            return quotient.limit_value(target, depth);
+        }
+
+        let base_value = (**base).clone().call()(0.);
+        let argument_limit = match argument.limit_value(target, depth) {
+            Ok(limit) => limit,
+            Err(_) => return self.numeric_limit(target),
+        };
+        let base_sign = base_value.ln().signum();
+
+        match argument_limit {
+            zero if zero.is_zero() => Ok(Limit::infinity(-base_sign)),
+            Limit::Finite(value) => Ok(Limit::Finite(value.log(base_value))),
+            Limit::PositiveInfinity => Ok(Limit::infinity(base_sign)),
+            Limit::NegativeInfinity => Err(Error::LimitError(format!(
+                "{self:?} is undefined as {target} since the logarithm of a negative number is undefined"
+            ))),
+        }
+    }
+
+    // the limit of a function applied to another function, e.g. sin(f(x))
+    fn limit_composition(
+        &self,
+        func: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        let inner_limit = match func.limit_value(target, depth) {
+            Ok(limit) => limit,
+            Err(_) => return self.numeric_limit(target),
+        };
+
+        match inner_limit {
+            Limit::Finite(value) => {
+                let outer_value = self.with_argument(value).call()(0.);
+                let has_pole = matches!(
+                    self,
+                    Tan(_) | Sec(_) | Csc(_) | Cot(_) | Factorial(_) | Gamma(_) | Polygamma(_, _)
+                );
+                // the rounding functions jump where their argument is an integer
+                let has_jump = matches!(self, Floor(_) | Ceil(_)) && value.fract() == 0.;
+
+                if outer_value.is_finite()
+                    && !(has_pole && outer_value.abs() > POLE_THRESHOLD)
+                    && !has_jump
+                {
+                    Ok(Limit::Finite(outer_value))
+                } else {
+                    self.numeric_limit(target)
+                }
+            }
+            infinite => {
+                let sign = infinite.signum();
+                match self {
+                    Atan(_) => Ok(Limit::Finite(sign * PI / 2.)),
+                    Acot(_) | Acsc(_) | Sech(_) | Csch(_) => Ok(Limit::Finite(0.)),
+                    Asec(_) => Ok(Limit::Finite(PI / 2.)),
+                    Tanh(_) | Coth(_) => Ok(Limit::Finite(sign)),
+                    Sinh(_) | Asinh(_) | Cbrt(_) | Floor(_) | Ceil(_) => Ok(Limit::infinity(sign)),
+                    Cosh(_) | Abs(_) => Ok(Limit::PositiveInfinity),
+                    Acosh(_) if sign > 0. => Ok(Limit::PositiveInfinity),
+                    Factorial(_) | Gamma(_) if sign > 0. => Ok(Limit::PositiveInfinity),
+                    Polygamma(_, 0) if sign > 0. => Ok(Limit::PositiveInfinity),
+                    Polygamma(_, _) if sign > 0. => Ok(Limit::Finite(0.)),
+                    _ => Err(Error::LimitError(format!(
+                        "The limit of {self:?} does not exist as {target} since its argument approaches {infinite}"
+                    ))),
+                }
+            }
+        }
+    }
+
+    // the limit of the branch that applies at the points closest to the target
+    fn limit_piecewise(
+        &self,
+        branches: &[(Condition, Arc<Elementary>)],
+        otherwise: &Arc<Elementary>,
+        target: Target,
+        depth: usize,
+    ) -> Result<Limit, Error> {
+        let branch_at = |x: f64| {
+            branches
+                .iter()
+                .find(|(condition, _)| condition.holds(x))
+                .map_or(otherwise, |(_, func)| func)
+        };
+
+        let branch = branch_at(target.sample(6));
+        if (7..=8).all(|k| Arc::ptr_eq(branch_at(target.sample(k)), branch)) {
+            branch
+                .limit_value(target, depth)
+                .or_else(|_| self.numeric_limit(target))
+        } else {
+            self.numeric_limit(target)
+        }
+    }
+
+    // estimates the limit by evaluating the function at a sequence of points approaching the target.
+    // This is only used as a last resort when the symbolic methods fail.
+    fn numeric_limit(&self, target: Target) -> Result<Limit, Error> {
+        let callable = self.clone().call();
+        let values: Vec<f64> = (3..=8).map(|k| callable(target.sample(k))).collect();
+
+        let does_not_exist = || {
+            Err(Error::LimitError(format!(
+                "The limit of {self:?} does not exist as {target}"
+            )))
+        };
+
+        if values.iter().any(|value| value.is_nan()) {
+            return does_not_exist();
+        }
+        let last = values[values.len() - 1];
+        if last.is_infinite() {
+            return Ok(Limit::infinity(last));
+        }
+
+        let differences: Vec<f64> = values.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let last_difference = differences[differences.len() - 1];
+
+        if last_difference.abs() <= NUMERIC_TOLERANCE * (1. + last.abs()) {
+            // the sequence has converged
+            if last.abs() < NUMERIC_TOLERANCE {
+                Ok(Limit::Finite(0.))
+            } else {
+                Ok(Limit::Finite(last))
+            }
+        } else if differences
+            .iter()
+            .all(|difference| difference.signum() == last_difference.signum())
+            && last_difference.abs() >= differences[0].abs() * 0.5
+        {
+            // the sequence keeps moving in the same direction without slowing down
+            Ok(Limit::infinity(last_difference))
+        } else {
+            does_not_exist()
+        }
+    }
+
+    // returns the sign of the function close to the target, provided that it is consistent
+    fn divergence_sign(&self, target: Target) -> Result<f64, Error> {
+        let callable = self.clone().call();
+        let signs: Vec<f64> = (6..=8)
+            .map(|k| callable(target.sample(k)).signum())
+            .collect();
+
+        if signs.iter().all(|sign| !sign.is_nan() && *sign == signs[0]) {
+            Ok(signs[0])
+        } else {
+            Err(Error::LimitError(format!(
+                "The limit of {self:?} does not exist as {target}"
+            )))
+        }
+    }
+
+    // returns the same function applied to the constant value instead of its argument
+    fn with_argument(&self, value: f64) -> Self {
+        let argument = Arc::new(Con(value));
+        match self {
+            Sin(_) => Sin(argument),
+            Cos(_) => Cos(argument),
+            Tan(_) => Tan(argument),
+            Sec(_) => Sec(argument),
+            Csc(_) => Csc(argument),
+            Cot(_) => Cot(argument),
+            Asin(_) => Asin(argument),
+            Acos(_) => Acos(argument),
+            Atan(_) => Atan(argument),
+            Acot(_) => Acot(argument),
+            Asec(_) => Asec(argument),
+            Acsc(_) => Acsc(argument),
+            Sinh(_) => Sinh(argument),
+            Cosh(_) => Cosh(argument),
+            Tanh(_) => Tanh(argument),
+            Sech(_) => Sech(argument),
+            Csch(_) => Csch(argument),
+            Coth(_) => Coth(argument),
+            Asinh(_) => Asinh(argument),
+            Acosh(_) => Acosh(argument),
+            Atanh(_) => Atanh(argument),
+            Factorial(_) => Factorial(argument),
+            Gamma(_) => Gamma(argument),
+            Polygamma(_, order) => Polygamma(argument, *order),
+            Abs(_) => Abs(argument),
+            Cbrt(_) => Cbrt(argument),
+            Floor(_) => Floor(argument),
+            Ceil(_) => Ceil(argument),
+            _ => self.clone(),
+        }
+    }
+
+    // rebuilds the function, evaluating constant subexpressions and removing trivial operations
+    // such as multiplication by one or addition of zero
+    fn fold_constants(&self) -> Self {
+        if self.is_constant() {
+            return Con(self.clone().call()(0.));
+        }
+
+        match self {
+            Add(func1, func2) => func1.fold_constants() + func2.fold_constants(),
+            Sub(func1, func2) => {
+                let (func1, func2) = (func1.fold_constants(), func2.fold_constants());
+                match (&func1, &func2) {
+                    (_, Con(numb)) if *numb == 0. => func1,
+                    (Con(numb), _) if *numb == 0. => func2 * Con(-1.),
+                    _ => func1 - func2,
+                }
+            }
+            Mul(func1, func2) => func1.fold_constants() * func2.fold_constants(),
+            Div(func1, func2) => {
+                let (func1, func2) = (func1.fold_constants(), func2.fold_constants());
+                match (&func1, &func2) {
+                    (Con(numb), _) if *numb == 0. => func1,
+                    (_, Con(numb)) if *numb == 1. => func1,
+                    _ => func1 / func2,
+                }
+            }
+            Pow(func1, func2) => {
+                let (func1, func2) = (func1.fold_constants(), func2.fold_constants());
+                match &func2 {
+                    Con(numb) if *numb == 1. => func1,
+                    _ => Pow(Arc::new(func1), Arc::new(func2)),
+                }
+            }
+            Log(func1, func2) => Log(
+                Arc::new(func1.fold_constants()),
+                Arc::new(func2.fold_constants()),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    // rewrites a product or quotient as c * f₁^n₁ * f₂^n₂ * ... where equal factors have been
+    // combined, e.g. (1/x)/(-1/x²) ⟹ -x
+    fn collect_factors(&self) -> Self {
+        let mut coefficient = 1.;
+        let mut factors: Vec<(Elementary, f64)> = Vec::new();
+        self.gather_factors(1., &mut coefficient, &mut factors);
+
+        let mut numerator = Con(coefficient);
+        let mut denominator = Con(1.);
+        for (factor, exponent) in factors {
+            let power = |exponent: f64| {
+                if exponent == 1. {
+                    factor.clone()
+                } else {
+                    Pow(Arc::new(factor.clone()), Arc::new(Con(exponent)))
+                }
+            };
+
+            if exponent > 0. {
+                numerator *= power(exponent);
+            } else if exponent < 0. {
+                denominator *= power(-exponent);
+            }
+        }
+
+        if denominator == Con(1.) {
+            numerator
+        } else {
+            numerator / denominator
+        }
+    }
+
+    fn gather_factors(
+        &self,
+        exponent: f64,
+        coefficient: &mut f64,
+        factors: &mut Vec<(Elementary, f64)>,
+    ) {
+        match self {
+            Mul(func1, func2) => {
+                func1.gather_factors(exponent, coefficient, factors);
+                func2.gather_factors(exponent, coefficient, factors);
+            }
+            Div(func1, func2) => {
+                func1.gather_factors(exponent, coefficient, factors);
+                func2.gather_factors(-exponent, coefficient, factors);
+            }
+            // (f(x)^a)^b = f(x)^(ab) only holds for all x when b is an integer
+            Pow(base, exp) if exponent.fract() == 0. && exp.as_number().is_some() => {
+                if let Some(numb) = exp.as_number() {
+                    base.gather_factors(exponent * numb.to_f64(), coefficient, factors);
+                }
+            }
+            Con(_) | Rat(_) | Sym(_) => *coefficient *= self.clone().call()(0.).powf(exponent),
+            _ => {
+                if let Some(existing) = factors.iter_mut().find(|(factor, _)| factor == self) {
+                    existing.1 += exponent;
+                } else {
+                    factors.push((self.clone(), exponent));
+                }
+            }
+        }
+    }
+
+    fn contains_logarithm(&self) -> bool {
+        match self {
+            Log(_, _) | Asin(_) | Acos(_) | Atan(_) | Acot(_) | Asec(_) | Acsc(_) => true,
+            // the inverse hyperbolic functions are logarithms
+            Asinh(_) | Acosh(_) | Atanh(_) => true,
+            Add(func1, func2)
+            | Sub(func1, func2)
+            | Mul(func1, func2)
+            | Div(func1, func2)
+            | Pow(func1, func2) => func1.contains_logarithm() || func2.contains_logarithm(),
+            Con(_) | Rat(_) | Sym(_) | X | Wildcard(_) => false,
+            Piecewise(_, _) => self.children().iter().any(|func| func.contains_logarithm()),
+            Sin(func)
+            | Cos(func)
+            | Tan(func)
+            | Sec(func)
+            | Csc(func)
+            | Cot(func)
+            | Sinh(func)
+            | Cosh(func)
+            | Tanh(func)
+            | Sech(func)
+            | Csch(func)
+            | Coth(func)
+            | Factorial(func)
+            | Gamma(func)
+            | Polygamma(func, _)
+            | Abs(func)
+            | Cbrt(func)
+            | Floor(func)
+            | Ceil(func) => func.contains_logarithm(),
+        }
+    }
+
+    // returns ln|f| with the logarithms of products, quotients and powers expanded
+    fn log_magnitude(&self) -> Self {
+        match self {
+            Mul(func1, func2) => Add(
+                Arc::new(func1.log_magnitude()),
+                Arc::new(func2.log_magnitude()),
+            ),
+            Div(func1, func2) => Sub(
+                Arc::new(func1.log_magnitude()),
+                Arc::new(func2.log_magnitude()),
+            ),
+            Pow(base, exp) if **base == Sym(Constant::E) => (**exp).clone(),
+            Pow(base, exp) => Mul(exp.clone(), Arc::new(base.log_magnitude())),
+            _ => Log(
+                Arc::new(Sym(Constant::E)),
+                Arc::new(Abs(Arc::new(self.clone()))),
+            ),
+        }
+    }
+
+    fn is_zero_constant(&self) -> bool {
+        self.is_constant() && self.clone().call()(0.) == 0.
+    }
+
+    // returns true if the function is known to be bounded for all x
+    fn is_bounded(&self) -> bool {
+        match self {
+            Sin(_) | Cos(_) | Atan(_) | Acot(_) | Asec(_) | Acsc(_) | Tanh(_) | Sech(_) => true,
+            Con(_) | Rat(_) | Sym(_) => true,
+            Add(func1, func2) | Sub(func1, func2) | Mul(func1, func2) => {
+                func1.is_bounded() && func2.is_bounded()
+            }
+            Abs(func) | Cbrt(func) | Floor(func) | Ceil(func) => func.is_bounded(),
+            Piecewise(branches, otherwise) => {
+                otherwise.is_bounded() && branches.iter().all(|(_, func)| func.is_bounded())
+            }
+            _ => false,
+        }
+    }
+}
+
+// finds the limit of a quotient by comparing the leading terms of the Taylor expansions of the
+// numerator and the denominator around the point
+fn series_quotient(
+    numerator: &Elementary,
+    denominator: &Elementary,
+    point: f64,
+    direction: Direction,
+) -> Option<Limit> {
+    let numerator_coefficients = numerator.taylor_coefficients(SERIES_ORDER, point);
+    let denominator_coefficients = denominator.taylor_coefficients(SERIES_ORDER, point);
+
+    let leading_term = |coefficients: &Vec<f64>| {
+        coefficients
+            .iter()
+            .position(|coefficient| coefficient.abs() > ZERO_TOLERANCE)
+    };
+
+    if numerator_coefficients
+        .iter()
+        .chain(denominator_coefficients.iter())
+        .any(|coefficient| !coefficient.is_finite())
+    {
+        return None;
+    }
+
+    let denominator_order = leading_term(&denominator_coefficients)?;
+    let numerator_order = match leading_term(&numerator_coefficients) {
+        Some(order) => order,
+        // the numerator vanishes to a higher order than can be detected
+        None => return Some(Limit::Finite(0.)),
+    };
+
+    let ratio =
+        numerator_coefficients[numerator_order] / denominator_coefficients[denominator_order];
+
+    if numerator_order >= denominator_order {
+        if numerator_order == denominator_order {
+            Some(Limit::Finite(ratio))
+        } else {
+            Some(Limit::Finite(0.))
+        }
+    } else {
+        // the quotient behaves like ratio * (x - a)^(numerator_order - denominator_order)
+        let odd_power = (denominator_order - numerator_order) % 2 == 1;
+        let sign = if direction == Direction::Left && odd_power {
+            -ratio.signum()
+        } else {
+            ratio.signum()
+        };
+        Some(Limit::infinity(sign))
+    }
+}
+
+impl Function {
+    /// Returns the limit of the function as x approaches the provided point from the provided
+    /// [Direction](crate::Direction).
+    ///
+    /// The limit is found through symbolic rewriting, using [L'Hôpital's
+    /// rule](https://en.wikipedia.org/wiki/L%27H%C3%B4pital%27s_rule) on the indeterminate forms
+    /// 0/0 and ∞/∞ and falling back to series expansions when needed. If the limit diverges or
+    /// does not exist, an [Error::LimitError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Direction, Function};
+    /// let function = Function::from("sin(x)/x");
+    ///
+    /// // sin(x)/x is undefined at x = 0...
+    /// assert!(function.call(0.).is_nan());
+    /// // ...but its limit as x approaches 0 exists
+    /// assert_eq!(function.limit(0., Direction::Both).unwrap(), 1.);
+    ///
+    /// // 1/x diverges as x approaches 0
+    /// let reciprocal = Function::from("1/x");
+    /// assert!(reciprocal.limit(0., Direction::Right).is_err());
+    /// ```
+    pub fn limit(&self, point: f64, direction: Direction) -> Result<f64, Error> {
+        self.elementary().limit(point, direction)
+    }
+
+    /// Returns the limit of the function as x approaches positive infinity.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("(2x + 1)/(x + 3)");
+    ///
+    /// assert_eq!(function.limit_at_infinity().unwrap(), 2.);
+    /// ```
+    pub fn limit_at_infinity(&self) -> Result<f64, Error> {
+        self.elementary().limit_at_infinity()
+    }
+
+    /// Returns the limit of the function as x approaches negative infinity.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("atan(x)");
+    ///
+    /// assert_eq!(function.limit_at_negative_infinity().unwrap(), -std::f64::consts::PI / 2.);
+    /// ```
+    pub fn limit_at_negative_infinity(&self) -> Result<f64, Error> {
+        self.elementary().limit_at_negative_infinity()
+    }
+}
@@ -1,6 +1,19 @@
+pub mod arena;
 pub mod calc;
+pub mod checked_evaluation;
+pub mod curve_analysis;
 pub mod differentiation;
+pub mod domain;
+pub mod extrema;
+pub mod forward_mode;
 pub mod integration;
+pub mod interval_evaluation;
+pub mod limits;
+pub mod piecewise;
+pub mod precise_evaluation;
+pub mod range;
+pub mod reverse_mode;
+pub mod roots;
+pub mod scalar_evaluation;
 pub mod series_expansions;
 pub mod utils;
-
@@ -0,0 +1,273 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use crate::{
+    Elementary::{self, *},
+    Function, Interval,
+};
+
+// the most integers that the argument of floor or ceil may cross for the integration to be split
+// at each of its jumps
+const MAX_JUMPS: f64 = 1000.;
+
+/// The comparison that a [Condition](crate::Condition) makes between its two sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Relation {
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl Relation {
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Less => "<",
+            Self::LessEqual => "<=",
+            Self::Greater => ">",
+            Self::GreaterEqual => ">=",
+        }
+    }
+
+    /// Whether lhs and rhs satisfy the relation. Comparisons with NaN never hold.
+    pub fn holds(&self, lhs: f64, rhs: f64) -> bool {
+        lhs.partial_cmp(&rhs)
+            .is_some_and(|ordering| self.holds_for(ordering))
+    }
+
+    // the relation that holds exactly when this one doesn't (for sides that aren't NaN)
+    pub(crate) fn negated(&self) -> Self {
+        match self {
+            Self::Less => Self::GreaterEqual,
+            Self::LessEqual => Self::Greater,
+            Self::Greater => Self::LessEqual,
+            Self::GreaterEqual => Self::Less,
+        }
+    }
+
+    // whether the relation holds for two sides that compare as the ordering
+    pub(crate) fn holds_for(&self, ordering: Ordering) -> bool {
+        match self {
+            Self::Less => ordering.is_lt(),
+            Self::LessEqual => ordering.is_le(),
+            Self::Greater => ordering.is_gt(),
+            Self::GreaterEqual => ordering.is_ge(),
+        }
+    }
+
+    // whether the relation holds between every value of the difference and 0 (Some(true)), none
+    // of them (Some(false)) or only some of them (None). Since the values satisfying a relation
+    // form a half-line, so do those that don't, which means that it suffices to check the bounds
+    pub(crate) fn holds_on(&self, difference: Interval) -> Option<bool> {
+        if difference.is_empty() {
+            return None;
+        }
+        let lo = self.holds_for(difference.lo.partial_cmp(&0.)?);
+        let hi = self.holds_for(difference.hi.partial_cmp(&0.)?);
+        (lo == hi).then_some(lo)
+    }
+}
+
+/// A comparison between two functions of x, such as x < 0, which decides whether a branch of a
+/// [piecewise](crate::Elementary::Piecewise) function applies.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub lhs: Arc<Elementary>,
+    pub relation: Relation,
+    pub rhs: Arc<Elementary>,
+}
+
+impl Condition {
+    pub fn new(lhs: Elementary, relation: Relation, rhs: Elementary) -> Self {
+        Self {
+            lhs: Arc::new(lhs),
+            relation,
+            rhs: Arc::new(rhs),
+        }
+    }
+
+    /// Whether the condition holds at x.
+    pub fn holds(&self, x: f64) -> bool {
+        self.relation
+            .holds((*self.lhs).clone().call()(x), (*self.rhs).clone().call()(x))
+    }
+
+    // lhs - rhs, whose sign decides whether the condition holds
+    pub(crate) fn difference(&self) -> Elementary {
+        Sub(self.lhs.clone(), self.rhs.clone())
+    }
+
+    // applies the provided function to both sides of the condition
+    pub(crate) fn map(&self, f: impl Fn(&Elementary) -> Elementary) -> Self {
+        Self {
+            lhs: Arc::new(f(&self.lhs)),
+            relation: self.relation,
+            rhs: Arc::new(f(&self.rhs)),
+        }
+    }
+}
+
+impl Elementary {
+    /// Creates the function that equals the value of the first branch whose condition holds, or
+    /// otherwise if none of them hold.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Condition, Elementary, Relation};
+    /// // the ramp function, max(x, 0)
+    /// let ramp = Elementary::piecewise(
+    ///     vec![(Condition::new(Elementary::X, Relation::Less, Elementary::Con(0.)), Elementary::Con(0.))],
+    ///     Elementary::X,
+    /// );
+    ///
+    /// assert_eq!(ramp.clone().call()(-2.), 0.);
+    /// assert_eq!(ramp.call()(3.), 3.);
+    /// ```
+    pub fn piecewise(branches: Vec<(Condition, Elementary)>, otherwise: Elementary) -> Self {
+        Piecewise(
+            branches
+                .into_iter()
+                .map(|(condition, value)| (condition, Arc::new(value)))
+                .collect(),
+            Arc::new(otherwise),
+        )
+    }
+
+    // the points within [a, b] where the condition of a piecewise subexpression changes, the
+    // argument of an absolute value changes sign or the argument of floor or ceil crosses an
+    // integer, sorted in increasing order
+    pub(crate) fn breakpoints(&self, a: f64, b: f64) -> Vec<f64> {
+        let mut breakpoints = Vec::new();
+        self.gather_breakpoints(a, b, &mut breakpoints);
+        breakpoints.sort_by(f64::total_cmp);
+        breakpoints.dedup();
+        breakpoints
+    }
+
+    fn gather_breakpoints(&self, a: f64, b: f64, breakpoints: &mut Vec<f64>) {
+        let changes: Vec<Elementary> = match self {
+            Piecewise(branches, _) => branches
+                .iter()
+                .map(|(condition, _)| condition.difference())
+                .collect(),
+            Abs(func) => vec![(**func).clone()],
+            // the integers that the argument may cross, as long as there are few enough of them
+            Floor(func) | Ceil(func) => {
+                let range = func.eval_interval(Interval::new(a.min(b), a.max(b)));
+                if range.hi - range.lo <= MAX_JUMPS {
+                    (range.lo.ceil() as i64..=range.hi.floor() as i64)
+                        .map(|integer| (**func).clone() - integer as f64)
+                        .collect()
+                } else {
+                    Vec::new()
+                }
+            }
+            _ => Vec::new(),
+        };
+        for change in changes.iter().filter(|change| !change.is_constant()) {
+            if let Ok(roots) = change.find_all_roots(a, b) {
+                breakpoints.extend(roots.iter().map(|root| root.value));
+            }
+        }
+        for child in self.children() {
+            child.gather_breakpoints(a, b, breakpoints);
+        }
+    }
+}
+
+// sign(f(x)), which is -1, 0 or 1
+pub(crate) fn signum(func: Arc<Elementary>) -> Elementary {
+    Piecewise(
+        vec![
+            (
+                Condition {
+                    lhs: func.clone(),
+                    relation: Relation::Less,
+                    rhs: Arc::new(Con(0.)),
+                },
+                Arc::new(Con(-1.)),
+            ),
+            (
+                Condition {
+                    lhs: func,
+                    relation: Relation::Greater,
+                    rhs: Arc::new(Con(0.)),
+                },
+                Arc::new(Con(1.)),
+            ),
+        ],
+        Arc::new(Con(0.)),
+    )
+}
+
+/// Creates a [Function](crate::Function) equal to the Heaviside step function of the passed
+/// [Function](crate::Function)
+///
+/// i.e f(x) ⟹ H(f(x)), which is 0 where f(x) < 0 and 1 elsewhere
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{heaviside, Function};
+/// let step = heaviside(Function::from("x - 1"));
+///
+/// assert_eq!(step.elementary().call()(0.), 0.);
+/// assert_eq!(step.elementary().call()(1.), 1.);
+/// ```
+pub fn heaviside(func: Function) -> Function {
+    let condition = Condition::new(func.elementary(), Relation::Less, Con(0.));
+    Function::from(Elementary::piecewise(vec![(condition, Con(0.))], Con(1.)))
+}
+
+/// Creates a [Function](crate::Function) equal to the sign of the passed
+/// [Function](crate::Function)
+///
+/// i.e f(x) ⟹ sign(f(x)), which is -1, 0 or 1
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{sign, Function};
+/// let sign_of_x = sign(Function::default());
+///
+/// assert_eq!(sign_of_x.elementary().call()(-3.), -1.);
+/// assert_eq!(sign_of_x.elementary().call()(0.), 0.);
+/// ```
+pub fn sign(func: Function) -> Function {
+    Function::from(signum(Arc::new(func.elementary())))
+}
+
+/// Creates a [Function](crate::Function) equal to the smaller of the two passed
+/// [Function](crate::Function)s
+///
+/// i.e f(x), g(x) ⟹ min(f(x), g(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{min, Function};
+/// let minimum = min(Function::from("x^2"), Function::from("x"));
+///
+/// assert_eq!(minimum.elementary().call()(0.5), 0.25);
+/// assert_eq!(minimum.elementary().call()(2.), 2.);
+/// ```
+pub fn min(func1: Function, func2: Function) -> Function {
+    let (func1, func2) = (func1.elementary(), func2.elementary());
+    let condition = Condition::new(func1.clone(), Relation::LessEqual, func2.clone());
+    Function::from(Elementary::piecewise(vec![(condition, func1)], func2))
+}
+
+/// Creates a [Function](crate::Function) equal to the larger of the two passed
+/// [Function](crate::Function)s
+///
+/// i.e f(x), g(x) ⟹ max(f(x), g(x))
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{max, Function};
+/// let relu = max(Function::default(), Function::from("0"));
+///
+/// assert_eq!(relu.elementary().call()(-1.), 0.);
+/// assert_eq!(relu.elementary().call()(2.), 2.);
+/// ```
+pub fn max(func1: Function, func2: Function) -> Function {
+    let (func1, func2) = (func1.elementary(), func2.elementary());
+    let condition = Condition::new(func1.clone(), Relation::GreaterEqual, func2.clone());
+    Function::from(Elementary::piecewise(vec![(condition, func1)], func2))
+}
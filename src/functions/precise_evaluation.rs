@@ -0,0 +1,125 @@
+use crate::{
+    utils::big_float::checked_div,
+    BigFloat, Constant,
+    Elementary::{self, *},
+    Error, Function,
+};
+
+impl Elementary {
+    /// Evaluates the function at the provided [BigFloat](crate::BigFloat), to the precision of the
+    /// BigFloat.
+    ///
+    /// Returns an [Error::InputError](crate::Error) if the function isn't defined at the point, or
+    /// if it contains functions that can't be evaluated to arbitrary precision (polygamma
+    /// functions and wildcards).
+    pub fn eval_precise(&self, x: &BigFloat) -> Result<BigFloat, Error> {
+        let digits = x.digits();
+        Ok(match self {
+            Sin(func) => func.eval_precise(x)?.sin(),
+            Cos(func) => func.eval_precise(x)?.cos(),
+            Tan(func) => func.eval_precise(x)?.tan()?,
+            Sec(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?.cos())?,
+            Csc(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?.sin())?,
+            Cot(func) => {
+                let (sin, cos) = func.eval_precise(x)?.sin_cos();
+                checked_div(cos, sin)?
+            }
+            Asin(func) => func.eval_precise(x)?.asin()?,
+            Acos(func) => func.eval_precise(x)?.acos()?,
+            Atan(func) => func.eval_precise(x)?.atan(),
+            // acot(x) = atan(1/x), which is continuous at x = 0 where it's π/2
+            Acot(func) => {
+                let value = func.eval_precise(x)?;
+                if value.is_zero() {
+                    BigFloat::constant(Constant::Pi, digits)?.mul_pow2(-1)
+                } else {
+                    (BigFloat::one(digits) / value).atan()
+                }
+            }
+            Asec(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?)?.acos()?,
+            Acsc(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?)?.asin()?,
+            Sinh(func) => func.eval_precise(x)?.sinh()?,
+            Cosh(func) => func.eval_precise(x)?.cosh()?,
+            Tanh(func) => func.eval_precise(x)?.tanh()?,
+            Sech(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?.cosh()?)?,
+            Csch(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?.sinh()?)?,
+            Coth(func) => checked_div(BigFloat::one(digits), func.eval_precise(x)?.tanh()?)?,
+            Asinh(func) => func.eval_precise(x)?.asinh()?,
+            Acosh(func) => func.eval_precise(x)?.acosh()?,
+            Atanh(func) => func.eval_precise(x)?.atanh()?,
+
+            Add(func1, func2) => func1.eval_precise(x)? + func2.eval_precise(x)?,
+            Sub(func1, func2) => func1.eval_precise(x)? - func2.eval_precise(x)?,
+            Mul(func1, func2) => func1.eval_precise(x)? * func2.eval_precise(x)?,
+            Div(func1, func2) => checked_div(func1.eval_precise(x)?, func2.eval_precise(x)?)?,
+            Pow(base, exp) => base.eval_precise(x)?.powf(&exp.eval_precise(x)?)?,
+            Log(base, func) => {
+                checked_div(func.eval_precise(x)?.ln()?, base.eval_precise(x)?.ln()?)?
+            }
+
+            Factorial(func) => (func.eval_precise(x)? + BigFloat::one(digits)).gamma()?,
+            Gamma(func) => func.eval_precise(x)?.gamma()?,
+            Polygamma(_, order) => {
+                return Err(Error::InputError(format!(
+                    "ψ{order} can't be evaluated to arbitrary precision"
+                )))
+            }
+            Abs(func) => func.eval_precise(x)?.abs(),
+            Cbrt(func) => func.eval_precise(x)?.cbrt()?,
+            Floor(func) => func.eval_precise(x)?.floor(),
+            Ceil(func) => func.eval_precise(x)?.ceil(),
+            Piecewise(branches, otherwise) => {
+                for (condition, func) in branches {
+                    let (lhs, rhs) = (
+                        condition.lhs.eval_precise(x)?,
+                        condition.rhs.eval_precise(x)?,
+                    );
+                    if lhs
+                        .partial_cmp(&rhs)
+                        .is_some_and(|ordering| condition.relation.holds_for(ordering))
+                    {
+                        return func.eval_precise(x);
+                    }
+                }
+                otherwise.eval_precise(x)?
+            }
+
+            Con(numb) => BigFloat::from_f64(*numb, digits)?,
+            Rat(rational) => {
+                BigFloat::from_integer(rational.numerator(), digits)
+                    / BigFloat::from_integer(rational.denominator(), digits)
+            }
+            Sym(constant) => BigFloat::constant(*constant, digits)?,
+            X => x.clone(),
+            Wildcard(name) => {
+                return Err(Error::InputError(format!(
+                    "The wildcard ?{name} has no value"
+                )))
+            }
+        })
+    }
+}
+
+impl Function {
+    /// Evaluates the function at x to the provided number of significant decimal digits, using
+    /// arbitrary-precision arithmetic.
+    ///
+    /// Note that x itself is taken to be exactly the f64 value it holds, so 0.1 is the nearest
+    /// binary fraction to 0.1 rather than exactly one tenth. Exact arguments can be passed to
+    /// [Elementary::eval_precise](crate::Elementary::eval_precise) instead.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("e^x");
+    ///
+    /// assert_eq!(
+    ///     function.eval_precise(1., 50).unwrap().to_string(),
+    ///     "2.7182818284590452353602874713526624977572470937000"
+    /// );
+    /// ```
+    pub fn eval_precise(&self, x: f64, digits: usize) -> Result<BigFloat, Error> {
+        self.elementary()
+            .eval_precise(&BigFloat::from_f64(x, digits)?)
+    }
+}
@@ -0,0 +1,218 @@
+use crate::{Elementary, Elementary::*, Error, Function, Interval};
+
+/// How [range_on()](crate::Function::range_on) bounds the values of a function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeMode {
+    /// Evaluates the function at evenly spaced points of the interval. This is fast, but narrow
+    /// peaks between the points are missed, so the estimate may lie within the true range.
+    Sampled,
+    /// Bounds the function using interval arithmetic on a bisection of the interval, refined by
+    /// the values at the critical points. The bounds are guaranteed to contain the true range.
+    Rigorous,
+}
+
+// the number of points evaluated in the sampled mode
+const SAMPLES: usize = 1000;
+// the rigorous bounds are refined until they are this close to an attained value, relative to
+// its magnitude
+const TOLERANCE: f64 = 1e-9;
+// the largest number of bisections used to refine a rigorous bound
+const MAX_BISECTIONS: usize = 5000;
+
+// a piece of the interval along with an enclosure of the function on it
+#[derive(Clone, Copy)]
+struct Piece {
+    x: Interval,
+    bounds: Interval,
+}
+
+impl Elementary {
+    /// Returns bounds (min, max) on the values of the function on the closed interval [a, b],
+    /// computed as described by the [RangeMode](crate::RangeMode).
+    ///
+    /// Points where the function isn't defined are ignored. If the function isn't defined
+    /// anywhere on the interval (or at any of the sampled points), an
+    /// [Error::InputError](crate::Error) is returned.
+    pub fn range_on(&self, a: f64, b: f64, mode: RangeMode) -> Result<(f64, f64), Error> {
+        if !(a.is_finite() && b.is_finite()) || a > b {
+            return Err(Error::InputError(format!(
+                "Cannot bound the function on the interval [{a}, {b}]"
+            )));
+        }
+
+        let range = match mode {
+            RangeMode::Sampled => self.sampled_range(a, b),
+            RangeMode::Rigorous => self.rigorous_range(a, b),
+        };
+        range.ok_or(Error::InputError(format!(
+            "The function is not defined on the interval [{a}, {b}]"
+        )))
+    }
+
+    fn sampled_range(&self, a: f64, b: f64) -> Option<(f64, f64)> {
+        let function = self.clone().call();
+        let step = (b - a) / (SAMPLES - 1) as f64;
+
+        (0..SAMPLES)
+            .map(|i| {
+                if i == SAMPLES - 1 {
+                    b
+                } else {
+                    a + i as f64 * step
+                }
+            })
+            .map(function)
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| match range {
+                Some((min, max)) => Some((value.min(min), value.max(max))),
+                None => Some((value, value)),
+            })
+    }
+
+    fn rigorous_range(&self, a: f64, b: f64) -> Option<(f64, f64)> {
+        // the simplified derivative has tighter enclosures, e.g. x^2 rather than x^(3 - 1)
+        let derivative = self
+            .clone()
+            .derivative()
+            .unwrap_or_else(|_| self.clone().derivative_unsimplified());
+
+        // the values at the endpoints and the critical points are attained, so they are used to
+        // discard the pieces that can't contain the extrema
+        let mut candidates = vec![a, b];
+        if a < b && !derivative.is_constant() {
+            if let Ok(roots) = derivative.find_all_roots(a, b) {
+                candidates.extend(roots.iter().map(|root| root.value));
+            }
+        }
+
+        // the slope only bounds the change of a continuous function, so the jumps of the step
+        // functions are enclosed by the direct enclosures alone (which is signalled by an empty
+        // slope)
+        let continuous = !self.has_jumps();
+        let slope = |x| {
+            if continuous {
+                derivative.eval_interval(x)
+            } else {
+                Interval::empty()
+            }
+        };
+
+        let min = lower_bound(&candidates, |x| self.eval_interval(x), slope, a, b)?;
+        // the largest value of f is the negated smallest value of -f
+        let max = -lower_bound(&candidates, |x| -self.eval_interval(x), |x| -slope(x), a, b)?;
+        Some((min, max))
+    }
+
+    // whether the function contains a step function (floor, ceil or a piecewise function, which
+    // includes sign), whose values may jump
+    fn has_jumps(&self) -> bool {
+        match self {
+            Floor(_) | Ceil(_) | Piecewise(_, _) => true,
+            _ => self.children().iter().any(|func| func.has_jumps()),
+        }
+    }
+}
+
+// a lower bound on the smallest value of the function on [a, b], found by branch and bound: the
+// piece with the smallest lower bound is bisected until its bound is close enough to a value
+// that is attained, or until it can't be bisected any further
+fn lower_bound(
+    candidates: &[f64],
+    enclosure: impl Fn(Interval) -> Interval,
+    slope: impl Fn(Interval) -> Interval,
+    a: f64,
+    b: f64,
+) -> Option<f64> {
+    // an upper bound on the smallest value of the function
+    let mut attained = f64::INFINITY;
+    for x in candidates {
+        let value = enclosure(Interval::point(*x));
+        if !value.is_empty() && value.hi.is_finite() {
+            attained = attained.min(value.hi);
+        }
+    }
+
+    let piece = |x: Interval| {
+        let mut bounds = enclosure(x);
+        let slope = slope(x);
+        if !slope.is_empty() && slope.lo.is_finite() && slope.hi.is_finite() {
+            // by the mean value theorem, f(x) lies in f(m) + f'(x)(x - m), which is much tighter
+            // than the direct enclosure on small pieces around the extrema
+            let midpoint = x.midpoint();
+            let centre = enclosure(Interval::point(midpoint));
+            if !centre.is_empty() {
+                bounds = bounds.intersection(&(centre + slope * (x - Interval::point(midpoint))));
+            }
+
+            // on pieces where the function is monotonic, its extrema lie at the ends of the piece
+            let (lo, hi) = (
+                enclosure(Interval::point(x.lo)),
+                enclosure(Interval::point(x.hi)),
+            );
+            if !slope.contains(0.) && !lo.is_empty() && !hi.is_empty() {
+                bounds = bounds.intersection(&lo.hull(&hi));
+            }
+        }
+        Piece { x, bounds }
+    };
+
+    let mut pieces = vec![piece(Interval::new(a, b))];
+    for _ in 0..MAX_BISECTIONS {
+        pieces.retain(|piece| !piece.bounds.is_empty() && piece.bounds.lo <= attained);
+        let (index, lowest) = pieces
+            .iter()
+            .enumerate()
+            .min_by(|(_, piece1), (_, piece2)| piece1.bounds.lo.total_cmp(&piece2.bounds.lo))?;
+
+        let midpoint = lowest.x.midpoint();
+        if attained - lowest.bounds.lo <= TOLERANCE * attained.abs().max(1.)
+            || midpoint <= lowest.x.lo
+            || midpoint >= lowest.x.hi
+        {
+            break;
+        }
+
+        let value = enclosure(Interval::point(midpoint));
+        if !value.is_empty() && value.hi.is_finite() {
+            attained = attained.min(value.hi);
+        }
+
+        let lowest = pieces.swap_remove(index);
+        pieces.push(piece(Interval::new(lowest.x.lo, midpoint)));
+        pieces.push(piece(Interval::new(midpoint, lowest.x.hi)));
+    }
+
+    // the pieces that were discarded lie above an attained value, so the smallest value lies on
+    // one of the remaining pieces
+    pieces
+        .iter()
+        .filter(|piece| !piece.bounds.is_empty())
+        .map(|piece| piece.bounds.lo)
+        .min_by(f64::total_cmp)
+}
+
+impl Function {
+    /// Returns bounds (min, max) on the values of the function on the closed interval [a, b].
+    ///
+    /// [RangeMode::Sampled](crate::RangeMode) gives a fast estimate from the values at evenly
+    /// spaced points, which suits plotting. [RangeMode::Rigorous](crate::RangeMode) returns
+    /// bounds that are guaranteed to contain every value of the function on the interval (where
+    /// it is defined), such as the bounds on the derivatives needed for the remainder of a
+    /// Taylor series. Functions with poles on the interval have infinite rigorous bounds.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Function, RangeMode};
+    /// let function = Function::from("sin(x) + x^2/10");
+    ///
+    /// let (min, max) = function.range_on(-3., 3., RangeMode::Rigorous).unwrap();
+    ///
+    /// // the function attains its minimum -0.79458... at x = -1.30644... and its maximum
+    /// // 1.30948... at x = 1.97738...
+    /// assert!(-0.7946 < min && min <= -0.79458);
+    /// assert!(1.30948 <= max && max < 1.3095);
+    /// ```
+    pub fn range_on(&self, a: f64, b: f64, mode: RangeMode) -> Result<(f64, f64), Error> {
+        self.elementary().range_on(a, b, mode)
+    }
+}
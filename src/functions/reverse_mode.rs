@@ -0,0 +1,228 @@
+use crate::{
+    functions::arena::{apply, selected_branch, Op},
+    polygamma_function, Elementary, Error, ExpressionArena,
+};
+
+// a single step of the tape, referring to earlier steps by their index
+#[derive(Debug, Clone)]
+enum Step {
+    Input(usize),
+    Apply(Op, Vec<usize>),
+}
+
+/// A tape recording the evaluation of a function of several variables, used for reverse-mode
+/// automatic differentiation.
+///
+/// Besides the independent variable x, the variables of the function are its wildcards, so that
+/// for instance "(?a*x + ?b)^2" is a function of a, b and x. Evaluating the tape forward and then
+/// propagating the adjoints backward yields the value of the function along with its full
+/// gradient, at a cost of about one evaluation no matter how many variables there are. See
+/// [this article](https://en.wikipedia.org/wiki/Automatic_differentiation#Reverse_accumulation)
+/// for further information.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Elementary, Tape};
+/// let tape = Tape::record(&Elementary::from("(?a*x + ?b)^2"));
+///
+/// assert_eq!(tape.variables(), ["a", "b", "x"]);
+///
+/// // at a = 1, b = 2 and x = 3
+/// let (value, gradient) = tape.value_and_gradient(&[1., 2., 3.]).unwrap();
+/// assert_eq!(value, 25.);
+/// assert_eq!(gradient, vec![30., 10., 10.]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Tape {
+    // the steps in topological order, where the last step is the output of the function
+    steps: Vec<Step>,
+    variables: Vec<String>,
+}
+
+impl Tape {
+    /// Records the tape of the provided function. Identical subexpressions are only recorded once.
+    pub fn record(function: &Elementary) -> Self {
+        let mut arena = ExpressionArena::new();
+        arena.intern(function);
+
+        let mut variables: Vec<String> = arena
+            .nodes()
+            .iter()
+            .filter_map(|node| variable_name(&node.op))
+            .collect();
+        variables.sort();
+        variables.dedup();
+
+        let steps = arena
+            .nodes()
+            .iter()
+            .map(|node| match variable_name(&node.op) {
+                Some(name) => Step::Input(
+                    variables
+                        .iter()
+                        .position(|variable| *variable == name)
+                        .unwrap(),
+                ),
+                None => Step::Apply(
+                    node.op.clone(),
+                    node.children.iter().map(|child| child.0).collect(),
+                ),
+            })
+            .collect();
+
+        Self { steps, variables }
+    }
+
+    /// The names of the variables of the function in alphabetical order, which is the order that
+    /// the inputs and the gradient follow. The independent variable is named "x".
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// The number of steps recorded on the tape.
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Evaluates the function at the provided values of its [variables](crate::Tape::variables).
+    pub fn evaluate(&self, inputs: &[f64]) -> Result<f64, Error> {
+        Ok(*self.forward(inputs)?.last().unwrap())
+    }
+
+    /// Returns the value of the function and its gradient, that is, its partial derivatives with
+    /// respect to each of its [variables](crate::Tape::variables), at the provided values of the
+    /// variables.
+    pub fn value_and_gradient(&self, inputs: &[f64]) -> Result<(f64, Vec<f64>), Error> {
+        let values = self.forward(inputs)?;
+
+        // the adjoint of a step is the partial derivative of the output with respect to it
+        let mut adjoints = vec![0.; self.steps.len()];
+        let mut gradient = vec![0.; self.variables.len()];
+        *adjoints.last_mut().unwrap() = 1.;
+
+        for (index, step) in self.steps.iter().enumerate().rev() {
+            let adjoint = adjoints[index];
+            if adjoint == 0. {
+                continue;
+            }
+            match step {
+                Step::Input(variable) => gradient[*variable] += adjoint,
+                Step::Apply(op, arguments) => {
+                    let argument_values: Vec<f64> =
+                        arguments.iter().map(|argument| values[*argument]).collect();
+                    let partials = partial_derivatives(op, &argument_values, values[index]);
+                    for (argument, partial) in arguments.iter().zip(partials) {
+                        adjoints[*argument] += adjoint * partial;
+                    }
+                }
+            }
+        }
+
+        Ok((values[values.len() - 1], gradient))
+    }
+
+    // the values of all steps of the tape
+    fn forward(&self, inputs: &[f64]) -> Result<Vec<f64>, Error> {
+        if inputs.len() != self.variables.len() {
+            return Err(Error::InputError(format!(
+                "Expected values for the {} variables {:?}, but got {} values",
+                self.variables.len(),
+                self.variables,
+                inputs.len()
+            )));
+        }
+
+        let mut values: Vec<f64> = Vec::with_capacity(self.steps.len());
+        for step in &self.steps {
+            let value = match step {
+                Step::Input(variable) => inputs[*variable],
+                Step::Apply(op, arguments) => {
+                    let argument_values: Vec<f64> =
+                        arguments.iter().map(|argument| values[*argument]).collect();
+                    apply(op, &argument_values, f64::NAN)
+                }
+            };
+            values.push(value);
+        }
+        Ok(values)
+    }
+}
+
+fn variable_name(op: &Op) -> Option<String> {
+    match op {
+        Op::X => Some(String::from("x")),
+        Op::Wildcard(name) => Some(name.clone()),
+        _ => None,
+    }
+}
+
+// the partial derivatives of the operation with respect to each of its arguments, given the values
+// of the arguments and of the operation itself
+fn partial_derivatives(op: &Op, arguments: &[f64], value: f64) -> Vec<f64> {
+    let argument = |index: usize| arguments[index];
+    match op {
+        Op::Sin => vec![argument(0).cos()],
+        Op::Cos => vec![-argument(0).sin()],
+        Op::Tan => vec![1. + value * value],
+        Op::Sec => vec![value * argument(0).tan()],
+        Op::Csc => vec![-value / argument(0).tan()],
+        Op::Cot => vec![-(1. + value * value)],
+        Op::Asin => vec![1. / (1. - argument(0).powi(2)).sqrt()],
+        Op::Acos => vec![-1. / (1. - argument(0).powi(2)).sqrt()],
+        Op::Atan => vec![1. / (1. + argument(0).powi(2))],
+        Op::Acot => vec![-1. / (1. + argument(0).powi(2))],
+        Op::Asec => vec![1. / (argument(0).abs() * (argument(0).powi(2) - 1.).sqrt())],
+        Op::Acsc => vec![-1. / (argument(0).abs() * (argument(0).powi(2) - 1.).sqrt())],
+        Op::Sinh => vec![argument(0).cosh()],
+        Op::Cosh => vec![argument(0).sinh()],
+        Op::Tanh => vec![1. - value * value],
+        Op::Sech => vec![-value * argument(0).tanh()],
+        Op::Csch => vec![-value / argument(0).tanh()],
+        Op::Coth => vec![1. - value * value],
+        Op::Asinh => vec![1. / (argument(0).powi(2) + 1.).sqrt()],
+        Op::Acosh => vec![1. / (argument(0).powi(2) - 1.).sqrt()],
+        Op::Atanh => vec![1. / (1. - argument(0).powi(2))],
+        Op::Add => vec![1., 1.],
+        Op::Sub => vec![1., -1.],
+        Op::Mul => vec![argument(1), argument(0)],
+        Op::Div => vec![1. / argument(1), -argument(0) / argument(1).powi(2)],
+        Op::Pow => {
+            let (base, exp) = (argument(0), argument(1));
+            // the partial derivative with respect to the exponent is only defined for positive
+            // bases, but it is only needed if the exponent isn't constant
+            let exp_partial = if base > 0. { value * base.ln() } else { 0. };
+            vec![exp * base.powf(exp - 1.), exp_partial]
+        }
+        // log_b(f) = ln(f) / ln(b)
+        Op::Log => {
+            let (base, func) = (argument(0), argument(1));
+            vec![-value / (base * base.ln()), 1. / (func * base.ln())]
+        }
+        Op::Factorial => vec![value * polygamma_function(argument(0) + 1., 0)],
+        Op::Gamma => vec![value * polygamma_function(argument(0), 0)],
+        Op::Polygamma(order) => vec![polygamma_function(argument(0), order + 1)],
+        Op::Abs if argument(0) == 0. => vec![0.],
+        Op::Abs => vec![argument(0).signum()],
+        Op::Cbrt => vec![1. / (3. * value * value)],
+        Op::Floor | Op::Ceil => vec![0.],
+        // only the value of the branch that applies affects the value
+        Op::Piecewise(relations) => {
+            let mut partials = vec![0.; arguments.len()];
+            partials[selected_branch(relations, arguments)] = 1.;
+            partials
+        }
+        Op::Con(_) | Op::Rat(_) | Op::Sym(_) | Op::X | Op::Wildcard(_) => Vec::new(),
+    }
+}
+
+impl Elementary {
+    /// Records the [Tape](crate::Tape) of the function, for evaluating its gradient with respect
+    /// to x and its wildcards.
+    pub fn record_tape(&self) -> Tape {
+        Tape::record(self)
+    }
+}
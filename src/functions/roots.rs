@@ -0,0 +1,864 @@
+use std::f64::consts::PI;
+
+use crate::{Complex, Elementary, Error, Func, Function, Polynomial};
+
+/// A root of a function, i.e. a value r such that f(r) = 0.
+///
+/// The multiplicity of the root is the number of times that the factor (x - r) divides the
+/// function, e.g. (x - 1)² has a root at x = 1 with multiplicity 2. See [this
+/// article](https://en.wikipedia.org/wiki/Multiplicity_(mathematics)) for further information.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Root {
+    pub value: f64,
+    pub multiplicity: usize,
+}
+
+/// A complex root of a polynomial along with its multiplicity.
+///
+/// See [Function::find_complex_roots()](crate::Function::find_complex_roots) for usage and
+/// examples.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexRoot {
+    pub value: Complex,
+    pub multiplicity: usize,
+}
+
+// the maximum number of iterations used by the iterative methods before giving up
+const MAX_ITERATIONS: usize = 200;
+// the number of subintervals that an interval is split into when scanning for roots
+const SCAN_RESOLUTION: usize = 1000;
+// the tolerance used when refining roots found by scanning an interval
+const SCAN_TOLERANCE: f64 = 1e-14;
+// derivatives whose absolute value is smaller than this are considered to vanish
+const VANISHING_TOLERANCE: f64 = 1e-6;
+// the highest multiplicity that will be reported for a root
+const MAX_MULTIPLICITY: usize = 8;
+// the relative size of the correction at which the Aberth-Ehrlich method is considered to have
+// converged
+const ABERTH_TOLERANCE: f64 = 1e-15;
+// the number of Newton iterations used to polish the roots of polynomials
+const MAX_POLISHING_STEPS: usize = 5;
+// imaginary parts that are this small compared to the absolute value of a complex root are
+// considered to be rounding errors
+const IMAGINARY_TOLERANCE: f64 = 1e-12;
+
+impl Elementary {
+    /// Finds a root of the function within the interval [a, b] using [Brent's
+    /// method](https://en.wikipedia.org/wiki/Brent%27s_method).
+    ///
+    /// The function must take values of opposite signs at a and b. Otherwise an
+    /// [Error::RootFindingError](crate::Error) is returned.
+    pub fn find_root_brent(&self, a: f64, b: f64, tolerance: f64) -> Result<f64, Error> {
+        brent(&self.clone().call(), a, b, tolerance)
+    }
+
+    /// Finds a root of the function using [Newton's
+    /// method](https://en.wikipedia.org/wiki/Newton%27s_method) starting at x0.
+    ///
+    /// If the method does not converge, an [Error::RootFindingError](crate::Error) is returned.
+    pub fn find_root_newton(&self, x0: f64, tolerance: f64) -> Result<f64, Error> {
+        newton(
+            &self.clone().call(),
+            &self.clone().derivative_unsimplified().call(),
+            x0,
+            tolerance,
+        )
+    }
+
+    /// Finds all roots of the function within the interval [a, b] along with an estimate of
+    /// their multiplicity.
+    pub fn find_all_roots(&self, a: f64, b: f64) -> Result<Vec<Root>, Error> {
+        if !(a.is_finite() && b.is_finite()) || a >= b {
+            return Err(Error::InputError(format!(
+                "Cannot search for roots in the interval [{a}, {b}]"
+            )));
+        }
+
+        // the roots of polynomials can be isolated exactly using Sturm sequences. This includes
+        // the zero polynomial, which is reported as an error since every number is a root of it
+        if let Ok(polynomial) = Polynomial::try_from(self) {
+            if polynomial.degree() > 0 || polynomial.is_zero() {
+                return polynomial.real_roots(a, b);
+            }
+        }
+
+        let function = self.clone().call();
+
+        // functions that vanish everywhere without simplifying to zero (such as sin(0x))
+        let step = (b - a) / SCAN_RESOLUTION as f64;
+        if (0..=SCAN_RESOLUTION).all(|i| function(a + i as f64 * step) == 0.) {
+            return Err(Error::InputError(format!(
+                "Every number in [{a}, {b}] is a root of {self:?}"
+            )));
+        }
+
+        let derivative = self.clone().derivative_unsimplified();
+        let callable_derivative = derivative.clone().call();
+
+        let mut candidates: Vec<f64> = Vec::new();
+
+        // roots where the function changes sign
+        candidates.append(&mut sign_changes(&function, a, b)?);
+
+        // roots where the function touches the x-axis without crossing it must be roots of the
+        // derivative
+        for critical_point in sign_changes(&callable_derivative, a, b)? {
+            let value = function(critical_point);
+            if value.abs() < VANISHING_TOLERANCE {
+                candidates.push(critical_point);
+            }
+        }
+
+        candidates.sort_by(|x1, x2| x1.total_cmp(x2));
+
+        let mut roots: Vec<Root> = Vec::new();
+        for candidate in candidates {
+            let root = self.classify_root(candidate, &derivative);
+
+            if !is_root(&function, root.value, a, b) {
+                continue;
+            }
+
+            match roots.last_mut() {
+                // the same root might be found by both scans
+                Some(previous) if is_same_point(previous.value, root.value) => {
+                    if root.multiplicity > previous.multiplicity {
+                        *previous = root;
+                    }
+                }
+                _ => roots.push(root),
+            }
+        }
+
+        Ok(roots)
+    }
+
+    // estimates the multiplicity of the root by counting the number of derivatives that vanish.
+    // Roots of high multiplicity are hard to locate accurately, so the location is refined using
+    // the highest order derivative that vanishes (which has a simple root at the same point)
+    fn classify_root(&self, value: f64, first_derivative: &Elementary) -> Root {
+        let mut derivatives = vec![self.clone(), first_derivative.clone()];
+        let mut value = value;
+        let mut multiplicity = 1;
+
+        while multiplicity < MAX_MULTIPLICITY {
+            let derivative = derivatives[multiplicity].clone().call();
+            if derivative(value).abs() >= VANISHING_TOLERANCE {
+                break;
+            }
+
+            // refine the location using the root of the derivative
+            let next_derivative = derivatives[multiplicity].clone().derivative_unsimplified();
+            if let Ok(refined) = newton(&derivative, &next_derivative.clone().call(), value, 1e-15)
+            {
+                if (refined - value).abs() < 1e-4 * value.abs().max(1.) {
+                    value = refined;
+                }
+            }
+
+            derivatives.push(next_derivative);
+            multiplicity += 1;
+        }
+
+        Root {
+            value,
+            multiplicity,
+        }
+    }
+}
+
+impl Function {
+    /// Finds a root of the function within the interval [a, b] using [Brent's
+    /// method](https://en.wikipedia.org/wiki/Brent%27s_method), which combines bisection with
+    /// the faster secant and inverse quadratic interpolation methods.
+    ///
+    /// The function must take values of opposite signs at a and b. If this is not the case, or
+    /// if the method fails to converge, an [Error::RootFindingError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("x^2 - 2");
+    ///
+    /// let root = function.find_root_brent(0., 2., 1e-12).unwrap();
+    ///
+    /// assert!((root - 2_f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn find_root_brent(&self, a: f64, b: f64, tolerance: f64) -> Result<f64, Error> {
+        self.elementary().find_root_brent(a, b, tolerance)
+    }
+
+    /// Finds a root of the function using [Newton's
+    /// method](https://en.wikipedia.org/wiki/Newton%27s_method) starting at x0. The derivative
+    /// used by the method is found analytically.
+    ///
+    /// If the method fails to converge, an [Error::RootFindingError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// let function = Function::from("cos(x)");
+    ///
+    /// let root = function.find_root_newton(1., 1e-12).unwrap();
+    ///
+    /// assert!((root - std::f64::consts::PI / 2.).abs() < 1e-12);
+    /// ```
+    pub fn find_root_newton(&self, x0: f64, tolerance: f64) -> Result<f64, Error> {
+        self.elementary().find_root_newton(x0, tolerance)
+    }
+
+    /// Finds all roots of the function within the interval [a, b].
+    ///
+    /// If the function is a polynomial, its roots are isolated using [Sturm
+    /// sequences](https://en.wikipedia.org/wiki/Sturm%27s_theorem) and their multiplicities are
+    /// found exactly (see [Polynomial::real_roots()](crate::Polynomial::real_roots)).
+    ///
+    /// Otherwise, the interval is scanned for points where the function changes sign as well as
+    /// points where the function touches the x-axis without crossing it (found as roots of the
+    /// derivative). Every root is returned along with an estimate of its
+    /// [multiplicity](crate::Root). A function that vanishes on the entire interval has no
+    /// isolated roots, in which case an [Error::InputError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// // (x - 1)²(x + 2) has a double root at x = 1 and a simple root at x = -2
+    /// let function = Function::from("(x - 1)^2 * (x + 2)");
+    ///
+    /// let roots = function.find_all_roots(-5., 5.).unwrap();
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert_eq!(roots[0].multiplicity, 1);
+    /// assert_eq!(roots[1].multiplicity, 2);
+    /// ```
+    pub fn find_all_roots(&self, a: f64, b: f64) -> Result<Vec<Root>, Error> {
+        self.elementary().find_all_roots(a, b)
+    }
+
+    /// Finds all complex roots of the function along with their multiplicities, provided that
+    /// the function is a polynomial. See [Polynomial::complex_roots()](crate::Polynomial) for
+    /// further information.
+    ///
+    /// If the function is not a polynomial, an [Error::SimplifyError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Function;
+    /// // x² + 1 has the roots ±i
+    /// let function = Function::from("x^2 + 1");
+    ///
+    /// let roots = function.find_complex_roots().unwrap();
+    ///
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0].value.im + 1.).abs() < 1e-12);
+    /// assert!((roots[1].value.im - 1.).abs() < 1e-12);
+    /// ```
+    pub fn find_complex_roots(&self) -> Result<Vec<ComplexRoot>, Error> {
+        Polynomial::try_from(self)?.complex_roots()
+    }
+}
+
+impl Polynomial {
+    /// Returns the [square-free
+    /// factorization](https://en.wikipedia.org/wiki/Square-free_polynomial) of the polynomial,
+    /// i.e. the monic polynomials f₁, f₂, f₃, ... without repeated roots such that the polynomial
+    /// is a constant multiple of f₁ * f₂² * f₃³ * .... Every factor is returned along with its
+    /// multiplicity, and factors that are constant are left out.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Polynomial;
+    /// // (x - 1)²(x + 2) = x³ - 3x + 2
+    /// let polynomial = Polynomial::new(vec![2., -3., 0., 1.]);
+    ///
+    /// let factors = polynomial.square_free_factorization();
+    ///
+    /// assert_eq!(factors.len(), 2);
+    /// assert_eq!(factors[0], (Polynomial::new(vec![2., 1.]), 1));
+    /// assert_eq!(factors[1], (Polynomial::new(vec![-1., 1.]), 2));
+    /// ```
+    pub fn square_free_factorization(&self) -> Vec<(Polynomial, usize)> {
+        if self.degree() == 0 {
+            return Vec::new();
+        }
+
+        // Yun's algorithm, see https://en.wikipedia.org/wiki/Square-free_polynomial
+        let derivative = self.derivative();
+        let common = self.gcd(&derivative);
+        let mut b = quotient(self, &common);
+        let mut d = quotient(&derivative, &common) - b.derivative();
+        d.clean(b.max_coefficient());
+
+        let mut factors = Vec::new();
+        let mut multiplicity = 1;
+        while b.degree() > 0 {
+            let factor = b.gcd(&d);
+            b = quotient(&b, &factor);
+            let c = quotient(&d, &factor);
+            d = c.clone() - b.derivative();
+            d.clean(c.max_coefficient().max(b.max_coefficient()));
+
+            if factor.degree() > 0 {
+                factors.push((factor, multiplicity));
+            }
+            multiplicity += 1;
+        }
+
+        factors
+    }
+
+    /// Returns the [Sturm sequence](https://en.wikipedia.org/wiki/Sturm%27s_theorem) p₀, p₁, ...
+    /// of the polynomial, where p₀ = p, p₁ = p' and pᵢ₊₁ = -rem(pᵢ₋₁, pᵢ).
+    pub fn sturm_sequence(&self) -> Vec<Polynomial> {
+        let mut sequence = vec![self.clone()];
+        let mut next = self.derivative();
+
+        while !next.is_zero() {
+            let (_, remainder) = sequence
+                .last()
+                .expect("the sequence is never empty")
+                .div_rem(&next)
+                .expect("next is not the zero polynomial");
+            sequence.push(next);
+            next = -remainder;
+        }
+
+        sequence
+    }
+
+    /// Returns the number of distinct real roots within the half-open interval (a, b] using
+    /// [Sturm's theorem](https://en.wikipedia.org/wiki/Sturm%27s_theorem).
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Polynomial;
+    /// // x³ - x = x(x - 1)(x + 1)
+    /// let polynomial = Polynomial::new(vec![0., -1., 0., 1.]);
+    ///
+    /// assert_eq!(polynomial.count_real_roots(-2., 2.), 3);
+    /// assert_eq!(polynomial.count_real_roots(-0.5, 0.5), 1);
+    /// ```
+    pub fn count_real_roots(&self, a: f64, b: f64) -> usize {
+        if self.is_zero() || a >= b {
+            return 0;
+        }
+        let sequence = self.sturm_sequence();
+        sign_variations(&sequence, a).saturating_sub(sign_variations(&sequence, b))
+    }
+
+    /// Finds all real roots within the interval [a, b] along with their multiplicities.
+    ///
+    /// The polynomial is split into its [square-free
+    /// factors](crate::Polynomial::square_free_factorization), meaning that the multiplicity of
+    /// every root is known exactly. The roots of each factor are isolated using [Sturm
+    /// sequences](crate::Polynomial::sturm_sequence) and then refined to full precision. Unlike
+    /// scanning for sign changes, this finds roots of even multiplicity (such as the double root
+    /// of (x - 1)²) as reliably as simple roots. Multiple roots are checked against the Taylor
+    /// coefficients of the polynomial around them, so that distinct roots lying too close
+    /// together for the factorization to tell apart are still reported separately.
+    ///
+    /// If the polynomial is the zero polynomial, an [Error::InputError](crate::Error) is returned.
+    pub fn real_roots(&self, a: f64, b: f64) -> Result<Vec<Root>, Error> {
+        if !(a.is_finite() && b.is_finite()) || a > b {
+            return Err(Error::InputError(format!(
+                "Cannot search for roots in the interval [{a}, {b}]"
+            )));
+        } else if self.is_zero() {
+            return Err(Error::InputError(String::from(
+                "Every number is a root of the zero polynomial",
+            )));
+        }
+
+        // the factors are subject to rounding errors, so roots that lie just outside of the
+        // interval are still taken into account
+        let tolerance = 1e-9 * a.abs().max(b.abs()).max(1.);
+
+        // roots that turn out to lie outside of the interval once polished are dropped, unless
+        // the nearest end of the interval is a root itself
+        let mut roots: Vec<Root> = self
+            .factored_roots(a - tolerance, b + tolerance)
+            .into_iter()
+            .flat_map(|root| self.split_cluster(root))
+            .filter_map(|root| {
+                let value = match root.value {
+                    value if (a..=b).contains(&value) => value,
+                    value if value < a && self.call(a) == 0. => a,
+                    value if value > b && self.call(b) == 0. => b,
+                    _ => return None,
+                };
+                Some(Root { value, ..root })
+            })
+            .collect();
+
+        roots.sort_by(|root1, root2| root1.value.total_cmp(&root2.value));
+        Ok(roots)
+    }
+
+    // the polished roots of the square-free factors within (a, b]
+    fn factored_roots(&self, a: f64, b: f64) -> Vec<Root> {
+        let mut roots = Vec::new();
+        for (factor, multiplicity) in self.square_free_factorization() {
+            let mut values = Vec::new();
+            isolate_roots(&factor.sturm_sequence(), a, b, &mut values);
+
+            roots.extend(values.into_iter().map(|value| Root {
+                value: self.polish_root(value, multiplicity),
+                multiplicity,
+            }));
+        }
+        roots
+    }
+
+    // the polynomial gcd behind the square-free factorization tolerates rounding errors, which
+    // also merges distinct roots that lie very close together (such as those of
+    // (x - 1)(x - 1 - 10⁻⁷)) into a single multiple root. At a root of multiplicity m, the first
+    // m Taylor coefficients around the root vanish up to rounding errors, whereas a cluster of
+    // distinct roots leaves some of them larger than that. The local polynomial formed by the
+    // first m + 1 coefficients then contains the cluster, which is rescaled so that its roots
+    // are counted and isolated with Sturm sequences like any other polynomial
+    fn split_cluster(&self, root: Root) -> Vec<Root> {
+        let multiplicity = root.multiplicity;
+        if multiplicity == 1 {
+            return vec![root];
+        }
+
+        // the rounding errors of the coefficients are estimated from the Taylor coefficients of
+        // the polynomial with the absolute values of the original coefficients
+        let magnitudes = Polynomial::new(
+            self.coefficients()
+                .iter()
+                .map(|coefficient| coefficient.abs())
+                .collect(),
+        );
+        let (mut derivative, mut magnitude_derivative) = (self.clone(), magnitudes);
+        let mut factorial = 1.;
+        let mut local = Vec::new();
+        for k in 0..=multiplicity {
+            if k > 0 {
+                derivative = derivative.derivative();
+                magnitude_derivative = magnitude_derivative.derivative();
+                factorial *= k as f64;
+            }
+            let coefficient = derivative.call(root.value) / factorial;
+            let rounding_error =
+                f64::EPSILON * magnitude_derivative.call(root.value.abs()) / factorial;
+            if k == multiplicity || coefficient.abs() > rounding_error {
+                local.push(coefficient);
+            } else {
+                local.push(0.);
+            }
+        }
+
+        // the roots of the local polynomial lie within twice this radius of the root
+        let leading_coefficient = local[multiplicity];
+        let radius = (0..multiplicity)
+            .filter(|k| local[*k] != 0.)
+            .map(|k| {
+                (local[k] / leading_coefficient)
+                    .abs()
+                    .powf(1. / (multiplicity - k) as f64)
+            })
+            .fold(0., f64::max);
+        if radius == 0. {
+            return vec![root];
+        }
+
+        let scaled = Polynomial::new(
+            local
+                .iter()
+                .enumerate()
+                .map(|(k, coefficient)| {
+                    coefficient / leading_coefficient * radius.powi(k as i32 - multiplicity as i32)
+                })
+                .collect(),
+        );
+        scaled
+            .factored_roots(-2., 2.)
+            .into_iter()
+            .map(|local_root| Root {
+                value: self.polish_root(
+                    root.value + radius * local_root.value,
+                    local_root.multiplicity,
+                ),
+                multiplicity: local_root.multiplicity,
+            })
+            .collect()
+    }
+
+    /// Finds all complex roots of the polynomial along with their multiplicities using the
+    /// [Aberth-Ehrlich method](https://en.wikipedia.org/wiki/Aberth_method).
+    ///
+    /// The method is applied to each [square-free
+    /// factor](crate::Polynomial::square_free_factorization) of the polynomial, meaning that the
+    /// multiplicity of every root is known exactly. The roots are sorted by their real parts,
+    /// followed by their imaginary parts.
+    ///
+    /// If the polynomial is the zero polynomial, an [Error::InputError](crate::Error) is returned.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Polynomial;
+    /// // (x² + 1)(x - 2)² = x⁴ - 4x³ + 5x² - 4x + 4
+    /// let polynomial = Polynomial::new(vec![4., -4., 5., -4., 1.]);
+    ///
+    /// let roots = polynomial.complex_roots().unwrap();
+    ///
+    /// assert_eq!(roots.len(), 3);
+    /// assert!((roots[2].value.re - 2.).abs() < 1e-12);
+    /// assert_eq!(roots[2].value.im, 0.);
+    /// assert_eq!(roots[2].multiplicity, 2);
+    /// ```
+    pub fn complex_roots(&self) -> Result<Vec<ComplexRoot>, Error> {
+        if self.is_zero() {
+            return Err(Error::InputError(String::from(
+                "Every number is a root of the zero polynomial",
+            )));
+        }
+
+        let mut roots = Vec::new();
+        for (factor, multiplicity) in self.square_free_factorization() {
+            roots.extend(aberth(&factor)?.into_iter().map(|value| ComplexRoot {
+                value,
+                multiplicity,
+            }));
+        }
+
+        roots.sort_by(|root1, root2| {
+            root1
+                .value
+                .re
+                .total_cmp(&root2.value.re)
+                .then(root1.value.im.total_cmp(&root2.value.im))
+        });
+        Ok(roots)
+    }
+
+    // the square-free factors are only accurate up to rounding errors. A root of multiplicity m
+    // is a simple root of the (m - 1)th derivative, so the root is polished using Newton's method
+    // on that derivative
+    fn polish_root(&self, value: f64, multiplicity: usize) -> f64 {
+        let function = (1..multiplicity).fold(self.clone(), |acc, _| acc.derivative());
+        let derivative = function.derivative();
+
+        let mut polished = value;
+        for _ in 0..MAX_POLISHING_STEPS {
+            let step = function.call(polished) / derivative.call(polished);
+            if !step.is_finite() || step == 0. {
+                break;
+            }
+            polished -= step;
+        }
+
+        // Newton's method might wander off if the derivative is small
+        if (polished - value).abs() < 1e-6 * value.abs().max(1.) {
+            polished
+        } else {
+            value
+        }
+    }
+
+    /// Evaluates the polynomial at the complex number z.
+    pub fn call_complex(&self, z: Complex) -> Complex {
+        self.coefficients()
+            .iter()
+            .rev()
+            .fold(Complex::default(), |acc, coefficient| {
+                acc * z + Complex::from(*coefficient)
+            })
+    }
+}
+
+// Brent's method as described in https://en.wikipedia.org/wiki/Brent%27s_method
+fn brent(function: &Func, a: f64, b: f64, tolerance: f64) -> Result<f64, Error> {
+    let (mut a, mut b) = (a, b);
+    let (mut fa, mut fb) = (function(a), function(b));
+
+    if fa == 0. {
+        return Ok(a);
+    } else if fb == 0. {
+        return Ok(b);
+    } else if !(fa.is_finite() && fb.is_finite()) || fa * fb > 0. {
+        return Err(Error::RootFindingError(format!(
+            "Brent's method requires the function to take values of opposite signs at the ends of the interval [{a}, {b}]"
+        )));
+    }
+
+    if fa.abs() < fb.abs() {
+        std::mem::swap(&mut a, &mut b);
+        std::mem::swap(&mut fa, &mut fb);
+    }
+
+    let (mut c, mut fc) = (a, fa);
+    let mut d = c;
+    let mut bisected = true;
+
+    for _ in 0..MAX_ITERATIONS {
+        // the tolerance can't be smaller than the spacing of the floats around the root
+        let tolerance = tolerance.max(4. * f64::EPSILON * b.abs());
+        if fb == 0. || (b - a).abs() < tolerance {
+            return Ok(b);
+        }
+
+        let mut s = if fa != fc && fb != fc {
+            // inverse quadratic interpolation
+            a * fb * fc / ((fa - fb) * (fa - fc))
+                + b * fa * fc / ((fb - fa) * (fb - fc))
+                + c * fa * fb / ((fc - fa) * (fc - fb))
+        } else {
+            // secant method
+            b - fb * (b - a) / (fb - fa)
+        };
+
+        let bound = (3. * a + b) / 4.;
+        let outside_bounds = !((s > bound.min(b)) && (s < bound.max(b)));
+        if outside_bounds
+            || (bisected && (s - b).abs() >= (b - c).abs() / 2.)
+            || (!bisected && (s - b).abs() >= (c - d).abs() / 2.)
+            || (bisected && (b - c).abs() < tolerance)
+            || (!bisected && (c - d).abs() < tolerance)
+        {
+            s = (a + b) / 2.;
+            bisected = true;
+        } else {
+            bisected = false;
+        }
+
+        let fs = function(s);
+        d = c;
+        c = b;
+        fc = fb;
+
+        if fa * fs < 0. {
+            b = s;
+            fb = fs;
+        } else {
+            a = s;
+            fa = fs;
+        }
+
+        if fa.abs() < fb.abs() {
+            std::mem::swap(&mut a, &mut b);
+            std::mem::swap(&mut fa, &mut fb);
+        }
+    }
+
+    Err(Error::RootFindingError(format!(
+        "Brent's method failed to converge within {MAX_ITERATIONS} iterations"
+    )))
+}
+
+fn newton(function: &Func, derivative: &Func, x0: f64, tolerance: f64) -> Result<f64, Error> {
+    let mut x = x0;
+
+    for _ in 0..MAX_ITERATIONS {
+        let value = function(x);
+        if value == 0. {
+            return Ok(x);
+        }
+
+        let slope = derivative(x);
+        if slope == 0. || !slope.is_finite() || !value.is_finite() {
+            return Err(Error::RootFindingError(format!(
+                "Newton's method failed since the derivative is {slope} at x = {x}"
+            )));
+        }
+
+        let step = value / slope;
+        x -= step;
+
+        if step.abs() < tolerance {
+            return Ok(x);
+        }
+    }
+
+    Err(Error::RootFindingError(format!(
+        "Newton's method failed to converge within {MAX_ITERATIONS} iterations starting from x = {x0}"
+    )))
+}
+
+// scans the interval for sign changes and refines each of them using Brent's method
+pub(crate) fn sign_changes(function: &Func, a: f64, b: f64) -> Result<Vec<f64>, Error> {
+    let step = (b - a) / SCAN_RESOLUTION as f64;
+    let points: Vec<f64> = (0..=SCAN_RESOLUTION).map(|i| a + i as f64 * step).collect();
+    let values: Vec<f64> = points.iter().map(|x| function(*x)).collect();
+
+    let mut roots: Vec<f64> = Vec::new();
+    for i in 0..SCAN_RESOLUTION {
+        if values[i] == 0. {
+            roots.push(points[i]);
+        } else if values[i] * values[i + 1] < 0.
+            && values[i].is_finite()
+            && values[i + 1].is_finite()
+        {
+            roots.push(brent(function, points[i], points[i + 1], SCAN_TOLERANCE)?);
+        }
+    }
+    if values[SCAN_RESOLUTION] == 0. {
+        roots.push(b);
+    }
+
+    Ok(roots)
+}
+
+// makes sure that the point is an actual root and not a discontinuity (like 1/x at x = 0) where
+// the function changes sign
+fn is_root(function: &Func, x: f64, a: f64, b: f64) -> bool {
+    let value = function(x);
+    if !value.is_finite() || x < a || x > b {
+        return false;
+    }
+
+    let step = (b - a) / SCAN_RESOLUTION as f64;
+    let scale = function(x - step)
+        .abs()
+        .max(function(x + step).abs())
+        .max(1.);
+
+    value.abs() < VANISHING_TOLERANCE * scale
+}
+
+pub(crate) fn is_same_point(x1: f64, x2: f64) -> bool {
+    (x1 - x2).abs() < 1e-7 * x1.abs().max(1.)
+}
+
+// the quotient of a division where the remainder is known to be zero
+fn quotient(dividend: &Polynomial, divisor: &Polynomial) -> Polynomial {
+    dividend
+        .div_rem(divisor)
+        .expect("the divisor is never the zero polynomial")
+        .0
+}
+
+// the number of sign changes in the Sturm sequence evaluated at x (ignoring zeros)
+fn sign_variations(sequence: &[Polynomial], x: f64) -> usize {
+    let signs: Vec<f64> = sequence
+        .iter()
+        .map(|polynomial| polynomial.call(x))
+        .filter(|value| *value != 0.)
+        .map(f64::signum)
+        .collect();
+
+    signs.windows(2).filter(|pair| pair[0] != pair[1]).count()
+}
+
+// recursively bisects the interval (a, b] until every part contains a single root, which is then
+// refined by further bisection
+fn isolate_roots(sequence: &[Polynomial], a: f64, b: f64, roots: &mut Vec<f64>) {
+    let count = sign_variations(sequence, a).saturating_sub(sign_variations(sequence, b));
+    let middle = (a + b) / 2.;
+
+    if count == 0 {
+        // there are no roots to isolate
+    } else if middle <= a || middle >= b {
+        // the interval cannot be split any further
+        roots.push(b);
+    } else if count == 1 {
+        roots.push(refine_root(sequence, a, b));
+    } else {
+        isolate_roots(sequence, a, middle, roots);
+        isolate_roots(sequence, middle, b, roots);
+    }
+}
+
+// narrows down the interval (a, b] containing a single root until its endpoints are adjacent
+// floating point numbers
+fn refine_root(sequence: &[Polynomial], a: f64, b: f64) -> f64 {
+    let (mut a, mut b) = (a, b);
+    let mut variations_a = sign_variations(sequence, a);
+
+    loop {
+        let middle = (a + b) / 2.;
+        if middle <= a || middle >= b {
+            return b;
+        }
+
+        let variations_middle = sign_variations(sequence, middle);
+        if variations_a > variations_middle {
+            // the root is located in (a, middle]
+            b = middle;
+        } else {
+            a = middle;
+            variations_a = variations_middle;
+        }
+    }
+}
+
+// finds all roots of a polynomial without repeated roots using the Aberth-Ehrlich method
+fn aberth(polynomial: &Polynomial) -> Result<Vec<Complex>, Error> {
+    let coefficients = polynomial.coefficients();
+
+    // the roots at 0 are factored out first, since the initial guesses below would all collapse
+    // onto 0 otherwise
+    let zeros = coefficients
+        .iter()
+        .take_while(|coefficient| **coefficient == 0.)
+        .count();
+    if zeros > 0 {
+        let mut roots = vec![Complex::default(); zeros];
+        let remaining = Polynomial::new(coefficients[zeros..].to_vec());
+        if remaining.degree() > 0 {
+            roots.extend(aberth(&remaining)?);
+        }
+        return Ok(roots);
+    }
+
+    let degree = polynomial.degree();
+    if degree == 1 {
+        return Ok(vec![Complex::from(-coefficients[0] / coefficients[1])]);
+    }
+
+    let derivative = polynomial.derivative();
+
+    // the initial guesses are spread out on a circle whose radius is the geometric mean of the
+    // absolute values of the roots
+    let radius = (coefficients[0] / coefficients[degree])
+        .abs()
+        .powf(1. / degree as f64)
+        .max(f64::MIN_POSITIVE);
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| Complex::from_polar(radius, 2. * PI * k as f64 / degree as f64 + 0.4))
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut converged = true;
+
+        for k in 0..degree {
+            let value = polynomial.call_complex(roots[k]);
+            if value == Complex::default() {
+                continue;
+            }
+            let ratio = value / derivative.call_complex(roots[k]);
+            let repulsion = (0..degree)
+                .filter(|j| *j != k)
+                .fold(Complex::default(), |acc, j| {
+                    acc + Complex::from(1.) / (roots[k] - roots[j])
+                });
+
+            let correction = ratio / (Complex::from(1.) - ratio * repulsion);
+            if !(correction.re.is_finite() && correction.im.is_finite()) {
+                return Err(Error::RootFindingError(String::from(
+                    "The Aberth-Ehrlich method encountered a non-finite correction",
+                )));
+            }
+
+            roots[k] = roots[k] - correction;
+            if correction.abs() > ABERTH_TOLERANCE * roots[k].abs().max(1.) {
+                converged = false;
+            }
+        }
+
+        if converged {
+            // the imaginary parts of real roots are rounding errors
+            for root in roots.iter_mut() {
+                if root.im.abs() < IMAGINARY_TOLERANCE * root.abs().max(1.) {
+                    root.im = 0.;
+                }
+            }
+            return Ok(roots);
+        }
+    }
+
+    Err(Error::RootFindingError(format!(
+        "The Aberth-Ehrlich method failed to converge within {MAX_ITERATIONS} iterations"
+    )))
+}
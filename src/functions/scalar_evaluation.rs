@@ -0,0 +1,110 @@
+use crate::{
+    functions::calc::unbound_wildcard,
+    Elementary::{self, *},
+    Function, Scalar,
+};
+
+fn evaluate<T: Scalar>(function: &Elementary, x: &T) -> T {
+    match function {
+        Sin(func) => evaluate(func, x).sin(),
+        Cos(func) => evaluate(func, x).cos(),
+        Tan(func) => evaluate(func, x).tan(),
+        Sec(func) => evaluate(func, x).sec(),
+        Csc(func) => evaluate(func, x).csc(),
+        Cot(func) => evaluate(func, x).cot(),
+        Asin(func) => evaluate(func, x).asin(),
+        Acos(func) => evaluate(func, x).acos(),
+        Atan(func) => evaluate(func, x).atan(),
+        Acot(func) => evaluate(func, x).acot(),
+        Asec(func) => evaluate(func, x).asec(),
+        Acsc(func) => evaluate(func, x).acsc(),
+        Sinh(func) => evaluate(func, x).sinh(),
+        Cosh(func) => evaluate(func, x).cosh(),
+        Tanh(func) => evaluate(func, x).tanh(),
+        Sech(func) => evaluate(func, x).sech(),
+        Csch(func) => evaluate(func, x).csch(),
+        Coth(func) => evaluate(func, x).coth(),
+        Asinh(func) => evaluate(func, x).asinh(),
+        Acosh(func) => evaluate(func, x).acosh(),
+        Atanh(func) => evaluate(func, x).atanh(),
+
+        Add(func1, func2) => evaluate(func1, x) + evaluate(func2, x),
+        Sub(func1, func2) => evaluate(func1, x) - evaluate(func2, x),
+        Mul(func1, func2) => evaluate(func1, x) * evaluate(func2, x),
+        Div(func1, func2) => evaluate(func1, x) / evaluate(func2, x),
+        // constant exponents are handled separately, since f(x)^c is defined for negative f(x)
+        // unlike e^(c ln(f(x)))
+        Pow(base, exp) if exp.is_constant() => {
+            evaluate(base, x).powf(exp.constant_value().to_f64())
+        }
+        Pow(base, exp) => evaluate(base, x).pow(&evaluate(exp, x)),
+        Log(base, func) => evaluate(func, x).ln() / evaluate(base, x).ln(),
+
+        Factorial(func) => (evaluate(func, x) + x.lift(1.)).gamma(),
+        Gamma(func) => evaluate(func, x).gamma(),
+        Polygamma(func, order) => evaluate(func, x).polygamma(*order),
+        Abs(func) => evaluate(func, x).abs(),
+        Cbrt(func) => evaluate(func, x).cbrt(),
+        Floor(func) => evaluate(func, x).floor(),
+        Ceil(func) => evaluate(func, x).ceil(),
+        Piecewise(branches, otherwise) => {
+            // the values of the branches that may apply, when their conditions can't be decided
+            let mut undecided: Option<T> = None;
+            for (condition, func) in branches {
+                let difference = evaluate(&condition.lhs, x) - evaluate(&condition.rhs, x);
+                match difference.satisfies(condition.relation) {
+                    Some(true) => return join(undecided, evaluate(func, x)),
+                    Some(false) => {}
+                    None => undecided = Some(join(undecided, evaluate(func, x))),
+                }
+            }
+            join(undecided, evaluate(otherwise, x))
+        }
+
+        Con(numb) => x.lift(*numb),
+        Rat(rational) => x.lift(rational.to_f64()),
+        Sym(constant) => x.lift(constant.value()),
+        X => x.clone(),
+        Wildcard(name) => panic!("{}", unbound_wildcard(name)),
+    }
+}
+
+fn join<T: Scalar>(undecided: Option<T>, value: T) -> T {
+    match undecided {
+        Some(undecided) => undecided.join(&value),
+        None => value,
+    }
+}
+
+impl Elementary {
+    /// Evaluates the function at any number type implementing [Scalar](crate::Scalar), such as
+    /// f32 or the [Dual](crate::Dual) numbers.
+    ///
+    /// Panics if the function is a pattern containing [wildcards](crate::Elementary::Wildcard),
+    /// which have no value to evaluate.
+    pub fn eval<T: Scalar>(&self, x: T) -> T {
+        evaluate(self, &x)
+    }
+}
+
+impl Function {
+    /// Evaluates the function at any number type implementing [Scalar](crate::Scalar).
+    ///
+    /// Unlike [call()](crate::Function::call), which is limited to f64, this evaluates the same
+    /// function on f32, on [Dual](crate::Dual) numbers, on [Interval](crate::Interval)s or on
+    /// user-defined number types.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Dual, Function};
+    /// let function = Function::from("x^3 + 2*x");
+    ///
+    /// assert_eq!(function.eval(2_f32), 12.);
+    ///
+    /// // the derivative 3x^2 + 2 comes along with the value
+    /// assert_eq!(function.eval(Dual::variable(2.)), Dual::new(12., 14.));
+    /// ```
+    pub fn eval<T: Scalar>(&self, x: T) -> T {
+        self.elementary().eval(x)
+    }
+}
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use crate::{
+    utils::rational::Number,
     Elementary::{self, *},
-    Error, Factorial, Function,
+    Error, ExpressionArena, Factorial, Function,
 };
 
 #[derive(Debug, Clone)]
@@ -53,18 +54,13 @@ impl Elementary {
     pub fn expand_taylor(&self, order: u8, centre: f64) -> Result<SeriesExpansion, Error> {
         let mut terms: Vec<Elementary> = Vec::new();
 
-        let mut current_derivative = self.clone();
+        let coefficients = self.exact_taylor_coefficients(order, centre);
 
-        let first_term = current_derivative.clone().call()(centre);
-
-        terms.push(Con(first_term));
-
-        for i in 1..=order {
-            current_derivative = current_derivative.derivative_unsimplified();
+        terms.push(Elementary::from_number(coefficients[0]));
 
+        for (i, coefficient) in coefficients.iter().enumerate().skip(1) {
             let ith_term = Pow(Arc::new(X - centre), Arc::new(Con(i as f64)))
-                * current_derivative.clone().call()(centre)
-                / ((i as usize).factorial() as f64);
+                * Elementary::from_number(*coefficient);
 
             terms.push(ith_term);
         }
@@ -82,4 +78,34 @@ impl Elementary {
 
         Ok(SeriesExpansion::Taylor(res))
     }
+
+    // returns the coefficients f⁽ⁿ⁾(a)/n! for n = 0, 1, ..., order of the Taylor expansion
+    // centered around a
+    pub(crate) fn taylor_coefficients(&self, order: u8, centre: f64) -> Vec<f64> {
+        self.exact_taylor_coefficients(order, centre)
+            .into_iter()
+            .map(Number::to_f64)
+            .collect()
+    }
+
+    // the derivatives are evaluated numerically, but integer derivatives (such as those of sin(x)
+    // at 0) are divided by n! exactly, so that the coefficients are 1/6 rather than 0.1666...
+    fn exact_taylor_coefficients(&self, order: u8, centre: f64) -> Vec<Number> {
+        // the derivatives are taken in an arena so that their shared subexpressions are only
+        // differentiated and evaluated once
+        let mut arena = ExpressionArena::new();
+        let mut current_derivative = arena.intern(self);
+        let mut coefficients = vec![Number::from(arena.evaluate(current_derivative, centre))];
+
+        for i in 1..=order {
+            current_derivative = arena.derivative(current_derivative);
+
+            coefficients.push(
+                Number::from(arena.evaluate(current_derivative, centre))
+                    / Number::from((i as usize).factorial() as f64),
+            );
+        }
+
+        coefficients
+    }
 }
@@ -78,6 +78,73 @@ impl serde::Serialize for Elementary {
             Con(numb) => serializer.serialize_newtype_variant("Elementary", 22, "Con", &(*numb)),
 
             X => serializer.serialize_newtype_variant("Elementary", 23, "X", "{}"),
+
+            Wildcard(name) => {
+                serializer.serialize_newtype_variant("Elementary", 24, "Wildcard", &name)
+            }
+
+            Rat(rational) => serializer.serialize_newtype_variant(
+                "Elementary",
+                25,
+                "Rat",
+                &(rational.numerator(), rational.denominator()),
+            ),
+
+            Sym(constant) => {
+                serializer.serialize_newtype_variant("Elementary", 26, "Sym", constant.name())
+            }
+
+            Piecewise(branches, otherwise) => serializer.serialize_newtype_variant(
+                "Elementary",
+                27,
+                "Piecewise",
+                &(
+                    branches
+                        .iter()
+                        .map(|(condition, func)| {
+                            (
+                                (*condition.lhs).clone(),
+                                condition.relation.symbol(),
+                                (*condition.rhs).clone(),
+                                (**func).clone(),
+                            )
+                        })
+                        .collect::<Vec<_>>(),
+                    (**otherwise).clone(),
+                ),
+            ),
+
+            Acot(func) => serializer.serialize_newtype_variant("Elementary", 28, "Acot", &(**func)),
+
+            Asec(func) => serializer.serialize_newtype_variant("Elementary", 29, "Asec", &(**func)),
+
+            Acsc(func) => serializer.serialize_newtype_variant("Elementary", 30, "Acsc", &(**func)),
+
+            Sech(func) => serializer.serialize_newtype_variant("Elementary", 31, "Sech", &(**func)),
+
+            Csch(func) => serializer.serialize_newtype_variant("Elementary", 32, "Csch", &(**func)),
+
+            Coth(func) => serializer.serialize_newtype_variant("Elementary", 33, "Coth", &(**func)),
+
+            Asinh(func) => {
+                serializer.serialize_newtype_variant("Elementary", 34, "Asinh", &(**func))
+            }
+
+            Acosh(func) => {
+                serializer.serialize_newtype_variant("Elementary", 35, "Acosh", &(**func))
+            }
+
+            Atanh(func) => {
+                serializer.serialize_newtype_variant("Elementary", 36, "Atanh", &(**func))
+            }
+
+            Cbrt(func) => serializer.serialize_newtype_variant("Elementary", 37, "Cbrt", &(**func)),
+
+            Floor(func) => {
+                serializer.serialize_newtype_variant("Elementary", 38, "Floor", &(**func))
+            }
+
+            Ceil(func) => serializer.serialize_newtype_variant("Elementary", 39, "Ceil", &(**func)),
         }
     }
 }
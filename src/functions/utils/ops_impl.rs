@@ -9,14 +9,10 @@ use std::sync::Arc;
 impl Add for Elementary {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
-        if let Con(numb) = self {
-            if numb == 0. {
-                return rhs;
-            }
-        } else if let Con(numb) = rhs {
-            if numb == 0. {
-                return self;
-            }
+        if self == Con(0.) {
+            return rhs;
+        } else if rhs == Con(0.) {
+            return self;
         }
         Self::Add(Arc::new(self), Arc::new(rhs))
     }
@@ -41,18 +37,10 @@ impl Sub for Elementary {
 impl Mul for Elementary {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
-        if let Con(numb) = self {
-            if numb == 1. {
-                return rhs;
-            } else if numb == 0. {
-                return self;
-            }
-        } else if let Con(numb) = rhs {
-            if numb == 1. {
-                return self;
-            } else if numb == 0. {
-                return rhs;
-            }
+        if self == Con(1.) || rhs == Con(0.) {
+            return rhs;
+        } else if rhs == Con(1.) || self == Con(0.) {
+            return self;
         }
         Self::Mul(Arc::new(self), Arc::new(rhs))
     }
@@ -1,6 +1,6 @@
-use std::f64::{consts::E, NAN};
+use std::f64::NAN;
 
-use crate::{Elementary::*, Integrate, EULER_MASCHERONI};
+use crate::{Constant, Elementary::*, Integrate, EULER_MASCHERONI};
 
 /// An infinit number in the complex plane with an unknown or undefined complex argument.
 ///
@@ -29,7 +29,11 @@ fn factorial_integer(numb: u128) -> u128 {
 pub fn gamma_function(z: f64) -> f64 {
     let inner_funciton = Mul(
         Pow(X.into(), Sub(Con(z).into(), Con(1.).into()).into()).into(),
-        Pow(Con(E).into(), Mul(X.into(), Con(-1.).into()).into()).into(),
+        Pow(
+            Sym(Constant::E).into(),
+            Mul(X.into(), Con(-1.).into()).into(),
+        )
+        .into(),
     );
 
     // for whole numbers
@@ -60,8 +64,10 @@ pub fn polygamma_function(z: f64, m: usize) -> f64 {
     if m == 0 {
         digamma_function(z)
     } else {
-        let inner_funciton = Pow(Log(Con(E).into(), X.into()).into(), Con(m as f64).into())
-            * Pow(X.into(), Con(z - 1.).into())
+        let inner_funciton = Pow(
+            Log(Sym(Constant::E).into(), X.into()).into(),
+            Con(m as f64).into(),
+        ) * Pow(X.into(), Con(z - 1.).into())
             / (Con(1.) - X);
 
         -inner_funciton
@@ -30,13 +30,29 @@
 //! | asin      | "asin(_)"            | [asin()](crate::asin) |
 //! | acos      | "acos(_)"            | [acos()](crate::acos) |
 //! | atan      | "atan(_)"            | [atan()](crate::atan) |
+//! | acot      | "acot(_)"            | [acot()](crate::acot) |
+//! | asec      | "asec(_)"            | [asec()](crate::asec) |
+//! | acsc      | "acsc(_)"            | [acsc()](crate::acsc) |
 //! | sinh      | "sinh(_)"            | [sinh()](crate::sinh) |
 //! | cosh      | "cosh(_)"            | [cosh()](crate::cosh) |
 //! | tanh      | "tanh(_)"            | [tanh()](crate::tanh) |
+//! | sech      | "sech(_)"            | [sech()](crate::sech) |
+//! | csch      | "csch(_)"            | [csch()](crate::csch) |
+//! | coth      | "coth(_)"            | [coth()](crate::coth) |
+//! | asinh     | "asinh(_)"            | [asinh()](crate::asinh) |
+//! | acosh     | "acosh(_)"            | [acosh()](crate::acosh) |
+//! | atanh     | "atanh(_)"            | [atanh()](crate::atanh) |
 //! | natural log|"ln(_)"            | [ln()](crate::ln) |
+//! | logarithm | "log(_, _)"            | [log()](crate::log) |
+//! | exponential| "exp(_)"            | [exp()](crate::exp) |
 //! | absolute value|"abs(_)"           | [abs()](crate::abs) |
 //! | square root|"sqrt(_)"            | [sqrt()](crate::sqrt) |
+//! | cube root | "cbrt(_)"            | [cbrt()](crate::cbrt) |
+//! | floor     | "floor(_)"            | [floor()](crate::floor) |
+//! | ceiling   | "ceil(_)"            | [ceil()](crate::ceil) |
+//! | sign      | "sign(_)"            | [sign()](crate::sign) |
 //! | factorial | "_!"              | [factorial()](crate::factorial) |
+//! | piecewise | "piecewise(_ < _: _, _)" | [Elementary::piecewise()](crate::Elementary::piecewise) |
 //! | addition | "_ + _ "            | [+](core::ops::Add) |
 //! | subtraction| "_ - _"            | [-](core::ops::Sub)  |
 //! | multiplication| "_ * _"       | [*](core::ops::Mul) |
@@ -49,9 +65,14 @@
 //! another operation will make the parser return a [Parsing Error](crate::Error::ParseError).
 //!
 //! ### Derivatives
-//! All of the [supported functions](#supported-functions) are [smooth functions](https://en.wikipedia.org/wiki/Smoothness) which in turn
-//! means that once initialized, a [Function](crate::Function) is guaranteed to be a smooth function and so are all of its
-//! derivatives.
+//! Most of the [supported functions](#supported-functions) are [smooth functions](https://en.wikipedia.org/wiki/Smoothness)
+//! on their domains, and so are their derivatives. The exceptions are floor, ceil, sign, abs and
+//! piecewise functions: floor, ceil and sign jump at the points where their value changes, abs
+//! has a corner where its argument changes sign, and piecewise functions may jump or have corners
+//! where their conditions change. Away from those points the derivative is exact as well (the
+//! derivative of floor and ceil is 0 between their jumps, that of abs(f) is sign(f) f', and that of a
+//! piecewise function is the piecewise function of the derivatives of its branches), while at the
+//! points themselves the derivative doesn't exist and the value returned there shouldn't be relied on.
 //!
 //! Derivatives are calculated analytically. The provided derivative function will always be the
 //! the exact derivative of the original function (although not always in simplest form).
@@ -76,6 +97,212 @@
 //! specified function is infinit, the resulting value might be [inf](f64::INFINITY) even though
 //! the integral should converge.
 //!
+//! ### Limits
+//! Limits can be found using the [limit() method](crate::Function::limit) which takes a
+//! [Direction](crate::Direction) deciding whether the left-hand, right-hand or two-sided limit
+//! is wanted. Limits at infinity are found using the [limit_at_infinity()
+//! method](crate::Function::limit_at_infinity) and the [limit_at_negative_infinity()
+//! method](crate::Function::limit_at_negative_infinity).
+//!
+//! Indeterminate forms such as 0/0 and ∞/∞ are resolved using L'Hôpital's rule. Limits that
+//! diverge or do not exist are reported through [Error::LimitError](crate::Error).
+//!
+//! ### Root Finding
+//! A single root can be found using either [Brent's method](crate::Function::find_root_brent),
+//! which requires an interval on which the function changes sign, or [Newton's
+//! method](crate::Function::find_root_newton), which requires a starting point. All roots within
+//! an interval, along with their multiplicities, can be found using the [find_all_roots()
+//! method](crate::Function::find_all_roots).
+//!
+//! The roots of polynomials are isolated using Sturm sequences, which guarantees that roots of
+//! even multiplicity are found as well. All complex roots of a polynomial can be found using the
+//! [find_complex_roots() method](crate::Function::find_complex_roots).
+//!
+//! ### Extrema
+//! The [critical_points() method](crate::Function::critical_points) finds all points within an
+//! interval where the derivative vanishes and classifies them as local minima, local maxima or
+//! saddle points. The global extrema on a closed interval are found using the [global_min_on()
+//! method](crate::Function::global_min_on) and the [global_max_on()
+//! method](crate::Function::global_max_on).
+//!
+//! ### Curve Analysis
+//! The [analyze() method](crate::Function::analyze) combines the above in order to sketch the
+//! graph of a function on an interval. The resulting [CurveReport](crate::CurveReport) contains
+//! the domain gaps, intercepts, intervals of monotonicity and concavity, inflection points and
+//! asymptotes of the function.
+//!
+//! ### Polynomials
+//! Functions that are polynomials can be converted into a [Polynomial](crate::Polynomial), which
+//! stores the coefficients of the polynomial directly. This allows for arithmetic, long division,
+//! greatest common divisors, differentiation, integration and composition without going through
+//! the expression tree.
+//!
+//! Quotients of polynomials are represented by [RationalFunction](crate::RationalFunction), which
+//! is always kept in lowest terms and can be decomposed into [partial
+//! fractions](crate::RationalFunction::partial_fractions).
+//!
+//! ### Trigonometric Simplification
+//! Simplifying a trigonometric expression applies the Pythagorean identities, the reciprocal
+//! relations between sin, cos, tan, sec, csc and cot, the double angle formulas and the
+//! sum-to-product formulas. Trigonometric functions evaluated at multiples of π/6 and π/4 are
+//! replaced by their exact values. This keeps derivatives readable: the derivative of tan(x)
+//! simplifies to sec²(x). Use [to_sin_cos()](crate::Elementary::to_sin_cos) to rewrite an
+//! expression in terms of sin and cos only.
+//!
+//! Exponentials and logarithms are simplified in the same manner: e^(ln(f)) and ln(e^f) are
+//! replaced by f, logarithms of products, quotients and powers are split up, quotients of
+//! logarithms with the same base are turned into a change of base, and products of powers of the
+//! same base are combined (e^a * e^b = e^(a + b)). Rules are only applied where the result is
+//! defined wherever the original expression is, so that for instance ln(x²) becomes 2ln|x|.
+//!
+//! ### Rewrite Rules
+//! Identities that the crate doesn't know about can be supplied as a [RuleSet](crate::RuleSet) of
+//! [rules](crate::Rule) such as "sin(?a)^2 + cos(?a)^2 -> 1", where ?a is a wildcard matching
+//! any subexpression. Rules may have side conditions and are applied from the bottom up until no
+//! more rules apply using the [rewrite() method](crate::Function::rewrite).
+//!
+//! ### Canonical Form
+//! The [canonical() method](crate::Elementary::canonical) flattens sums and products, orders their
+//! terms and factors deterministically, combines like terms and equal factors and evaluates
+//! constant subexpressions, so that for instance x + 2\*x + x\*3 becomes 6x. Wrapping a function
+//! in a [Canonical](crate::Canonical) compares and hashes it by its canonical form, which allows
+//! for functions to be used as keys in maps and sets.
+//!
+//! ### Expanding, Factoring and Collecting
+//! The shape of a function can be controlled using [expand()](crate::Function::expand), which
+//! multiplies out products and powers of sums, [factor()](crate::Function::factor), which pulls out
+//! common factors and splits polynomials into linear and quadratic factors,
+//! [collect()](crate::Function::collect), which groups terms by the powers of a variable, and
+//! [together()](crate::Function::together), which combines fractions over a common denominator.
+//!
+//! ### Exact Constants
+//! Constants such as 1/3 are stored as exact [rationals](crate::Rational) while functions are
+//! parsed, differentiated and simplified, so that the derivative of x²/3 is 2/3 x rather than
+//! 0.6666666666666666x. Constants are only converted into floating point numbers upon evaluation,
+//! or once exact arithmetic would overflow.
+//!
+//! ### Symbolic Constants
+//! The constants π, e, τ, φ, γ and the others listed in [Constant](crate::Constant) are parsed from
+//! their names ("pi", "tau", "phi", ...) as well as their symbols, and are kept symbolic by the
+//! simplification. For instance, ln(e) simplifies to exactly 1, sin(π) to 0 and 2π * 3 to 6π.
+//!
+//! ### Shared Expressions
+//! An [ExpressionArena](crate::ExpressionArena) stores expressions with every distinct subexpression
+//! interned once. Derivatives are memoized per node and evaluation computes every shared
+//! subexpression only once, so repeated differentiation grows polynomially rather than exponentially.
+//! Series expansions are computed this way: the 10th derivative of tan(sin(x)) takes around 2000 nodes.
+//!
+//! ### Automatic Differentiation
+//! When only the value of a derivative is needed, the function can be evaluated at a [Dual](crate::Dual)
+//! number instead of building the symbolic derivative. [HyperDual](crate::HyperDual) numbers give the
+//! second derivative and a [Jet](crate::Jet) gives derivatives of any order, all exact up to floating
+//! point precision:
+//! ```rust
+//! # use number_diff::Function;
+//! let function = Function::from("sin(x)*x");
+//!
+//! let (value, derivative) = function.value_and_derivative(0.);
+//! assert_eq!((value, derivative), (0., 0.));
+//!
+//! // f(0), f'(0), f''(0), f'''(0)
+//! assert_eq!(function.value_and_derivatives(0., 3), vec![0., 0., 2., 0.]);
+//! ```
+//!
+//! ### Gradients
+//! Functions of several variables are written with wildcards as the variables besides x, such as
+//! "(?a*x + ?b)^2". Recording a [Tape](crate::Tape) of such a function gives its value and full
+//! gradient in a single backward pass (reverse-mode automatic differentiation), which costs about as
+//! much as evaluating the function once regardless of the number of variables.
+//!
+//! ### Interval Arithmetic
+//! [eval_interval()](crate::Function::eval_interval) evaluates a function on a whole interval at once
+//! and returns an [Interval](crate::Interval) that is guaranteed to contain every value the function
+//! takes there. The bounds are rounded outward and respect the periodicity of the trigonometric
+//! functions, poles, and the domains of logarithms and roots, which makes them usable as proofs:
+//! ```rust
+//! # use number_diff::Function;
+//! let function = Function::from("x^2 + e^x");
+//!
+//! // the function has no roots on [-5, 0]
+//! assert!(function.eval_interval(-5., 0.).lo > 0.);
+//! ```
+//!
+//! ### Arbitrary Precision
+//! [eval_precise()](crate::Function::eval_precise) evaluates a function to any number of significant
+//! digits using the [BigFloat](crate::BigFloat) type, which covers every elementary function, the gamma
+//! function and the symbolic constants:
+//! ```rust
+//! # use number_diff::{BigFloat, Constant};
+//! let gamma = BigFloat::constant(Constant::EulerMascheroni, 40).unwrap();
+//!
+//! assert_eq!(gamma.to_string(), "0.5772156649015328606065120900824024310422");
+//! ```
+//!
+//! ### Generic Evaluation
+//! Besides f64, functions can be evaluated on any number type implementing the [Scalar](crate::Scalar)
+//! trait using [eval()](crate::Function::eval). The trait is implemented for f32, the dual numbers and
+//! intervals, and can be implemented for custom number types:
+//! ```rust
+//! # use number_diff::{Function, Interval};
+//! let function = Function::from("x^2 + 1");
+//!
+//! assert_eq!(function.eval(3_f32), 10.);
+//! // the enclosure of the values on [1, 2] is rounded outward
+//! let range = function.eval(Interval::new(1., 2.));
+//! assert!(range.lo <= 2. && range.hi >= 5.);
+//! ```
+//!
+//! ### Domain Errors
+//! [call()](crate::Function::call) returns NaN or ±∞ when a function is evaluated outside of its domain.
+//! [try_call()](crate::Function::try_call) instead returns an [EvalError](crate::EvalError) reporting
+//! which subexpression left its domain and why:
+//! ```rust
+//! # use number_diff::{EvalErrorKind, Function};
+//! let function = Function::from("1 + asin(x^2)");
+//!
+//! let error = function.try_call(2.).unwrap_err();
+//! assert_eq!(error.kind, EvalErrorKind::InverseTrigonometricRange);
+//! assert_eq!(error.argument, 4.);
+//! ```
+//!
+//! ### Domains
+//! [domain()](crate::Function::domain) derives the set of points where a function is defined from the
+//! restrictions of its logarithms, divisions, roots, inverse trigonometric functions and poles, and
+//! returns it as an [IntervalSet](crate::IntervalSet):
+//! ```rust
+//! # use number_diff::Function;
+//! let function = Function::from("sqrt(x^2 - 4)/(x - 3)");
+//!
+//! assert_eq!(function.domain().to_string(), "(-∞, -2] ∪ [2, 3) ∪ (3, ∞)");
+//! ```
+//!
+//! ### Ranges
+//! [range_on()](crate::Function::range_on) bounds the values of a function on an interval, either
+//! estimated from samples or rigorously, combining interval arithmetic with the critical points of
+//! the derivative:
+//! ```rust
+//! # use number_diff::{Function, RangeMode};
+//! let function = Function::from("x^3 - 3x");
+//!
+//! let (min, max) = function.range_on(-2., 2., RangeMode::Rigorous).unwrap();
+//! assert!(min <= -2. && max >= 2.);
+//! ```
+//!
+//! ### Piecewise Functions
+//! Functions defined by cases are written as `piecewise(condition: value, ..., otherwise)`, where
+//! the first branch whose condition holds applies. They are differentiated branch by branch and
+//! integrated piece by piece between their breakpoints, and helpers such as
+//! [heaviside()](crate::heaviside), [sign()](crate::sign), [min()](crate::min) and
+//! [max()](crate::max) build them from other functions:
+//! ```rust
+//! # use number_diff::{max, Function, Integrate};
+//! let function = Function::from("piecewise(x < 0: -x, x^2)");
+//! assert_eq!(function.elementary().call()(-2.), 2.);
+//!
+//! let relu = max(Function::default(), Function::from("0"));
+//! assert_eq!(relu.evaluate_integral(-1., 1.), 0.5);
+//! ```
+//!
 //! ### Series Expansions
 //! See [this article](https://en.wikipedia.org/wiki/Series_expansion) for an explanation of series
 //! expansions.
@@ -109,4 +336,6 @@ pub enum Error {
     InternalError(String),
     ExpansionError(String),
     InputError(String),
+    LimitError(String),
+    RootFindingError(String),
 }
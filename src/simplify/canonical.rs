@@ -0,0 +1,422 @@
+use std::{
+    cmp::Ordering,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use crate::{
+    utils::rational::Number,
+    Elementary::{self, *},
+    Function,
+};
+
+use super::rewrite::split_power;
+
+/// A function in [canonical form](crate::Elementary::canonical).
+///
+/// Unlike [Elementary](crate::Elementary), which compares floating point constants using their
+/// values and the shape of the expression tree as it was written, a Canonical compares (and
+/// hashes) the canonical form of the function structurally. Two functions that are equal up to
+/// the order of their terms and factors are therefore equal as Canonicals, which allows for them
+/// to be used as keys in maps and sets.
+///
+/// Example:
+/// ```rust
+/// # use std::collections::HashSet;
+/// # use number_diff::{Canonical, Elementary};
+/// let mut functions = HashSet::new();
+/// functions.insert(Canonical::from(Elementary::from("x + 2*x")));
+///
+/// assert!(functions.contains(&Canonical::from(Elementary::from("x*3"))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Canonical(Elementary);
+
+impl Canonical {
+    pub fn new(function: &Elementary) -> Self {
+        Self(function.canonical())
+    }
+
+    /// Returns the canonical form of the function.
+    pub fn elementary(&self) -> &Elementary {
+        &self.0
+    }
+}
+
+impl From<Elementary> for Canonical {
+    fn from(value: Elementary) -> Self {
+        Self::new(&value)
+    }
+}
+
+impl From<&Function> for Canonical {
+    fn from(value: &Function) -> Self {
+        Self::new(&value.elementary())
+    }
+}
+
+impl From<Canonical> for Elementary {
+    fn from(value: Canonical) -> Self {
+        value.0
+    }
+}
+
+impl PartialEq for Canonical {
+    fn eq(&self, other: &Self) -> bool {
+        compare(&self.0, &other.0) == Ordering::Equal
+    }
+}
+
+impl Eq for Canonical {}
+
+impl PartialOrd for Canonical {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Canonical {
+    fn cmp(&self, other: &Self) -> Ordering {
+        compare(&self.0, &other.0)
+    }
+}
+
+impl Hash for Canonical {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        hash_structure(&self.0, state);
+    }
+}
+
+impl Elementary {
+    /// Returns the canonical form of the function.
+    ///
+    /// Sums and products are flattened and their terms and factors are put in a deterministic
+    /// order, like terms are combined by adding their coefficients, equal factors are combined
+    /// into powers and constant subexpressions are evaluated. Functions that are equal up to these
+    /// rearrangements therefore have the same canonical form.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Elementary;
+    /// assert_eq!(
+    ///     Elementary::from("x + 2*x + x*3").canonical(),
+    ///     Elementary::from("6*x")
+    /// );
+    /// assert_eq!(
+    ///     Elementary::from("sin(x)*x*sin(x)").canonical(),
+    ///     Elementary::from("x*sin(x)^2").canonical()
+    /// );
+    /// ```
+    pub fn canonical(&self) -> Self {
+        let function = match self.map_children(|func| func.canonical()) {
+            function @ (Add(_, _) | Sub(_, _)) => canonical_sum(&function),
+            function @ (Mul(_, _) | Div(_, _) | Pow(_, _)) => canonical_product(&function),
+            function => function,
+        };
+
+        if function.is_constant() && function.as_number().is_none() {
+            if let Some(value) = function.exact_trig_value() {
+                return Con(value);
+            }
+            if let Some(value) = function.exact_log_value() {
+                return value.canonical();
+            }
+            if function.is_symbolic() {
+                return function;
+            }
+            let value = function.constant_value();
+            if value.is_finite() {
+                return Self::from_number(value);
+            }
+        }
+        function
+    }
+}
+
+impl Function {
+    /// Puts the function on [canonical form](crate::Elementary::canonical).
+    pub fn canonicalize(&mut self) {
+        self.set_function(self.elementary().canonical());
+    }
+}
+
+// the terms of a sum as pairs of coefficients and the rest of the term
+pub(super) fn gather_terms(
+    function: &Elementary,
+    sign: Number,
+    terms: &mut Vec<(Number, Elementary)>,
+) {
+    match function {
+        Add(func1, func2) => {
+            gather_terms(func1, sign, terms);
+            gather_terms(func2, sign, terms);
+        }
+        Sub(func1, func2) => {
+            gather_terms(func1, sign, terms);
+            gather_terms(func2, -sign, terms);
+        }
+        _ => {
+            let mut coefficient = Number::from(1.);
+            let mut powers = Vec::new();
+            gather_powers(function, false, &mut coefficient, &mut powers);
+            let term = build_product(Number::from(1.), powers);
+
+            match terms.iter_mut().find(|(_, other)| *other == term) {
+                Some((total, _)) => *total = *total + sign * coefficient,
+                None => terms.push((sign * coefficient, term)),
+            }
+        }
+    }
+}
+
+// writes a sum as t1 ± t2 ± ... ± c, where the terms are ordered by their bases (with higher
+// powers first) and the constant term comes last
+pub(super) fn canonical_sum(function: &Elementary) -> Elementary {
+    let mut terms = Vec::new();
+    gather_terms(function, Number::from(1.), &mut terms);
+
+    let constant = terms
+        .iter()
+        .filter(|(_, term)| *term == Con(1.))
+        .fold(Number::from(0.), |sum, (coefficient, _)| sum + *coefficient);
+    let mut terms: Vec<(Number, Elementary)> = terms
+        .into_iter()
+        .filter(|(coefficient, term)| !coefficient.is_zero() && *term != Con(1.))
+        .collect();
+    terms.sort_by(|(_, term1), (_, term2)| compare_terms(term1, term2));
+    if !constant.is_zero() || terms.is_empty() {
+        terms.push((constant, Con(1.)));
+    }
+
+    let with_coefficient = |coefficient: Number, term: Elementary| {
+        canonical_product(&Mul(
+            Arc::new(Elementary::from_number(coefficient)),
+            Arc::new(term),
+        ))
+    };
+
+    let mut terms = terms.into_iter();
+    let (coefficient, term) = terms.next().expect("the sum has at least one term");
+    terms.fold(
+        with_coefficient(coefficient, term),
+        |sum, (coefficient, term)| {
+            if coefficient.is_negative() {
+                Sub(
+                    Arc::new(sum),
+                    Arc::new(with_coefficient(-coefficient, term)),
+                )
+            } else {
+                Add(Arc::new(sum), Arc::new(with_coefficient(coefficient, term)))
+            }
+        },
+    )
+}
+
+// the factors of a product as pairs of bases and exponents, where the constant factors are
+// multiplied into the coefficient
+pub(super) fn gather_powers(
+    function: &Elementary,
+    inverted: bool,
+    coefficient: &mut Number,
+    powers: &mut Vec<(Elementary, Elementary)>,
+) {
+    match (function, function.as_number()) {
+        (Mul(func1, func2), _) => {
+            gather_powers(func1, inverted, coefficient, powers);
+            gather_powers(func2, inverted, coefficient, powers);
+        }
+        (Div(func1, func2), _) => {
+            gather_powers(func1, inverted, coefficient, powers);
+            gather_powers(func2, !inverted, coefficient, powers);
+        }
+        (_, Some(numb)) if !inverted => *coefficient = *coefficient * numb,
+        (_, Some(numb)) if !numb.is_zero() => *coefficient = *coefficient / numb,
+        _ => {
+            let (base, exp) = split_power(function.clone());
+            let exp = match (inverted, exp.as_number()) {
+                (false, _) => exp,
+                (true, Some(numb)) => Elementary::from_number(-numb),
+                (true, None) => canonical_product(&Mul(Arc::new(Con(-1.)), Arc::new(exp))),
+            };
+
+            match powers.iter_mut().find(|(other, _)| *other == base) {
+                Some((_, total)) => {
+                    *total = match (total.as_number(), exp.as_number()) {
+                        (Some(numb1), Some(numb2)) => Elementary::from_number(numb1 + numb2),
+                        _ => canonical_sum(&Add(Arc::new(total.clone()), Arc::new(exp))),
+                    }
+                }
+                None => powers.push((base, exp)),
+            }
+        }
+    }
+}
+
+// writes a product as c * f1^n1 * f2^n2 * ... / (g1^m1 * g2^m2 * ...), where the factors are
+// ordered by their bases and the factors with negative constant exponents make up the denominator
+pub(super) fn canonical_product(function: &Elementary) -> Elementary {
+    let mut coefficient = Number::from(1.);
+    let mut powers = Vec::new();
+    gather_powers(function, false, &mut coefficient, &mut powers);
+    build_product(coefficient, powers)
+}
+
+pub(super) fn build_product(
+    coefficient: Number,
+    mut powers: Vec<(Elementary, Elementary)>,
+) -> Elementary {
+    if coefficient.is_zero() {
+        return Con(0.);
+    }
+    powers.retain(|(_, exp)| *exp != Con(0.));
+    powers.sort_by(|(base1, exp1), (base2, exp2)| {
+        compare(base1, base2).then_with(|| compare(exp1, exp2))
+    });
+
+    let power = |base: Elementary, exp: Elementary| {
+        if exp == Con(1.) {
+            base
+        } else {
+            Pow(Arc::new(base), Arc::new(exp))
+        }
+    };
+    let multiply = |product: Option<Elementary>, factor: Elementary| match product {
+        Some(product) => Some(Mul(Arc::new(product), Arc::new(factor))),
+        None => Some(factor),
+    };
+
+    let mut numerator = (!coefficient.is_one()).then_some(Elementary::from_number(coefficient));
+    let mut denominator = None;
+    for (base, exp) in powers {
+        match exp.as_number() {
+            Some(numb) if numb.is_negative() => {
+                denominator = multiply(denominator, power(base, Elementary::from_number(-numb)))
+            }
+            _ => numerator = multiply(numerator, power(base, exp)),
+        }
+    }
+
+    let numerator = numerator.unwrap_or(Con(1.));
+    match denominator {
+        Some(denominator) => Div(Arc::new(numerator), Arc::new(denominator)),
+        None => numerator,
+    }
+}
+
+// orders the terms of a sum by their bases, where higher powers of the same base come first (so
+// that polynomials are written with their leading term first)
+fn compare_terms(term1: &Elementary, term2: &Elementary) -> Ordering {
+    let (base1, exp1) = split_power(term1.clone());
+    let (base2, exp2) = split_power(term2.clone());
+    compare(&base1, &base2).then_with(|| compare(&exp2, &exp1))
+}
+
+// the position of the kind of function in the canonical order
+fn rank(function: &Elementary) -> u8 {
+    match function {
+        // exact and floating point constants are ordered by their values
+        Con(_) | Rat(_) => 0,
+        // followed by the symbolic constants, so that for instance 2πx is written in that order
+        Sym(_) => 1,
+        X => 2,
+        Wildcard(_) => 3,
+        Pow(_, _) => 4,
+        Mul(_, _) => 5,
+        Div(_, _) => 6,
+        Add(_, _) => 7,
+        Sub(_, _) => 8,
+        Log(_, _) => 9,
+        Sin(_) => 10,
+        Cos(_) => 11,
+        Tan(_) => 12,
+        Sec(_) => 13,
+        Csc(_) => 14,
+        Cot(_) => 15,
+        Asin(_) => 16,
+        Acos(_) => 17,
+        Atan(_) => 18,
+        Sinh(_) => 19,
+        Cosh(_) => 20,
+        Tanh(_) => 21,
+        Factorial(_) => 22,
+        Gamma(_) => 23,
+        Polygamma(_, _) => 24,
+        Abs(_) => 25,
+        Piecewise(_, _) => 26,
+        Acot(_) => 27,
+        Asec(_) => 28,
+        Acsc(_) => 29,
+        Sech(_) => 30,
+        Csch(_) => 31,
+        Coth(_) => 32,
+        Asinh(_) => 33,
+        Acosh(_) => 34,
+        Atanh(_) => 35,
+        Cbrt(_) => 36,
+        Floor(_) => 37,
+        Ceil(_) => 38,
+    }
+}
+
+// -0 and 0 as well as all NaNs are considered equal
+fn normalize(numb: f64) -> f64 {
+    if numb == 0. {
+        0.
+    } else if numb.is_nan() {
+        f64::NAN
+    } else {
+        numb
+    }
+}
+
+fn value(constant: &Elementary) -> f64 {
+    constant.as_number().map_or(f64::NAN, Number::to_f64)
+}
+
+// a total order on the structure of functions
+fn compare(function1: &Elementary, function2: &Elementary) -> Ordering {
+    rank(function1)
+        .cmp(&rank(function2))
+        .then_with(|| match (function1, function2) {
+            (Con(_) | Rat(_), Con(_) | Rat(_)) => {
+                normalize(value(function1)).total_cmp(&normalize(value(function2)))
+            }
+            (Sym(constant1), Sym(constant2)) => (*constant1 as u8).cmp(&(*constant2 as u8)),
+            (Wildcard(name1), Wildcard(name2)) => name1.cmp(name2),
+            (Polygamma(_, order1), Polygamma(_, order2)) => order1.cmp(order2),
+            (Piecewise(branches1, _), Piecewise(branches2, _)) => branches1
+                .iter()
+                .map(|(condition, _)| condition.relation)
+                .cmp(branches2.iter().map(|(condition, _)| condition.relation)),
+            _ => Ordering::Equal,
+        })
+        .then_with(|| {
+            let children1 = function1.children();
+            let children2 = function2.children();
+            children1
+                .iter()
+                .zip(&children2)
+                .map(|(child1, child2)| compare(child1, child2))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or(children1.len().cmp(&children2.len()))
+        })
+}
+
+fn hash_structure<H: Hasher>(function: &Elementary, state: &mut H) {
+    rank(function).hash(state);
+    match function {
+        Con(_) | Rat(_) => normalize(value(function)).to_bits().hash(state),
+        Sym(constant) => constant.hash(state),
+        Wildcard(name) => name.hash(state),
+        Polygamma(_, order) => order.hash(state),
+        Piecewise(branches, _) => {
+            for (condition, _) in branches {
+                condition.relation.hash(state);
+            }
+        }
+        _ => {}
+    }
+    for child in function.children() {
+        hash_structure(child, state);
+    }
+}
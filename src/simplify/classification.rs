@@ -43,10 +43,22 @@ impl Elementary {
             Acos(func) => func.is_constant(),
             Atan(func) => func.is_constant(),
 
+            Acot(func) => func.is_constant(),
+            Asec(func) => func.is_constant(),
+            Acsc(func) => func.is_constant(),
+
             Sinh(func) => func.is_constant(),
             Cosh(func) => func.is_constant(),
             Tanh(func) => func.is_constant(),
 
+            Sech(func) => func.is_constant(),
+            Csch(func) => func.is_constant(),
+            Coth(func) => func.is_constant(),
+
+            Asinh(func) => func.is_constant(),
+            Acosh(func) => func.is_constant(),
+            Atanh(func) => func.is_constant(),
+
             // for the operations, both of their functions must be constant for the whole function
             // to be considered constant
             Add(func1, func2) => func1.is_constant() && func2.is_constant(),
@@ -63,21 +75,25 @@ impl Elementary {
 
             Abs(func) => func.is_constant(),
 
+            Cbrt(func) => func.is_constant(),
+
+            Floor(func) => func.is_constant(),
+            Ceil(func) => func.is_constant(),
+
+            Piecewise(_, _) => self.children().iter().all(|func| func.is_constant()),
+
             Con(_) => true,
+            Rat(_) => true,
+            Sym(_) => true,
             X => false,
+            Wildcard(_) => false,
         }
     }
 
-    // returns true if the function is of type f(x) = Cx
-    fn is_linear(&self) -> bool {
-        if let Mul(func1, func2) = self {
-            if (func1.is_constant() && (func2.is_linear() || func2.clone() == X.into()))
-                || (func2.is_constant() && (func1.is_linear() || func1.clone() == X.into()))
-            {
-                return true;
-            }
-        }
-        false
+    // returns true if the function contains a symbolic constant such as π, in which case constant
+    // folding would lose its exact value
+    pub(crate) fn is_symbolic(&self) -> bool {
+        matches!(self, Sym(_)) || self.children().iter().any(|func| func.is_symbolic())
     }
 
     // returns true if the function is a constant digit f(x) = C, C ∈ ℤ
@@ -90,22 +106,27 @@ impl Elementary {
         Ok(false)
     }
 
-    // returns true if the function is of type f(x) = a^(cx)
+    // returns true if the function is built from exponentials a^f(x) and logarithms log_a(f(x)),
+    // where the arguments f(x) are polynomials or exponential themselves, using arithmetic with
+    // other exponentials and constants
     pub fn is_exponential(&self) -> Result<bool, Error> {
-        if let Pow(base, exp) = self {
-            if base.is_constant() && exp.is_linear() {
-                return Ok(true);
+        let is_argument =
+            |func: &Elementary| Ok::<_, Error>(func.is_polynomial()? || func.is_exponential()?);
+        match self {
+            Pow(base, exp) => {
+                if base.is_constant() && !exp.is_constant() {
+                    return is_argument(exp);
+                }
+                Ok(base.is_exponential()? && exp.is_constant()) // exponential^constant
             }
-        }
-        if let Mul(func1, func2) = self {
-            if (func1.is_exponential()? && func2.is_constant())
-                || (func1.is_constant() && func2.is_exponential()?)
-            {
-                return Ok(true);
+            Log(base, arg) => Ok(base.is_constant() && !arg.is_constant() && is_argument(arg)?),
+            Add(func1, func2) | Sub(func1, func2) | Mul(func1, func2) | Div(func1, func2) => {
+                Ok((func1.is_exponential()? && func2.is_exponential()?)
+                    || (func1.is_exponential()? && func2.is_constant())
+                    || (func1.is_constant() && func2.is_exponential()?))
             }
+            _ => Ok(false),
         }
-
-        Ok(false)
     }
 
     // returns true if the function is of type f(x) = ax^n + bx^(n-1) + cx^(n-2) + ...
@@ -117,7 +138,7 @@ impl Elementary {
             }
         } else if self.clone() == X.into() {
             return Ok(true);
-        } else if let Con(_) = self {
+        } else if let Con(_) | Rat(_) = self {
             return Ok(true);
         } else if let Mul(func1, func2) = self {
             if func1.is_polynomial()? && func2.is_polynomial()? {
@@ -159,8 +180,11 @@ impl Elementary {
             Cosh(_) => true,
             Tanh(_) => true,
 
-            Add(func1, func2) => func1.is_trig() && func2.is_trig(),
-            Sub(func1, func2) => func1.is_trig() && func2.is_trig(),
+            Add(func1, func2) | Sub(func1, func2) => {
+                (func1.is_trig() && func2.is_trig()) // trig ± trig
+                    || (func1.is_trig() && func2.is_constant()) // trig ± constant
+                    || (func2.is_trig() && func1.is_constant()) // constant ± trig
+            }
             Mul(func1, func2) => {
                 (func1.is_trig() && func2.is_trig()) // trig * trig
                     || (func1.is_trig() && func2.is_constant()) // trig * constant
@@ -179,9 +203,19 @@ impl Elementary {
             Gamma(_) => false,
             Polygamma(_, _) => false,
 
+            // the trigonometric identities don't cover the reciprocal arcus and hyperbolic functions
+            Acot(_) | Asec(_) | Acsc(_) => false,
+            Sech(_) | Csch(_) | Coth(_) | Asinh(_) | Acosh(_) | Atanh(_) => false,
+
             Abs(_) => false,
+            Cbrt(_) => false,
+            Floor(_) | Ceil(_) => false,
+            Piecewise(_, _) => false,
             Con(_) => false,
+            Rat(_) => false,
+            Sym(_) => false,
             X => false,
+            Wildcard(_) => false,
         }
     }
 }
@@ -0,0 +1,199 @@
+use std::sync::Arc;
+
+use crate::{
+    Elementary::{self, *},
+    Error, Polynomial,
+};
+
+use super::rewrite::simplify_with;
+
+// The simplification rewrites the expression using the following rules (see rewrite.rs):
+//
+// 1. inverses: b^(log_b(f)) = f, log_b(b^f) = f, log_b(b) = 1
+// 2. logarithms of powers: log_b(f^g) = g * log_b(f) (using |f| for even powers)
+// 3. logarithms of products and quotients: log_b(fg) = log_b(f) + log_b(g) and
+//    log_b(f/g) = log_b(f) - log_b(g), provided that one of the factors is known to be positive
+// 4. change of base: log_b(f) / log_b(g) = log_g(f)
+// 5. exponentials: (a^f)^g = a^(fg), a^f / a^g = a^(f - g) (and a^f * a^g = a^(f + g), which is
+//    handled when collecting products)
+//
+// All rules are applied in the direction that keeps the result defined wherever the original
+// expression is defined.
+
+pub fn simplify_exponential(function: &Elementary) -> Result<Elementary, Error> {
+    Ok(simplify_with(
+        function,
+        &[
+            inverse,
+            logarithm_of_power,
+            logarithm_of_product,
+            change_of_base,
+            combine_exponentials,
+            tidy_exponent,
+        ],
+    ))
+}
+
+// returns true if the function is known to be positive for all values of x
+fn is_positive(function: &Elementary) -> bool {
+    match function {
+        Con(_) | Rat(_) => is_positive_constant(function),
+        Pow(base, _) => is_positive_constant(base),
+        Mul(func1, func2) | Div(func1, func2) | Add(func1, func2) => {
+            is_positive(func1) && is_positive(func2)
+        }
+        Cosh(_) => true,
+        _ => false,
+    }
+}
+
+// all symbolic constants (such as π and e) are positive
+fn is_positive_constant(function: &Elementary) -> bool {
+    matches!(function, Sym(_))
+        || matches!(function.as_number(), Some(numb) if !numb.is_negative() && !numb.is_zero())
+}
+
+impl Elementary {
+    // returns the exact value of a logarithm of a power of its base, so that for instance ln(e)
+    // becomes 1 without relying on floating point evaluation
+    pub(crate) fn exact_log_value(&self) -> Option<Self> {
+        let Log(base, arg) = self else {
+            return None;
+        };
+        if !is_positive_constant(base) || **base == Con(1.) {
+            return None;
+        }
+        match &**arg {
+            arg if arg == &**base => Some(Con(1.)),
+            Pow(inner_base, exp) if inner_base == base => Some((**exp).clone()),
+            _ => None,
+        }
+    }
+}
+
+// b^(log_b(f)) = f and log_b(b^f) = f
+fn inverse(function: &Elementary) -> Option<Elementary> {
+    if let Some(value) = function.exact_log_value() {
+        return Some(value);
+    }
+    match function {
+        Pow(base1, exp) => match &**exp {
+            Log(base2, func) if base1 == base2 => Some((**func).clone()),
+            _ => None,
+        },
+        Log(base1, arg) => match &**arg {
+            Pow(base2, func) if base1 == base2 && is_positive_constant(base1) => {
+                Some((**func).clone())
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn logarithm_of_power(function: &Elementary) -> Option<Elementary> {
+    let Log(base, arg) = function else {
+        return None;
+    };
+    let Pow(func, exp) = &**arg else {
+        return None;
+    };
+
+    let func = if is_positive(func) {
+        func.clone()
+    } else if let Some(numb) = exp.as_number().map(|numb| numb.to_f64()) {
+        // f^n is defined for negative f when n is an integer, where log(f^n) = n * log|f| for
+        // even n (the logarithm of f is only defined where f^n is positive for odd n)
+        if numb % 2. == 0. {
+            Arc::new(Abs(func.clone()))
+        } else {
+            func.clone()
+        }
+    } else {
+        return None;
+    };
+
+    Some((**exp).clone() * Log(base.clone(), func))
+}
+
+fn logarithm_of_product(function: &Elementary) -> Option<Elementary> {
+    let Log(base, arg) = function else {
+        return None;
+    };
+    let (Mul(func1, func2) | Div(func1, func2)) = &**arg else {
+        return None;
+    };
+    // if one of the factors is positive, then the other is positive wherever the logarithm is
+    // defined
+    if !is_positive(func1) && !is_positive(func2) {
+        return None;
+    }
+
+    let log1 = Log(base.clone(), func1.clone());
+    let log2 = Log(base.clone(), func2.clone());
+    Some(match &**arg {
+        Mul(_, _) => log1 + log2,
+        _ => log1 - log2,
+    })
+}
+
+// log_b(f) / log_b(g) = log_g(f)
+fn change_of_base(function: &Elementary) -> Option<Elementary> {
+    let Div(numerator, denominator) = function else {
+        return None;
+    };
+    match (&**numerator, &**denominator) {
+        (Log(base1, func1), Log(base2, func2)) if base1 == base2 => {
+            Some(Log(func2.clone(), func1.clone()))
+        }
+        _ => None,
+    }
+}
+
+fn combine_exponentials(function: &Elementary) -> Option<Elementary> {
+    match function {
+        // (a^f)^g = a^(fg)
+        Pow(inner, exp2) => match &**inner {
+            Pow(base, exp1) if is_positive_constant(base) => Some(Pow(
+                base.clone(),
+                Arc::new((**exp2).clone() * (**exp1).clone()),
+            )),
+            _ => None,
+        },
+        // a^f / a^g = a^(f - g)
+        Div(numerator, denominator) => match (&**numerator, &**denominator) {
+            (Pow(base1, exp1), Pow(base2, exp2))
+                if base1 == base2 && is_positive_constant(base1) =>
+            {
+                Some(Pow(
+                    base1.clone(),
+                    Arc::new((**exp1).clone() - (**exp2).clone()),
+                ))
+            }
+            // f / a^g = f * a^(-g)
+            (numerator, Pow(base, exp)) if is_positive_constant(base) => {
+                Some(numerator.clone() * Pow(base.clone(), Arc::new(Con(-1.) * (**exp).clone())))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// exponents that are polynomials are put on the form ax^n + bx^(n - 1) + ... so that exponents
+// that have been combined are as short as possible
+fn tidy_exponent(function: &Elementary) -> Option<Elementary> {
+    let Pow(base, exp) = function else {
+        return None;
+    };
+    if !is_positive_constant(base) || exp.is_constant() {
+        return None;
+    }
+
+    let tidy = simplify_with(&Elementary::from(Polynomial::try_from(&**exp).ok()?), &[]);
+    if tidy == **exp {
+        None
+    } else {
+        Some(Pow(base.clone(), Arc::new(tidy)))
+    }
+}
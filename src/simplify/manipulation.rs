@@ -0,0 +1,449 @@
+use std::sync::Arc;
+
+use crate::{
+    utils::rational::Number,
+    Elementary::{self, *},
+    Error, Function, Polynomial,
+};
+
+use super::canonical::{
+    build_product, canonical_product, canonical_sum, gather_powers, gather_terms,
+};
+
+// powers of sums with larger exponents than this are not expanded, since the number of terms grows
+// rapidly with the exponent
+const MAX_EXPONENT: f64 = 64.;
+// roots of polynomials that are this close to an integer are considered to be that integer when
+// factoring
+const ROOT_TOLERANCE: f64 = 1e-9;
+
+impl Elementary {
+    /// Expands the function by multiplying out products of sums and integer powers of sums.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Elementary;
+    /// // (x + 1)² = x² + 2x + 1
+    /// let expanded = Elementary::from("(x + 1)^2").expand().unwrap();
+    /// assert_eq!(expanded, Elementary::from("x^2 + 2*x + 1").canonical());
+    /// ```
+    pub fn expand(&self) -> Result<Self, Error> {
+        self.check_simplification(&expand(&self.canonical()))
+    }
+
+    /// Factors the function. Common factors are pulled out of sums and polynomials are split into
+    /// linear and irreducible quadratic factors using their [roots](crate::Polynomial::complex_roots).
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Elementary;
+    /// // x³ - x = x(x - 1)(x + 1)
+    /// let factored = Elementary::from("x^3 - x").factorize().unwrap();
+    /// assert_eq!(factored, Elementary::from("x*(x - 1)*(x + 1)").canonical());
+    /// ```
+    pub fn factorize(&self) -> Result<Self, Error> {
+        self.check_simplification(&factor(&self.canonical()))
+    }
+
+    /// Expands the function and groups its terms by the powers of the provided variable, which
+    /// may be any subexpression (such as x or sin(x)). The groups are ordered by descending
+    /// powers.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Elementary;
+    /// // x sin(x) + 2x + x² = x² + x(sin(x) + 2)
+    /// let collected = Elementary::from("x*sin(x) + 2*x + x^2")
+    ///     .collect(&Elementary::X)
+    ///     .unwrap();
+    /// assert_eq!(collected, Elementary::from("x^2 + x*(sin(x) + 2)").canonical());
+    /// ```
+    pub fn collect(&self, variable: &Elementary) -> Result<Self, Error> {
+        self.check_simplification(&collect(&self.canonical(), &variable.canonical()))
+    }
+
+    /// Combines the terms of sums into a single fraction over a common denominator.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::Elementary;
+    /// // 1/x + 1/(x + 1) = (2x + 1)/(x(x + 1))
+    /// let combined = Elementary::from("1/x + 1/(x + 1)").together().unwrap();
+    /// assert_eq!(combined, Elementary::from("(2*x + 1)/(x*(x + 1))").canonical());
+    /// ```
+    pub fn together(&self) -> Result<Self, Error> {
+        self.check_simplification(&together(&self.canonical()))
+    }
+}
+
+impl Function {
+    /// [Expands](crate::Elementary::expand) the function.
+    pub fn expand(&mut self) -> Result<(), Error> {
+        self.set_function(self.elementary().expand()?);
+        Ok(())
+    }
+
+    /// [Factors](crate::Elementary::factorize) the function.
+    pub fn factor(&mut self) -> Result<(), Error> {
+        self.set_function(self.elementary().factorize()?);
+        Ok(())
+    }
+
+    /// Groups the terms of the function by the powers of the variable. See
+    /// [Elementary::collect()](crate::Elementary::collect).
+    pub fn collect(&mut self, variable: &Elementary) -> Result<(), Error> {
+        self.set_function(self.elementary().collect(variable)?);
+        Ok(())
+    }
+
+    /// Combines the function into a single fraction. See
+    /// [Elementary::together()](crate::Elementary::together).
+    pub fn together(&mut self) -> Result<(), Error> {
+        self.set_function(self.elementary().together()?);
+        Ok(())
+    }
+}
+
+// builds the canonical sum of the terms
+fn sum_of(terms: Vec<(Number, Elementary)>) -> Elementary {
+    let sum = terms.into_iter().fold(Con(0.), |sum, (coefficient, term)| {
+        Add(
+            Arc::new(sum),
+            Arc::new(Mul(
+                Arc::new(Elementary::from_number(coefficient)),
+                Arc::new(term),
+            )),
+        )
+    });
+    canonical_sum(&sum)
+}
+
+fn one() -> Number {
+    Number::from(1.)
+}
+
+fn expand(function: &Elementary) -> Elementary {
+    sum_of(expand_terms(function))
+}
+
+// the terms of the expanded function as pairs of coefficients and the rest of the terms
+fn expand_terms(function: &Elementary) -> Vec<(Number, Elementary)> {
+    match function {
+        Add(func1, func2) | Sub(func1, func2) => {
+            let sign = if let Sub(_, _) = function {
+                -one()
+            } else {
+                one()
+            };
+            let mut terms = expand_terms(func1);
+            for (coefficient, term) in expand_terms(func2) {
+                gather_terms(&term, sign * coefficient, &mut terms);
+            }
+            terms
+        }
+        Mul(func1, func2) => multiply_terms(&expand_terms(func1), &expand_terms(func2)),
+        Div(func1, func2) => {
+            let denominator = Arc::new(expand(func2));
+            let mut terms = Vec::new();
+            for (coefficient, term) in expand_terms(func1) {
+                let quotient = canonical_product(&Div(Arc::new(term), denominator.clone()));
+                gather_terms(&quotient, coefficient, &mut terms);
+            }
+            terms
+        }
+        Pow(base, exp) => match **exp {
+            Con(numb) if numb > 1. && numb <= MAX_EXPONENT && numb.fract() == 0. => {
+                let base = expand_terms(base);
+                (0..numb as usize).fold(vec![(one(), Con(1.))], |product, _| {
+                    multiply_terms(&product, &base)
+                })
+            }
+            _ => expand_atom(function),
+        },
+        _ => expand_atom(function),
+    }
+}
+
+// functions that aren't sums or products are expanded on the inside
+fn expand_atom(function: &Elementary) -> Vec<(Number, Elementary)> {
+    let mut terms = Vec::new();
+    gather_terms(
+        &function.map_children(expand).canonical(),
+        one(),
+        &mut terms,
+    );
+    terms
+}
+
+fn multiply_terms(
+    terms1: &[(Number, Elementary)],
+    terms2: &[(Number, Elementary)],
+) -> Vec<(Number, Elementary)> {
+    let mut product = Vec::new();
+    for (coefficient1, term1) in terms1 {
+        for (coefficient2, term2) in terms2 {
+            let term = canonical_product(&Mul(Arc::new(term1.clone()), Arc::new(term2.clone())));
+            gather_terms(&term, *coefficient1 * *coefficient2, &mut product);
+        }
+    }
+    product
+}
+
+fn factor(function: &Elementary) -> Elementary {
+    match function.map_children(factor) {
+        function @ (Add(_, _) | Sub(_, _)) => factor_sum(&function),
+        // factoring the numerator and the denominator may reveal common factors
+        function @ (Mul(_, _) | Div(_, _)) => canonical_product(&function),
+        function => function,
+    }
+}
+
+// the factors of a term (without its coefficient) as pairs of bases and exponents
+fn powers_of(term: &Elementary) -> Vec<(Elementary, Elementary)> {
+    let mut powers = Vec::new();
+    gather_powers(term, false, &mut one(), &mut powers);
+    powers
+}
+
+fn factor_sum(function: &Elementary) -> Elementary {
+    if let Some(factored) = factor_polynomial(function) {
+        return factored;
+    }
+
+    let mut terms = Vec::new();
+    gather_terms(function, one(), &mut terms);
+    let terms: Vec<(Number, Vec<(Elementary, Elementary)>)> = terms
+        .into_iter()
+        .map(|(coefficient, term)| (coefficient, powers_of(&term)))
+        .collect();
+
+    // the factors that all terms have in common, raised to their lowest (constant) power
+    let mut common: Vec<(Elementary, Number)> = Vec::new();
+    for (base, exp) in &terms[0].1 {
+        let Some(mut lowest) = exp.as_number() else {
+            continue;
+        };
+        let in_all = terms.iter().skip(1).all(|(_, powers)| {
+            powers.iter().any(|(other, exp)| match exp.as_number() {
+                Some(numb) if other == base => {
+                    if numb.to_f64() < lowest.to_f64() {
+                        lowest = numb;
+                    }
+                    true
+                }
+                _ => false,
+            })
+        });
+        if in_all {
+            common.push((base.clone(), lowest));
+        }
+    }
+
+    let coefficient = terms
+        .iter()
+        .map(|(coefficient, _)| *coefficient)
+        .reduce(gcd)
+        .unwrap_or(one());
+
+    if common.is_empty() && coefficient.abs().is_one() {
+        return function.clone();
+    }
+
+    let common_factor = build_product(
+        coefficient,
+        common
+            .iter()
+            .map(|(base, exp)| (base.clone(), Elementary::from_number(*exp)))
+            .collect(),
+    );
+    let rest = sum_of(
+        terms
+            .into_iter()
+            .map(|(term_coefficient, mut powers)| {
+                for (base, exp) in &common {
+                    powers.push((base.clone(), Elementary::from_number(-*exp)));
+                }
+                let term = canonical_product(&build_product(one(), powers));
+                (term_coefficient / coefficient, term)
+            })
+            .collect(),
+    );
+
+    let rest = factor_polynomial(&rest).unwrap_or(rest);
+    canonical_product(&Mul(Arc::new(common_factor), Arc::new(rest)))
+}
+
+// the greatest common divisor of the coefficients if they are exact (and 1 otherwise). The sign
+// of the first coefficient is kept, so that the leading term of the remaining sum is positive
+fn gcd(numb1: Number, numb2: Number) -> Number {
+    match (numb1, numb2) {
+        (Number::Exact(rational1), Number::Exact(rational2)) => rational1
+            .checked_gcd(rational2)
+            .map_or(one(), |gcd| match numb1.is_negative() {
+                true => -Number::Exact(gcd),
+                false => Number::Exact(gcd),
+            }),
+        _ => one(),
+    }
+}
+
+// splits the polynomial into its leading coefficient and linear and irreducible quadratic factors
+fn factor_polynomial(function: &Elementary) -> Option<Elementary> {
+    let polynomial = Polynomial::try_from(function).ok()?;
+    if polynomial.degree() < 1 {
+        return None;
+    }
+
+    let mut factors: Vec<(Elementary, Elementary)> = Vec::new();
+    for root in polynomial.complex_roots().ok()? {
+        let multiplicity = Con(root.multiplicity as f64);
+        if root.value.is_real() {
+            let factor = Polynomial::new(vec![-clean_root(root.value.re), 1.]);
+            factors.push((Elementary::from(factor).canonical(), multiplicity));
+        } else if root.value.im > 0. {
+            // the conjugate root is covered by the same quadratic factor
+            let factor = Polynomial::new(vec![
+                clean_root(root.value.abs().powi(2)),
+                clean_root(-2. * root.value.re),
+                1.,
+            ]);
+            factors.push((Elementary::from(factor).canonical(), multiplicity));
+        }
+    }
+
+    Some(build_product(
+        Number::from(polynomial.leading_coefficient()),
+        factors,
+    ))
+}
+
+fn clean_root(root: f64) -> f64 {
+    if (root - root.round()).abs() < ROOT_TOLERANCE * root.abs().max(1.) {
+        root.round()
+    } else {
+        root
+    }
+}
+
+fn collect(function: &Elementary, variable: &Elementary) -> Elementary {
+    let mut terms = Vec::new();
+    gather_terms(&expand(function), one(), &mut terms);
+
+    // the terms grouped by the power of the variable
+    let mut groups: Vec<(Number, Vec<(Number, Elementary)>)> = Vec::new();
+    for (coefficient, term) in terms {
+        let mut powers = powers_of(&term);
+        let degree = match powers
+            .iter()
+            .position(|(base, exp)| base == variable && exp.as_number().is_some())
+        {
+            Some(index) => powers.remove(index).1.as_number().unwrap_or(one()),
+            None => Number::from(0.),
+        };
+        let rest = build_product(one(), powers);
+
+        match groups.iter_mut().find(|(other, _)| *other == degree) {
+            Some((_, group)) => group.push((coefficient, rest)),
+            None => groups.push((degree, vec![(coefficient, rest)])),
+        }
+    }
+    groups.sort_by(|(degree1, _), (degree2, _)| degree2.to_f64().total_cmp(&degree1.to_f64()));
+
+    let group_term = |coefficient: Elementary, degree: Number| {
+        let power = build_product(
+            one(),
+            vec![(variable.clone(), Elementary::from_number(degree))],
+        );
+        canonical_product(&Mul(Arc::new(coefficient), Arc::new(power)))
+    };
+
+    groups
+        .into_iter()
+        .map(|(degree, group)| (sum_of(group), degree))
+        .fold(None, |sum, (coefficient, degree)| {
+            match (sum, coefficient.as_number()) {
+                (None, _) => Some(group_term(coefficient, degree)),
+                (Some(sum), Some(numb)) if numb.is_negative() => Some(Sub(
+                    Arc::new(sum),
+                    Arc::new(group_term(Elementary::from_number(-numb), degree)),
+                )),
+                (Some(sum), _) => Some(Add(
+                    Arc::new(sum),
+                    Arc::new(group_term(coefficient, degree)),
+                )),
+            }
+        })
+        .unwrap_or(Con(0.))
+}
+
+fn together(function: &Elementary) -> Elementary {
+    let function = match function.map_children(together) {
+        function @ (Mul(_, _) | Div(_, _)) => return canonical_product(&function),
+        function @ (Add(_, _) | Sub(_, _)) => function,
+        function => return function,
+    };
+
+    let mut terms = Vec::new();
+    gather_terms(&function, one(), &mut terms);
+
+    // the numerators and denominators of the terms, where the denominators consist of the factors
+    // with negative constant exponents
+    let mut fractions = Vec::new();
+    let mut common: Vec<(Elementary, Number)> = Vec::new();
+    for (coefficient, term) in terms {
+        let mut numerator = Vec::new();
+        let mut denominator: Vec<(Elementary, Number)> = Vec::new();
+        for (base, exp) in powers_of(&term) {
+            match exp.as_number() {
+                Some(numb) if numb.is_negative() => denominator.push((base, -numb)),
+                _ => numerator.push((base, exp)),
+            }
+        }
+
+        for (base, exp) in &denominator {
+            match common.iter_mut().find(|(other, _)| other == base) {
+                Some((_, highest)) if exp.to_f64() > highest.to_f64() => *highest = *exp,
+                Some(_) => {}
+                None => common.push((base.clone(), *exp)),
+            }
+        }
+        fractions.push((coefficient, numerator, denominator));
+    }
+
+    if common.is_empty() {
+        return function;
+    }
+
+    // each numerator is multiplied by the factors of the common denominator that its own
+    // denominator lacks, after which the numerators are added and expanded
+    let numerator = expand(&sum_of(
+        fractions
+            .into_iter()
+            .map(|(coefficient, mut numerator, denominator)| {
+                for (base, exp) in &common {
+                    let own = denominator
+                        .iter()
+                        .find(|(other, _)| other == base)
+                        .map_or(Number::from(0.), |(_, own)| *own);
+                    let missing = *exp - own;
+                    if !missing.is_zero() {
+                        numerator.push((base.clone(), Elementary::from_number(missing)));
+                    }
+                }
+                (
+                    coefficient,
+                    canonical_product(&build_product(one(), numerator)),
+                )
+            })
+            .collect(),
+    ));
+    let denominator = build_product(
+        one(),
+        common
+            .into_iter()
+            .map(|(base, exp)| (base, Elementary::from_number(exp)))
+            .collect(),
+    );
+
+    Div(Arc::new(numerator), Arc::new(denominator))
+}
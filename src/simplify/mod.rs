@@ -1,5 +1,10 @@
+pub mod canonical;
 mod classification;
 mod exponential;
+mod manipulation;
 mod operations;
 pub mod polynomial;
+pub mod rational;
+mod rewrite;
+pub mod rules;
 mod trigonometric;
@@ -1,20 +1,33 @@
 use std::sync::Arc;
 
 use crate::{
+    utils::rational::Number,
+    Condition,
     Elementary::{self, *},
-    Error,
+    Error, Polynomial, RationalFunction,
 };
 
 use super::classification::Category;
-use super::polynomial;
+use super::{exponential, polynomial, trigonometric};
 
 impl Elementary {
     pub fn simplify(&self) -> Result<Self, Error> {
         let new_function: Self = match self.classify()? {
             Category::Constant => self.simplify_constant()?,
             Category::Polynomial => polynomial::simplify_polynomial(self.clone())?,
-            Category::ClusterFuck => self.simplify_operations()?,
-            _ => self.clone(),
+            Category::Exponential => exponential::simplify_exponential(self)?,
+            Category::Trigonometric => trigonometric::simplify_trigonometric(self)?,
+            Category::ClusterFuck => {
+                let simplified = self.simplify_operations()?;
+                // simplifying the parts may reveal an expression of another category (as is
+                // common for derivatives, where the chain rule leaves factors such as 0x + 1)
+                match simplified.classify()? {
+                    Category::Exponential => exponential::simplify_exponential(&simplified)?,
+                    Category::Polynomial => polynomial::simplify_polynomial(simplified)?,
+                    Category::Trigonometric => trigonometric::simplify_trigonometric(&simplified)?,
+                    _ => simplified,
+                }
+            }
         };
 
         self.check_simplification(&new_function)
@@ -22,7 +35,7 @@ impl Elementary {
 
     // makes sure that the simplified funciton is correct, that is, it will yield the same result
     // upon calling for all numbers within its definition set.
-    fn check_simplification(&self, new_function: &Self) -> Result<Self, Error> {
+    pub(super) fn check_simplification(&self, new_function: &Self) -> Result<Self, Error> {
         for i in -1000..1000 {
             if !self.is_within_margin(new_function, i as f64) {
                 return Err(Error::InternalError(String::from(
@@ -37,11 +50,17 @@ impl Elementary {
         let callable_new = other.clone().call();
 
         // if the original function returns Nan, then the simplification may have gotten rid of the
-        // issue
+        // issue. The same goes for infinite values caused by overflow (such as e^1000/e^999), which
+        // unlike poles are infinite on either side of the point as well. Poles must be kept, but
+        // their sign depends on the sign of the zero in the denominator (1/(1 + 1/x) and x/(x + 1)
+        // are +∞ and -∞ at -1)
         if callable_self(point).is_nan() {
             return true;
-        } else if callable_self(point).is_infinite() && callable_new(point).is_infinite() {
-            return true;
+        } else if callable_self(point).is_infinite() {
+            let step = 1e-7 * point.abs().max(1.);
+            let overflow = callable_self(point - step).is_infinite()
+                && callable_self(point + step).is_infinite();
+            return overflow || callable_new(point).is_infinite();
         } else {
             // if not, the value must be within 1% of the original value + 1e-5
             let margin = (callable_self(point) * 0.01).abs() + 1e-5;
@@ -64,11 +83,38 @@ impl Elementary {
                 Arc::new(func1.simplify()?),
                 Arc::new(func2.simplify()?),
             )),
+            // the sides of the conditions and the values of the branches are simplified separately
+            Piecewise(branches, otherwise) => Ok(Piecewise(
+                branches
+                    .iter()
+                    .map(|(condition, func)| {
+                        let condition = Condition::new(
+                            condition.lhs.simplify()?,
+                            condition.relation,
+                            condition.rhs.simplify()?,
+                        );
+                        Ok((condition, Arc::new(func.simplify()?)))
+                    })
+                    .collect::<Result<_, Error>>()?,
+                Arc::new(otherwise.simplify()?),
+            )),
+            // the functions that no category covers only have their arguments simplified, along
+            // with the compositions that cancel
+            Acot(func) | Asec(func) | Acsc(func) | Sech(func) | Csch(func) | Coth(func)
+            | Asinh(func) | Acosh(func) | Atanh(func) | Cbrt(func) | Floor(func) | Ceil(func) => {
+                let func = func.simplify()?;
+                let simplified = self.map_children(|_| func.clone());
+                Ok(cancel_composition(&simplified).unwrap_or(simplified))
+            }
             _ => Ok(self.to_owned()),
         }
     }
 
     pub fn simplify_power(base: &Self, exp: &Self) -> Result<Self, Error> {
+        // constant exponents such as 2 - 1 (left by the power rule) are evaluated first
+        if exp.is_constant() && exp.as_number().is_none() {
+            return Self::simplify_power(base, &exp.simplify_constant()?);
+        }
         match exp.clone() {
             Con(numb) => {
                 if numb == 0. {
@@ -99,30 +145,40 @@ impl Elementary {
 
     pub fn divide(&self) -> Result<Self, Error> {
         if let Div(numerator, denomenator) = self {
+            // quotients of polynomials are put in lowest terms using polynomial division
+            if let (Ok(numerator), Ok(denomenator)) = (
+                Polynomial::try_from(&**numerator),
+                Polynomial::try_from(&**denomenator),
+            ) {
+                if denomenator.degree() > 0 {
+                    return Ok(RationalFunction::new(numerator, denomenator)?.into());
+                }
+            }
+
             let numerator = numerator.factor()?;
             let denomenator = denomenator.factor()?;
 
             let mut removed_numerator: Vec<usize> = Vec::new();
             let mut removed_denomenator: Vec<usize> = Vec::new();
 
-            let mut constant_factor = 1.;
+            let mut constant_factor = Number::from(1.);
 
             for i in 0..numerator.len() {
                 for j in 0..denomenator.len() {
                     if numerator[i] == denomenator[j] {
                         removed_numerator.push(i);
                         removed_denomenator.push(j);
-                    } else if let (Con(numb1), Con(numb2)) =
-                        (numerator[i].clone(), denomenator[j].clone())
+                    } else if let (Some(numb1), Some(numb2)) =
+                        (numerator[i].as_number(), denomenator[j].as_number())
                     {
-                        constant_factor *= numb1 / numb2;
+                        constant_factor = constant_factor * numb1 / numb2;
                         removed_numerator.push(i);
                         removed_denomenator.push(j);
                     }
                 }
             }
 
-            let mut new_numerator = Con(constant_factor);
+            let mut new_numerator = Self::from_number(constant_factor);
             for (i, term) in numerator.iter().enumerate() {
                 if !removed_numerator.contains(&i) {
                     new_numerator *= term.clone();
@@ -167,8 +223,8 @@ impl Elementary {
                                 + Div(func2.to_owned(), Arc::new(f2.clone())).divide()?)
                             .simplify()?,
                         );
-                    } else if let (Con(numb1), Con(numb2)) = (f1.clone(), f2.clone()) {
-                        let gcd = Con(gcd(numb1, numb2));
+                    } else if let (Some(numb1), Some(numb2)) = (f1.as_number(), f2.as_number()) {
+                        let gcd = Self::from_number(gcd(numb1, numb2));
                         factors.push(gcd.clone());
                         factors.push(
                             ((func1.clone() / gcd.clone()).divide()?)
@@ -177,6 +233,10 @@ impl Elementary {
                     }
                 }
             }
+            // a sum whose terms have no factor in common is a factor in itself
+            if factors.is_empty() {
+                factors.push(self.to_owned());
+            }
         } else {
             factors.push(self.to_owned());
         }
@@ -192,8 +252,16 @@ impl Elementary {
 
     pub fn simplify_constant(&self) -> Result<Self, Error> {
         if self.classify()? == Category::Constant {
-            let value = self.clone().call()(0.);
-            Ok(Con(value))
+            // trig functions at multiples of π are evaluated exactly, so that sin(π) becomes 0,
+            // while other expressions containing symbolic constants are only put on canonical form
+            // (so that for instance 2π * 3 becomes 6π)
+            if let Some(value) = self.exact_trig_value() {
+                Ok(Con(value))
+            } else if self.is_symbolic() {
+                Ok(self.canonical())
+            } else {
+                Ok(Self::from_number(self.constant_value()))
+            }
         } else {
             Err(Error::SimplifyError(
                 self.to_owned(),
@@ -201,17 +269,66 @@ impl Elementary {
             ))
         }
     }
+
+    // evaluates a constant function. The value is kept exact for as long as it only involves
+    // arithmetic with rational numbers (and integer powers), so that for instance 1/3 does not
+    // become 0.333...
+    pub(crate) fn constant_value(&self) -> Number {
+        match self {
+            Con(numb) => Number::from(*numb),
+            Rat(rational) => Number::Exact(*rational),
+            Add(func1, func2) => func1.constant_value() + func2.constant_value(),
+            Sub(func1, func2) => func1.constant_value() - func2.constant_value(),
+            Mul(func1, func2) => func1.constant_value() * func2.constant_value(),
+            Div(func1, func2) => func1.constant_value() / func2.constant_value(),
+            Pow(base, exp) => match exp.constant_value() {
+                Number::Exact(exp)
+                    if exp.is_integer() && i32::try_from(exp.numerator()).is_ok() =>
+                {
+                    base.constant_value().powi(exp.numerator() as i32)
+                }
+                _ => Number::Float(self.clone().call()(0.)),
+            },
+            _ => Number::Float(self.clone().call()(0.)),
+        }
+    }
 }
 
-fn gcd(numb1: f64, numb2: f64) -> f64 {
-    // create bindings
-    let mut numb1 = numb1;
-    let mut numb2 = numb2;
+// the greatest common divisor of two numbers. For rationals, gcd(a/b, c/d) = gcd(ad, cb)/bd
+// asinh(sinh(f)) = f, atanh(tanh(f)) = f, acosh(cosh(f)) = |f| and cbrt(f^3) = f, while rounding
+// a value that has already been rounded leaves it as it is
+fn cancel_composition(function: &Elementary) -> Option<Elementary> {
+    let (outer, inner) = match function {
+        Asinh(func) | Acosh(func) | Atanh(func) | Cbrt(func) | Floor(func) | Ceil(func) => {
+            (function, &**func)
+        }
+        _ => return None,
+    };
+    match (outer, inner) {
+        (Asinh(_), Sinh(func)) | (Atanh(_), Tanh(func)) => Some((**func).clone()),
+        (Acosh(_), Cosh(func)) => Some(Abs(func.clone())),
+        (Cbrt(_), Pow(func, exp)) if exp.as_number().is_some_and(|numb| numb.to_f64() == 3.) => {
+            Some((**func).clone())
+        }
+        (Floor(_) | Ceil(_), Floor(_) | Ceil(_)) => Some(inner.clone()),
+        _ => None,
+    }
+}
+
+fn gcd(numb1: Number, numb2: Number) -> Number {
+    if let (Number::Exact(rational1), Number::Exact(rational2)) = (numb1, numb2) {
+        if let Some(gcd) = rational1.checked_gcd(rational2) {
+            return Number::Exact(gcd);
+        }
+    }
+
+    let mut numb1 = numb1.to_f64();
+    let mut numb2 = numb2.to_f64();
 
     while numb2 != 0. {
         let temp = numb1;
         numb1 = numb2;
         numb2 = temp % numb2;
     }
-    numb1
+    Number::Float(numb1)
 }
@@ -4,10 +4,13 @@ use std::{
 };
 
 use crate::{
+    utils::rational::Number,
     Elementary::{self, *},
-    Error, Factorial,
+    Error, Factorial, Function,
 };
 
+use super::{classification::Category, rational::RationalFunction};
+
 use itertools::Itertools;
 
 // Genreal idea goes as follows:
@@ -29,7 +32,8 @@ use itertools::Itertools;
 pub fn simplify_polynomial(polynomial: Elementary) -> Result<Elementary, Error> {
     // figure out if the polynomial is regular or rational
     if is_rational(polynomial.clone()) {
-        todo!()
+        // rational polynomials are put in lowest terms by cancelling out common factors
+        Ok(RationalFunction::try_from(&polynomial)?.into())
     } else {
         // we have a regular polynomial
 
@@ -68,28 +72,36 @@ pub fn simplify_polynomial(polynomial: Elementary) -> Result<Elementary, Error>
     }
 }
 
+// a polynomial is rational if it contains a division by a non-constant anywhere, since for
+// instance 1/x + 1 cannot be simplified term by term
 fn is_rational(polynomial: Elementary) -> bool {
-    if let Div(_, pol2) = polynomial {
-        !(*pol2).is_constant()
-    } else {
-        false
+    match polynomial {
+        Div(pol1, pol2) => !(*pol2).is_constant() || is_rational((*pol1).clone()),
+        Add(pol1, pol2) | Sub(pol1, pol2) | Mul(pol1, pol2) => {
+            is_rational((*pol1).clone()) || is_rational((*pol2).clone())
+        }
+        Pow(base, _) => is_rational((*base).clone()),
+        _ => false,
     }
 }
 
-fn get_polynomial_chunk(coefficient: f64, degree: i128) -> Elementary {
-    if coefficient == 0. {
+fn get_polynomial_chunk(coefficient: Number, degree: i128) -> Elementary {
+    let constant = Elementary::from_number(coefficient);
+    if coefficient.is_zero() {
         Con(0.)
     } else if degree == 0 {
-        Con(coefficient)
-    } else if coefficient == 1. {
+        constant
+    } else if coefficient.is_one() {
         if degree == 1 {
             X
         } else {
             Pow(X.into(), Con(degree as f64).into())
         }
+    } else if degree == 1 {
+        Mul(constant.into(), X.into())
     } else {
         Mul(
-            Con(coefficient).into(),
+            constant.into(),
             Pow(X.into(), Con(degree as f64).into()).into(),
         )
     }
@@ -118,12 +130,12 @@ fn get_terms(polynomial: Elementary) -> Result<Vec<Elementary>, Error> {
         Mul(ref pol1, ref pol2) => {
             if pol1.is_constant() {
                 for mut term in get_terms(simplify_polynomial((**pol2).clone())?)? {
-                    term *= Con((**pol1).clone().call()(0.));
+                    term *= Elementary::from_number(pol1.constant_value());
                     terms.push(term);
                 }
             } else if pol2.is_constant() {
                 for mut term in get_terms(simplify_polynomial((**pol1).clone())?)? {
-                    term *= Con((**pol2).clone().call()(0.));
+                    term *= Elementary::from_number(pol2.constant_value());
                     terms.push(term);
                 }
             } else {
@@ -164,7 +176,7 @@ fn expand_term(polynomial: &Elementary) -> Result<Vec<Elementary>, Error> {
         Pow(pol1, power) => {
             if let X = (*pol1).clone() {
                 Ok(vec![polynomial.to_owned()])
-            } else if let Con(_) = (*pol1).clone() {
+            } else if let Con(_) | Rat(_) = *pol1 {
                 // this polynomial should be a constant
                 Ok(vec![polynomial.clone().simplify()?])
             } else {
@@ -224,14 +236,14 @@ fn deconstruct_term(term: &Elementary) -> Result<(Elementary, Elementary), Error
 pub fn convert_term(polynomial: Elementary) -> Result<Elementary, Error> {
     match polynomial.clone() {
         Mul(pol1, pol2) => {
-            if let Con(numb) = (*pol1).clone() {
+            if let Con(_) | Rat(_) = *pol1 {
                 if let Mul(coefficient, power) = convert_term((*pol2).clone())? {
-                    let new_coefficient = coefficient * Con(numb);
+                    let new_coefficient = coefficient * (*pol1).clone();
                     return Ok(Mul(new_coefficient.into(), power));
                 }
-            } else if let Con(numb) = (*pol2).clone() {
+            } else if let Con(_) | Rat(_) = *pol2 {
                 if let Mul(coefficient, power) = convert_term((*pol1).clone())? {
-                    let new_coefficient = coefficient * Con(numb);
+                    let new_coefficient = coefficient * (*pol2).clone();
                     return Ok(Mul(new_coefficient.into(), power));
                 }
             }
@@ -263,7 +275,7 @@ pub fn convert_term(polynomial: Elementary) -> Result<Elementary, Error> {
         Div(pol1, pol2) => {
             if pol2.is_constant() {
                 if let Mul(coefficient, power) = convert_term((*pol1).clone())? {
-                    let new_coefficient = (*coefficient).clone() / (*pol2).clone().call()(0.);
+                    let new_coefficient = Div(coefficient, pol2);
                     Ok(Mul(new_coefficient.into(), power))
                 } else {
                     Err(Error::SimplifyError(polynomial, String::from("Internal Error, polynomial simplification yielded an unexpected result")))
@@ -279,7 +291,7 @@ pub fn convert_term(polynomial: Elementary) -> Result<Elementary, Error> {
         }
         Pow(base, _) => match (*base).clone() {
             X => Ok(Mul(Con(1.).into(), polynomial.into())),
-            Con(_) => Ok(Mul(
+            Con(_) | Rat(_) => Ok(Mul(
                 polynomial.simplify_constant()?.into(),
                 Pow(X.into(), Con(0.).into()).into(),
             )),
@@ -298,7 +310,7 @@ pub fn convert_term(polynomial: Elementary) -> Result<Elementary, Error> {
             }
         },
         X => Ok(Mul(Con(1.).into(), Pow(X.into(), Con(1.).into()).into())),
-        Con(numb) => Ok(Mul(Con(numb).into(), Pow(X.into(), Con(0.).into()).into())),
+        Con(_) | Rat(_) => Ok(Mul(polynomial.into(), Pow(X.into(), Con(0.).into()).into())),
         _ => Err(Error::SimplifyError(
             polynomial,
             String::from("Attempted to simplify a non-polynomial using polynomial-simplification"),
@@ -345,22 +357,22 @@ fn convert_mul(
     }
 }
 
-fn group_together(terms: Vec<Elementary>) -> Result<HashMap<i128, f64>, Error> {
-    let mut map: HashMap<i128, f64> = HashMap::new();
+fn group_together(terms: Vec<Elementary>) -> Result<HashMap<i128, Number>, Error> {
+    let mut map: HashMap<i128, Number> = HashMap::new();
 
     for term in terms {
         if let Mul(coefficient, power) = term {
             if let Pow(_, exp) = (*power).clone() {
                 // insert into the map
                 let degree = (*exp).clone().call()(0.) as i128;
-                let coefficient = (*coefficient).clone().call()(0.);
+                let coefficient = coefficient.constant_value();
                 if let Entry::Vacant(e) = map.entry(degree) {
                     e.insert(coefficient);
                 } else {
                     let existing_value = map.get_mut(&degree).expect("This should not fail");
                     // the unwrapping should not fail because the previous step ensures that the
                     // key does not already exist within the map
-                    *existing_value += coefficient;
+                    *existing_value = *existing_value + coefficient;
                 }
             }
         }
@@ -443,3 +455,326 @@ impl<T: PartialEq + Clone> Dedup<T> for Vec<T> {
         })
     }
 }
+
+/// A polynomial p(x) = a₀ + a₁x + a₂x² + ... + aₙxⁿ stored by its (dense) coefficients.
+///
+/// Polynomials can be manipulated directly (added, multiplied, divided, differentiated etc.)
+/// without going through the generic [Elementary](crate::Elementary) tree. A polynomial can be
+/// created from an [Elementary](crate::Elementary) or a [Function](crate::Function) that
+/// represents a polynomial, and converted back.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Function, Polynomial};
+/// let function = Function::from("(x + 1)^2");
+/// let polynomial = Polynomial::try_from(&function).unwrap();
+///
+/// // (x + 1)² = 1 + 2x + x²
+/// assert_eq!(polynomial.coefficients(), &[1., 2., 1.]);
+/// assert_eq!(polynomial.call(2.), 9.);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Polynomial {
+    // coefficients[i] is the coefficient of x^i. The leading coefficient is never zero, meaning
+    // that the zero polynomial has no coefficients at all
+    coefficients: Vec<f64>,
+}
+
+// coefficients that are this small compared to the largest coefficient are considered to be
+// rounding errors when dividing polynomials
+const RELATIVE_TOLERANCE: f64 = 1e-10;
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients, starting with the constant term, i.e.
+    /// `Polynomial::new(vec![a₀, a₁, ..., aₙ])` represents a₀ + a₁x + ... + aₙxⁿ.
+    pub fn new(coefficients: Vec<f64>) -> Self {
+        let mut polynomial = Self { coefficients };
+        polynomial.trim();
+        polynomial
+    }
+
+    /// Returns the polynomial p(x) = 0.
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Returns the constant polynomial p(x) = c.
+    pub fn constant(c: f64) -> Self {
+        Self::new(vec![c])
+    }
+
+    /// Returns the monomial p(x) = cxⁿ.
+    pub fn monomial(c: f64, n: usize) -> Self {
+        let mut coefficients = vec![0.; n + 1];
+        coefficients[n] = c;
+        Self::new(coefficients)
+    }
+
+    /// Returns the coefficients of the polynomial, starting with the constant term. The zero
+    /// polynomial has no coefficients.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial. The zero polynomial is considered to have degree 0.
+    pub fn degree(&self) -> usize {
+        self.coefficients.len().saturating_sub(1)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.coefficients.is_empty()
+    }
+
+    /// Returns the coefficient of the highest power of x.
+    pub fn leading_coefficient(&self) -> f64 {
+        self.coefficients.last().copied().unwrap_or(0.)
+    }
+
+    /// Evaluates the polynomial at x using [Horner's
+    /// method](https://en.wikipedia.org/wiki/Horner%27s_method).
+    pub fn call(&self, x: f64) -> f64 {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(0., |acc, coefficient| acc * x + coefficient)
+    }
+
+    pub fn derivative(&self) -> Self {
+        Self::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, coefficient)| i as f64 * coefficient)
+                .collect(),
+        )
+    }
+
+    /// Returns the antiderivative of the polynomial whose constant term is zero.
+    pub fn integral(&self) -> Self {
+        let mut coefficients = vec![0.];
+        coefficients.extend(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .map(|(i, coefficient)| coefficient / (i + 1) as f64),
+        );
+        Self::new(coefficients)
+    }
+
+    /// Returns the composition p(q(x)).
+    pub fn compose(&self, inner: &Self) -> Self {
+        // Horner's method with polynomial arithmetic
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Self::zero(), |acc, coefficient| {
+                acc * inner.clone() + Self::constant(*coefficient)
+            })
+    }
+
+    /// Divides the polynomial by the divisor using [polynomial long
+    /// division](https://en.wikipedia.org/wiki/Polynomial_long_division), returning the quotient
+    /// and the remainder.
+    ///
+    /// If the divisor is the zero polynomial, an [Error::InputError](crate::Error) is returned.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), Error> {
+        if divisor.is_zero() {
+            return Err(Error::InputError(String::from(
+                "Attempted to divide a polynomial by the zero polynomial",
+            )));
+        }
+
+        let mut remainder = self.coefficients.clone();
+        if remainder.len() < divisor.coefficients.len() {
+            return Ok((Self::zero(), self.clone()));
+        }
+
+        let mut quotient = vec![0.; remainder.len() - divisor.coefficients.len() + 1];
+        for i in (0..quotient.len()).rev() {
+            let coefficient = remainder[i + divisor.degree()] / divisor.leading_coefficient();
+            quotient[i] = coefficient;
+            for (j, divisor_coefficient) in divisor.coefficients.iter().enumerate() {
+                remainder[i + j] -= coefficient * divisor_coefficient;
+            }
+            // the leading term is eliminated exactly (and not just up to rounding errors)
+            remainder[i + divisor.degree()] = 0.;
+        }
+
+        let mut remainder = Self::new(remainder);
+        remainder.clean(self.max_coefficient());
+        Ok((Self::new(quotient), remainder))
+    }
+
+    /// Returns the (monic) [greatest common
+    /// divisor](https://en.wikipedia.org/wiki/Polynomial_greatest_common_divisor) of the two
+    /// polynomials.
+    ///
+    /// Since the coefficients are floating point numbers, remainders whose coefficients are
+    /// negligible compared to the coefficients of the polynomials are treated as zero.
+    pub fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while !b.is_zero() {
+            let (_, mut remainder) = a.div_rem(&b).expect("b is not the zero polynomial");
+            remainder.clean(a.max_coefficient().max(b.max_coefficient()));
+            a = b;
+            b = remainder;
+        }
+
+        a.monic()
+    }
+
+    /// Returns the polynomial divided by its leading coefficient.
+    pub fn monic(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let leading_coefficient = self.leading_coefficient();
+        Self::new(
+            self.coefficients
+                .iter()
+                .map(|coefficient| coefficient / leading_coefficient)
+                .collect(),
+        )
+    }
+
+    pub(crate) fn max_coefficient(&self) -> f64 {
+        self.coefficients
+            .iter()
+            .fold(0., |max, coefficient| coefficient.abs().max(max))
+    }
+
+    // removes coefficients that are negligible compared to the provided scale
+    pub(crate) fn clean(&mut self, scale: f64) {
+        for coefficient in self.coefficients.iter_mut() {
+            if coefficient.abs() <= RELATIVE_TOLERANCE * scale {
+                *coefficient = 0.;
+            }
+        }
+        self.trim();
+    }
+
+    // removes leading zeros
+    fn trim(&mut self) {
+        while self.coefficients.last() == Some(&0.) {
+            self.coefficients.pop();
+        }
+    }
+}
+
+impl std::ops::Add for Polynomial {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let len = self.coefficients.len().max(rhs.coefficients.len());
+        Self::new(
+            (0..len)
+                .map(|i| {
+                    self.coefficients.get(i).unwrap_or(&0.) + rhs.coefficients.get(i).unwrap_or(&0.)
+                })
+                .collect(),
+        )
+    }
+}
+impl std::ops::Neg for Polynomial {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(self.coefficients.iter().map(|c| -c).collect())
+    }
+}
+impl std::ops::Sub for Polynomial {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl std::ops::Mul for Polynomial {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return Self::zero();
+        }
+        let mut coefficients = vec![0.; self.coefficients.len() + rhs.coefficients.len() - 1];
+        for (i, a) in self.coefficients.iter().enumerate() {
+            for (j, b) in rhs.coefficients.iter().enumerate() {
+                coefficients[i + j] += a * b;
+            }
+        }
+        Self::new(coefficients)
+    }
+}
+impl std::ops::Mul<f64> for Polynomial {
+    type Output = Self;
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self::new(self.coefficients.iter().map(|c| c * rhs).collect())
+    }
+}
+
+impl TryFrom<&Elementary> for Polynomial {
+    type Error = Error;
+    fn try_from(value: &Elementary) -> Result<Self, Self::Error> {
+        match value.classify()? {
+            Category::Constant => Ok(Self::constant(value.clone().call()(0.))),
+            Category::Polynomial => to_polynomial(value),
+            _ => Err(Error::SimplifyError(
+                value.clone(),
+                String::from("Attempted to convert a non-polynomial expression into a polynomial"),
+            )),
+        }
+    }
+}
+
+// builds the polynomial from the expression tree using polynomial arithmetic
+fn to_polynomial(value: &Elementary) -> Result<Polynomial, Error> {
+    match value {
+        X => Ok(Polynomial::monomial(1., 1)),
+        Con(numb) => Ok(Polynomial::constant(*numb)),
+        Add(pol1, pol2) => Ok(to_polynomial(pol1)? + to_polynomial(pol2)?),
+        Sub(pol1, pol2) => Ok(to_polynomial(pol1)? - to_polynomial(pol2)?),
+        Mul(pol1, pol2) => Ok(to_polynomial(pol1)? * to_polynomial(pol2)?),
+        Div(pol1, pol2) if pol2.is_constant() => {
+            Ok(to_polynomial(pol1)? * (1. / (**pol2).clone().call()(0.)))
+        }
+        Pow(base, exp) if exp.is_constant() => {
+            let exp = (**exp).clone().call()(0.);
+            if exp < 0. || exp.fract() != 0. {
+                return Err(Error::SimplifyError(
+                    value.clone(),
+                    String::from("Polynomials can only contain non-negative integer powers of x"),
+                ));
+            }
+            let base = to_polynomial(base)?;
+            Ok((0..exp as usize).fold(Polynomial::constant(1.), |acc, _| acc * base.clone()))
+        }
+        _ if value.is_constant() => Ok(Polynomial::constant(value.clone().call()(0.))),
+        _ => Err(Error::SimplifyError(
+            value.clone(),
+            String::from("Attempted to convert a non-polynomial expression into a polynomial"),
+        )),
+    }
+}
+
+impl TryFrom<&Function> for Polynomial {
+    type Error = Error;
+    fn try_from(value: &Function) -> Result<Self, Self::Error> {
+        Self::try_from(&value.elementary())
+    }
+}
+
+impl From<Polynomial> for Elementary {
+    fn from(value: Polynomial) -> Self {
+        let mut elementary = Con(0.);
+        for (degree, coefficient) in value.coefficients.iter().enumerate() {
+            if *coefficient != 0. {
+                elementary += get_polynomial_chunk(Number::from(*coefficient), degree as i128);
+            }
+        }
+        elementary
+    }
+}
+
+impl From<Polynomial> for Function {
+    fn from(value: Polynomial) -> Self {
+        Function::from(Elementary::from(value))
+    }
+}
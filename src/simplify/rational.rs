@@ -0,0 +1,396 @@
+use std::sync::Arc;
+
+use crate::{
+    Elementary::{self, *},
+    Error, Function, Polynomial,
+};
+
+use super::classification::Category;
+
+/// A rational function p(x)/q(x), i.e. the quotient of two [polynomials](crate::Polynomial).
+///
+/// Rational functions are always kept in lowest terms, meaning that common factors of the
+/// numerator and the denominator are cancelled out, and the denominator is monic.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Function, Polynomial, RationalFunction};
+/// // (x² - 1)/(x - 1) = x + 1
+/// let function = Function::from("(x^2 - 1)/(x - 1)");
+/// let rational = RationalFunction::try_from(&function).unwrap();
+///
+/// assert_eq!(rational.numerator(), &Polynomial::new(vec![1., 1.]));
+/// assert_eq!(rational.denominator(), &Polynomial::constant(1.));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RationalFunction {
+    numerator: Polynomial,
+    denominator: Polynomial,
+}
+
+/// A term of a [partial fraction decomposition](crate::PartialFractions).
+#[derive(Debug, Clone, PartialEq)]
+pub enum PartialFraction {
+    /// The term coefficient / (x - root)^power.
+    Linear {
+        coefficient: f64,
+        root: f64,
+        power: usize,
+    },
+    /// The term (linear * x + constant) / factor^power where the factor is an irreducible
+    /// quadratic polynomial x² + px + q.
+    Quadratic {
+        linear: f64,
+        constant: f64,
+        factor: Polynomial,
+        power: usize,
+    },
+}
+
+/// The [partial fraction decomposition](https://en.wikipedia.org/wiki/Partial_fraction_decomposition)
+/// of a rational function, i.e. a polynomial plus a sum of [terms](crate::PartialFraction) whose
+/// denominators are powers of linear or irreducible quadratic polynomials.
+///
+/// See [RationalFunction::partial_fractions()](crate::RationalFunction::partial_fractions) for
+/// usage and examples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialFractions {
+    pub polynomial: Polynomial,
+    pub terms: Vec<PartialFraction>,
+}
+
+impl RationalFunction {
+    /// Creates the rational function numerator/denominator in lowest terms.
+    ///
+    /// If the denominator is the zero polynomial, an [Error::InputError](crate::Error) is
+    /// returned.
+    pub fn new(numerator: Polynomial, denominator: Polynomial) -> Result<Self, Error> {
+        if denominator.is_zero() {
+            return Err(Error::InputError(String::from(
+                "The denominator of a rational function cannot be the zero polynomial",
+            )));
+        }
+
+        // cancel out the common factors
+        let common = numerator.gcd(&denominator);
+        let (numerator, _) = numerator.div_rem(&common)?;
+        let (denominator, _) = denominator.div_rem(&common)?;
+
+        // make the denominator monic
+        let leading_coefficient = denominator.leading_coefficient();
+        Ok(Self {
+            numerator: numerator * (1. / leading_coefficient),
+            denominator: denominator.monic(),
+        })
+    }
+
+    pub fn numerator(&self) -> &Polynomial {
+        &self.numerator
+    }
+
+    pub fn denominator(&self) -> &Polynomial {
+        &self.denominator
+    }
+
+    pub fn call(&self, x: f64) -> f64 {
+        self.numerator.call(x) / self.denominator.call(x)
+    }
+
+    /// Decomposes the rational function into [partial
+    /// fractions](https://en.wikipedia.org/wiki/Partial_fraction_decomposition).
+    ///
+    /// The denominator is factored into powers of linear and irreducible quadratic polynomials
+    /// using its [complex roots](crate::Polynomial::complex_roots), after which the numerators of
+    /// the terms are found by solving a system of linear equations.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Function, PartialFraction, RationalFunction};
+    /// // 1/(x² - 1) = 0.5/(x - 1) - 0.5/(x + 1)
+    /// let function = Function::from("1/(x^2 - 1)");
+    /// let rational = RationalFunction::try_from(&function).unwrap();
+    ///
+    /// let decomposition = rational.partial_fractions().unwrap();
+    ///
+    /// assert!(decomposition.polynomial.is_zero());
+    /// assert_eq!(decomposition.terms.len(), 2);
+    /// if let PartialFraction::Linear { coefficient, root, power } = decomposition.terms[0] {
+    ///     assert!((coefficient + 0.5).abs() < 1e-12);
+    ///     assert!((root + 1.).abs() < 1e-12);
+    ///     assert_eq!(power, 1);
+    /// }
+    /// ```
+    pub fn partial_fractions(&self) -> Result<PartialFractions, Error> {
+        let (polynomial, remainder) = self.numerator.div_rem(&self.denominator)?;
+
+        // the denominator is split into powers of linear and quadratic factors. Every power up to
+        // the multiplicity of a factor gets a term of its own
+        let mut terms: Vec<PartialFraction> = Vec::new();
+        let mut basis: Vec<Polynomial> = Vec::new();
+        for root in self.denominator.complex_roots()? {
+            if root.value.is_real() {
+                let factor = Polynomial::new(vec![-root.value.re, 1.]);
+                for power in 1..=root.multiplicity {
+                    terms.push(PartialFraction::Linear {
+                        coefficient: 0.,
+                        root: root.value.re,
+                        power,
+                    });
+                    basis.push(self.cofactor(&factor, power)?);
+                }
+            } else if root.value.im > 0. {
+                // the conjugate root is covered by the same quadratic factor
+                let factor =
+                    Polynomial::new(vec![root.value.abs().powi(2), -2. * root.value.re, 1.]);
+                for power in 1..=root.multiplicity {
+                    terms.push(PartialFraction::Quadratic {
+                        linear: 0.,
+                        constant: 0.,
+                        factor: factor.clone(),
+                        power,
+                    });
+                    let cofactor = self.cofactor(&factor, power)?;
+                    basis.push(cofactor.clone() * Polynomial::monomial(1., 1));
+                    basis.push(cofactor);
+                }
+            }
+        }
+
+        // the remainder is a linear combination of the cofactors
+        let size = self.denominator.degree();
+        let matrix: Vec<Vec<f64>> = (0..size)
+            .map(|i| {
+                basis
+                    .iter()
+                    .map(|polynomial| *polynomial.coefficients().get(i).unwrap_or(&0.))
+                    .collect()
+            })
+            .collect();
+        let rhs: Vec<f64> = (0..size)
+            .map(|i| *remainder.coefficients().get(i).unwrap_or(&0.))
+            .collect();
+        let mut solution = solve_linear_system(matrix, rhs)?.into_iter();
+
+        for term in terms.iter_mut() {
+            match term {
+                PartialFraction::Linear { coefficient, .. } => {
+                    *coefficient = solution.next().unwrap_or(0.);
+                }
+                PartialFraction::Quadratic {
+                    linear, constant, ..
+                } => {
+                    *linear = solution.next().unwrap_or(0.);
+                    *constant = solution.next().unwrap_or(0.);
+                }
+            }
+        }
+
+        Ok(PartialFractions { polynomial, terms })
+    }
+
+    // returns the denominator divided by factor^power
+    fn cofactor(&self, factor: &Polynomial, power: usize) -> Result<Polynomial, Error> {
+        let divisor = (0..power).fold(Polynomial::constant(1.), |acc, _| acc * factor.clone());
+        Ok(self.denominator.div_rem(&divisor)?.0)
+    }
+}
+
+impl std::ops::Add for RationalFunction {
+    type Output = Result<Self, Error>;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator.clone() + rhs.numerator * self.denominator.clone(),
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+impl std::ops::Sub for RationalFunction {
+    type Output = Result<Self, Error>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator.clone() - rhs.numerator * self.denominator.clone(),
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+impl std::ops::Mul for RationalFunction {
+    type Output = Result<Self, Error>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.numerator,
+            self.denominator * rhs.denominator,
+        )
+    }
+}
+impl std::ops::Div for RationalFunction {
+    type Output = Result<Self, Error>;
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.numerator * rhs.denominator,
+            self.denominator * rhs.numerator,
+        )
+    }
+}
+
+impl From<Polynomial> for RationalFunction {
+    fn from(value: Polynomial) -> Self {
+        Self {
+            numerator: value,
+            denominator: Polynomial::constant(1.),
+        }
+    }
+}
+
+impl TryFrom<&Elementary> for RationalFunction {
+    type Error = Error;
+    fn try_from(value: &Elementary) -> Result<Self, Self::Error> {
+        match value.classify()? {
+            Category::Constant | Category::Polynomial => to_rational(value),
+            _ => Err(Error::SimplifyError(
+                value.clone(),
+                String::from(
+                    "Attempted to convert a non-rational expression into a rational function",
+                ),
+            )),
+        }
+    }
+}
+
+impl TryFrom<&Function> for RationalFunction {
+    type Error = Error;
+    fn try_from(value: &Function) -> Result<Self, Self::Error> {
+        Self::try_from(&value.elementary())
+    }
+}
+
+// builds the rational function from the expression tree using rational function arithmetic
+fn to_rational(value: &Elementary) -> Result<RationalFunction, Error> {
+    match value {
+        Add(func1, func2) => to_rational(func1)? + to_rational(func2)?,
+        Sub(func1, func2) => to_rational(func1)? - to_rational(func2)?,
+        Mul(func1, func2) => to_rational(func1)? * to_rational(func2)?,
+        Div(func1, func2) => to_rational(func1)? / to_rational(func2)?,
+        Pow(base, exp) if exp.is_constant() => {
+            let exp = (**exp).clone().call()(0.);
+            if exp.fract() != 0. {
+                return Err(Error::SimplifyError(
+                    value.clone(),
+                    String::from("Rational functions can only contain integer powers of x"),
+                ));
+            }
+            let base = to_rational(base)?;
+            let power = (0..exp.abs() as usize).try_fold(
+                RationalFunction::from(Polynomial::constant(1.)),
+                |acc, _| acc * base.clone(),
+            )?;
+            if exp < 0. {
+                RationalFunction::from(Polynomial::constant(1.)) / power
+            } else {
+                Ok(power)
+            }
+        }
+        _ => Ok(RationalFunction::from(Polynomial::try_from(value)?)),
+    }
+}
+
+impl From<RationalFunction> for Elementary {
+    fn from(value: RationalFunction) -> Self {
+        if value.denominator.degree() == 0 {
+            Elementary::from(value.numerator * (1. / value.denominator.leading_coefficient()))
+        } else {
+            Div(
+                Arc::new(Elementary::from(value.numerator)),
+                Arc::new(Elementary::from(value.denominator)),
+            )
+        }
+    }
+}
+
+impl From<RationalFunction> for Function {
+    fn from(value: RationalFunction) -> Self {
+        Function::from(Elementary::from(value))
+    }
+}
+
+impl From<PartialFraction> for Elementary {
+    fn from(value: PartialFraction) -> Self {
+        match value {
+            PartialFraction::Linear {
+                coefficient,
+                root,
+                power,
+            } => {
+                let factor = Elementary::from(Polynomial::new(vec![-root, 1.]));
+                Con(coefficient) / power_of(factor, power)
+            }
+            PartialFraction::Quadratic {
+                linear,
+                constant,
+                factor,
+                power,
+            } => {
+                Elementary::from(Polynomial::new(vec![constant, linear]))
+                    / power_of(Elementary::from(factor), power)
+            }
+        }
+    }
+}
+
+impl From<PartialFractions> for Elementary {
+    fn from(value: PartialFractions) -> Self {
+        let mut elementary = Elementary::from(value.polynomial);
+        for term in value.terms {
+            elementary += Elementary::from(term);
+        }
+        elementary
+    }
+}
+
+fn power_of(base: Elementary, power: usize) -> Elementary {
+    if power == 1 {
+        base
+    } else {
+        Pow(Arc::new(base), Arc::new(Con(power as f64)))
+    }
+}
+
+// solves the system of linear equations using Gaussian elimination with partial pivoting
+fn solve_linear_system(matrix: Vec<Vec<f64>>, rhs: Vec<f64>) -> Result<Vec<f64>, Error> {
+    let size = rhs.len();
+    let mut matrix = matrix;
+    let mut rhs = rhs;
+
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|i, j| {
+                matrix[*i][column]
+                    .abs()
+                    .total_cmp(&matrix[*j][column].abs())
+            })
+            .expect("the range is not empty");
+        if matrix[pivot][column] == 0. {
+            return Err(Error::InternalError(String::from(
+                "Encountered a singular system of equations while decomposing into partial fractions",
+            )));
+        }
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+
+        let pivot_row = matrix[column].clone();
+        for row in column + 1..size {
+            let factor = matrix[row][column] / pivot_row[column];
+            for (entry, pivot_entry) in matrix[row].iter_mut().zip(&pivot_row).skip(column) {
+                *entry -= factor * pivot_entry;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+
+    let mut solution = vec![0.; size];
+    for row in (0..size).rev() {
+        let sum: f64 = (row + 1..size).map(|k| matrix[row][k] * solution[k]).sum();
+        solution[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+
+    Ok(solution)
+}
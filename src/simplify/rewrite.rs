@@ -0,0 +1,290 @@
+use std::sync::Arc;
+
+use crate::{
+    utils::rational::Number,
+    Elementary::{self, *},
+};
+
+// Shared machinery for the rule based simplifications (trigonometric and exponential). The
+// expression is rewritten from the bottom up until no more rules apply. Before the rules of the
+// category are tried, constant subexpressions are evaluated, products are collected into
+// c * f1^n1 * f2^n2 * ... and like terms of sums are combined, so that the rules can recognise
+// their patterns in for instance derivatives.
+
+// the maximum number of times the rules are applied to the whole expression
+const MAX_PASSES: usize = 16;
+
+pub(super) type Rule = fn(&Elementary) -> Option<Elementary>;
+
+pub(super) fn simplify_with(function: &Elementary, rules: &[Rule]) -> Elementary {
+    let mut current = function.clone();
+
+    for _ in 0..MAX_PASSES {
+        let next = rewrite(&current, rules);
+        if next == current {
+            break;
+        }
+        current = next;
+    }
+
+    current
+}
+
+impl Elementary {
+    // applies the provided function to all direct children of the expression
+    pub(crate) fn map_children(&self, f: impl Fn(&Elementary) -> Elementary) -> Self {
+        let map = |func: &Arc<Elementary>| Arc::new(f(func));
+        match self {
+            Sin(func) => Sin(map(func)),
+            Cos(func) => Cos(map(func)),
+            Tan(func) => Tan(map(func)),
+            Sec(func) => Sec(map(func)),
+            Csc(func) => Csc(map(func)),
+            Cot(func) => Cot(map(func)),
+            Asin(func) => Asin(map(func)),
+            Acos(func) => Acos(map(func)),
+            Atan(func) => Atan(map(func)),
+            Acot(func) => Acot(map(func)),
+            Asec(func) => Asec(map(func)),
+            Acsc(func) => Acsc(map(func)),
+            Sinh(func) => Sinh(map(func)),
+            Cosh(func) => Cosh(map(func)),
+            Tanh(func) => Tanh(map(func)),
+            Sech(func) => Sech(map(func)),
+            Csch(func) => Csch(map(func)),
+            Coth(func) => Coth(map(func)),
+            Asinh(func) => Asinh(map(func)),
+            Acosh(func) => Acosh(map(func)),
+            Atanh(func) => Atanh(map(func)),
+            Add(func1, func2) => Add(map(func1), map(func2)),
+            Sub(func1, func2) => Sub(map(func1), map(func2)),
+            Mul(func1, func2) => Mul(map(func1), map(func2)),
+            Div(func1, func2) => Div(map(func1), map(func2)),
+            Pow(func1, func2) => Pow(map(func1), map(func2)),
+            Log(func1, func2) => Log(map(func1), map(func2)),
+            Factorial(func) => Factorial(map(func)),
+            Gamma(func) => Gamma(map(func)),
+            Polygamma(func, order) => Polygamma(map(func), *order),
+            Abs(func) => Abs(map(func)),
+            Cbrt(func) => Cbrt(map(func)),
+            Floor(func) => Floor(map(func)),
+            Ceil(func) => Ceil(map(func)),
+            Piecewise(branches, otherwise) => Piecewise(
+                branches
+                    .iter()
+                    .map(|(condition, func)| (condition.map(&f), map(func)))
+                    .collect(),
+                map(otherwise),
+            ),
+            Con(_) | Rat(_) | Sym(_) | X | Wildcard(_) => self.clone(),
+        }
+    }
+
+    // the direct children of the expression
+    pub(crate) fn children(&self) -> Vec<&Elementary> {
+        match self {
+            Sin(func)
+            | Cos(func)
+            | Tan(func)
+            | Sec(func)
+            | Csc(func)
+            | Cot(func)
+            | Asin(func)
+            | Acos(func)
+            | Atan(func)
+            | Acot(func)
+            | Asec(func)
+            | Acsc(func)
+            | Sinh(func)
+            | Cosh(func)
+            | Tanh(func)
+            | Sech(func)
+            | Csch(func)
+            | Coth(func)
+            | Asinh(func)
+            | Acosh(func)
+            | Atanh(func)
+            | Factorial(func)
+            | Gamma(func)
+            | Polygamma(func, _)
+            | Abs(func)
+            | Cbrt(func)
+            | Floor(func)
+            | Ceil(func) => vec![func],
+            Add(func1, func2)
+            | Sub(func1, func2)
+            | Mul(func1, func2)
+            | Div(func1, func2)
+            | Pow(func1, func2)
+            | Log(func1, func2) => vec![func1, func2],
+            // the sides of each condition are followed by the value of its branch
+            Piecewise(branches, otherwise) => branches
+                .iter()
+                .flat_map(|(condition, func)| [&*condition.lhs, &*condition.rhs, &**func])
+                .chain([&**otherwise])
+                .collect(),
+            Con(_) | Rat(_) | Sym(_) | X | Wildcard(_) => Vec::new(),
+        }
+    }
+}
+
+fn rewrite(function: &Elementary, rules: &[Rule]) -> Elementary {
+    let function = match function.map_children(|func| rewrite(func, rules)) {
+        // the operators get rid of multiplications by 0 and 1 as well as additions of 0
+        Mul(func1, func2) => (*func1).clone() * (*func2).clone(),
+        Add(func1, func2) => (*func1).clone() + (*func2).clone(),
+        Sub(func1, func2) if *func2 == Con(0.) => (*func1).clone(),
+        // 0/c = 0 for constants other than 0, including symbolic ones such as e
+        Div(func1, func2)
+            if *func1 == Con(0.) && func2.is_constant() && !func2.constant_value().is_zero() =>
+        {
+            Con(0.)
+        }
+        Div(func1, func2) => match func2.as_number() {
+            Some(numb) if !numb.is_zero() => {
+                Elementary::from_number(Number::from(1.) / numb) * (*func1).clone()
+            }
+            _ => Div(func1, func2),
+        },
+        Pow(func1, func2) if *func2 == Con(1.) => (*func1).clone(),
+        Pow(_, func2) if *func2 == Con(0.) => Con(1.),
+        other => other,
+    };
+
+    if let Some(value) = function.exact_trig_value() {
+        return Con(value);
+    }
+    if let Some(value) = function.exact_log_value() {
+        return value;
+    }
+    // expressions with symbolic constants are left to the rules so that they are kept exact
+    if function.is_constant() && !function.is_symbolic() {
+        if function.as_number().is_some() {
+            return function;
+        }
+        let value = function.constant_value();
+        if value.is_finite() {
+            return Elementary::from_number(value);
+        }
+    }
+
+    collect_product(&function)
+        .or_else(|| collect_sum(&function))
+        .or_else(|| rules.iter().find_map(|rule| rule(&function)))
+        .unwrap_or(function)
+}
+
+// splits a factor into its base and exponent. Powers are only combined when that is valid for all
+// values of the base, that is, when the exponent is constant or the base is a positive constant
+pub(super) fn split_power(factor: Elementary) -> (Elementary, Elementary) {
+    match factor {
+        Pow(base, exp)
+            if exp.as_number().is_some()
+                || matches!(*base, Sym(_))
+                || matches!(base.as_number(), Some(numb) if !numb.is_negative() && !numb.is_zero()) =>
+        {
+            ((*base).clone(), (*exp).clone())
+        }
+        other => (other, Con(1.)),
+    }
+}
+
+// rewrites a product as c * f1^n1 * f2^n2 * ..., where the constants have been multiplied together
+// and equal factors have been combined into powers (so that for instance e^a * e^b = e^(a + b)).
+// Returns None if the product is already on this form
+fn collect_product(function: &Elementary) -> Option<Elementary> {
+    if !matches!(function, Mul(_, _)) {
+        return None;
+    }
+    let mut factors = Vec::new();
+    gather_factors(function, &mut factors);
+
+    let mut constant = Number::from(1.);
+    let mut powers: Vec<(Elementary, Elementary)> = Vec::new();
+    for factor in factors {
+        if let Some(numb) = factor.as_number() {
+            constant = constant * numb;
+            continue;
+        }
+        let (base, exp) = split_power(factor);
+        match powers.iter_mut().find(|(other, _)| *other == base) {
+            Some((_, total)) => {
+                *total = match (total.as_number(), exp.as_number()) {
+                    (Some(numb1), Some(numb2)) => Elementary::from_number(numb1 + numb2),
+                    _ => total.clone() + exp,
+                }
+            }
+            None => powers.push((base, exp)),
+        }
+    }
+
+    let result = powers.into_iter().fold(
+        Elementary::from_number(constant),
+        |result, (base, exp)| match exp.as_number() {
+            Some(numb) if numb.is_zero() => result,
+            Some(numb) if numb.is_one() => result * base,
+            _ => result * Pow(Arc::new(base), Arc::new(exp)),
+        },
+    );
+
+    if result == *function {
+        None
+    } else {
+        Some(result)
+    }
+}
+
+// splits a term into its constant coefficient and the rest of the term
+fn split_coefficient(function: &Elementary) -> (Number, Elementary) {
+    if let Some(numb) = function.as_number() {
+        return (numb, Con(1.));
+    }
+    match function {
+        Mul(func1, func2) => match (func1.as_number(), func2.as_number()) {
+            (Some(numb), _) => (numb, (**func2).clone()),
+            (_, Some(numb)) => (numb, (**func1).clone()),
+            _ => (Number::from(1.), function.clone()),
+        },
+        _ => (Number::from(1.), function.clone()),
+    }
+}
+
+// combines like terms (c1 * f ± c2 * f = (c1 ± c2) * f) and turns the addition of negative terms
+// into subtractions (and vice versa)
+fn collect_sum(function: &Elementary) -> Option<Elementary> {
+    let (func1, func2, sign) = match function {
+        Add(func1, func2) => (func1, func2, Number::from(1.)),
+        Sub(func1, func2) => (func1, func2, Number::from(-1.)),
+        _ => return None,
+    };
+    let (coefficient1, term1) = split_coefficient(func1);
+    let (coefficient2, term2) = split_coefficient(func2);
+
+    if term1 == term2 && !matches!(term1, Con(_)) {
+        return Some(Elementary::from_number(coefficient1 + sign * coefficient2) * term1);
+    }
+    if **func1 == Con(0.) {
+        return Some(Elementary::from_number(sign * coefficient2) * term2);
+    }
+    if coefficient2.is_negative() && !matches!(term2, Con(_)) {
+        let negated = Elementary::from_number(-coefficient2) * term2;
+        return Some(if !sign.is_negative() {
+            (**func1).clone() - negated
+        } else {
+            (**func1).clone() + negated
+        });
+    }
+    if !sign.is_negative() && coefficient1.is_negative() && !matches!(term1, Con(_)) {
+        return Some((**func2).clone() - Elementary::from_number(-coefficient1) * term1);
+    }
+    None
+}
+
+pub(super) fn gather_factors(function: &Elementary, factors: &mut Vec<Elementary>) {
+    if let Mul(func1, func2) = function {
+        gather_factors(func1, factors);
+        gather_factors(func2, factors);
+    } else {
+        factors.push(function.clone());
+    }
+}
@@ -0,0 +1,338 @@
+use std::{collections::HashMap, fmt, mem::discriminant, sync::Arc};
+
+use crate::{
+    Elementary::{self, *},
+    Error, Function,
+};
+
+/// The subexpressions matched by the wildcards of a pattern, indexed by the names of the wildcards
+/// (without the leading question mark).
+pub type Bindings = HashMap<String, Elementary>;
+
+type Condition = Arc<dyn Fn(&Bindings) -> bool + Send + Sync>;
+
+// the default maximum number of times the rules are applied to the whole expression
+const MAX_PASSES: usize = 100;
+// the default maximum number of nodes in the expression tree. Rules such as ?a -> ?a + 0 would
+// otherwise grow the expression indefinitely
+const MAX_SIZE: usize = 10_000;
+
+/// A rewrite rule of the type pattern -> replacement.
+///
+/// The pattern may contain wildcards (written as ?name) which match arbitrary subexpressions. A
+/// wildcard that appears several times in the pattern must match the same subexpression every
+/// time. Addition and multiplication are matched commutatively, so that the pattern ?a * x matches
+/// both 2x and x*2.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Function, Rule, RuleSet};
+/// let mut rules = RuleSet::new();
+/// rules.add_rule(Rule::parse("sin(?a)^2 + cos(?a)^2 -> 1").unwrap());
+///
+/// let mut function = Function::from("ln(sin(3x)^2 + cos(3x)^2)");
+/// function.rewrite(&rules).unwrap();
+/// assert_eq!(function.call(2.), 0.);
+/// ```
+#[derive(Clone)]
+pub struct Rule {
+    pattern: Elementary,
+    replacement: Elementary,
+    condition: Option<Condition>,
+}
+
+impl Rule {
+    /// Creates a rule that replaces expressions matching the pattern by the replacement. All
+    /// wildcards in the replacement must appear in the pattern.
+    pub fn new(pattern: Elementary, replacement: Elementary) -> Result<Self, Error> {
+        let mut pattern_wildcards = Vec::new();
+        pattern.wildcards(&mut pattern_wildcards);
+        let mut replacement_wildcards = Vec::new();
+        replacement.wildcards(&mut replacement_wildcards);
+
+        if let Some(unbound) = replacement_wildcards
+            .iter()
+            .find(|name| !pattern_wildcards.contains(name))
+        {
+            return Err(Error::ParseError(format!(
+                "The wildcard ?{unbound} in the replacement does not appear in the pattern"
+            )));
+        }
+
+        Ok(Self {
+            pattern,
+            replacement,
+            condition: None,
+        })
+    }
+
+    /// Parses a rule written as "pattern -> replacement", such as "?a - ?a -> 0".
+    pub fn parse(rule: &str) -> Result<Self, Error> {
+        let Some((pattern, replacement)) = rule.split_once("->") else {
+            return Err(Error::ParseError(format!(
+                "The rule '{rule}' must be of the form pattern -> replacement"
+            )));
+        };
+        Self::new(
+            Elementary::try_parse(pattern)?,
+            Elementary::try_parse(replacement)?,
+        )
+    }
+
+    /// Sets a side condition that the matched subexpressions must fulfill in order for the rule to
+    /// be applied.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use number_diff::{Elementary, Rule, RuleSet};
+    /// // |c| = c only holds for positive constants
+    /// let mut rule = Rule::parse("abs(?c) -> ?c").unwrap();
+    /// rule.set_condition(|bindings| {
+    ///     bindings["c"].is_constant() && bindings["c"].clone().call()(0.) >= 0.
+    /// });
+    ///
+    /// let mut rules = RuleSet::new();
+    /// rules.add_rule(rule);
+    /// assert_eq!(Elementary::from("abs(2)").rewrite(&rules).unwrap(), Elementary::Con(2.));
+    /// assert!(matches!(Elementary::from("abs(x)").rewrite(&rules).unwrap(), Elementary::Abs(_)));
+    /// ```
+    pub fn set_condition(
+        &mut self,
+        condition: impl Fn(&Bindings) -> bool + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.condition = Some(Arc::new(condition));
+        self
+    }
+
+    pub fn pattern(&self) -> &Elementary {
+        &self.pattern
+    }
+
+    pub fn replacement(&self) -> &Elementary {
+        &self.replacement
+    }
+
+    /// Returns the bindings of the wildcards if the function matches the pattern of the rule (and
+    /// fulfills its condition). Only the function as a whole is matched, not its subexpressions.
+    pub fn matches(&self, function: &Elementary) -> Option<Bindings> {
+        let bindings = match_pattern(&self.pattern, function, &Bindings::new())?;
+        match &self.condition {
+            Some(condition) if !condition(&bindings) => None,
+            _ => Some(bindings),
+        }
+    }
+
+    /// Applies the rule to the function as a whole, returning None if it does not match.
+    pub fn apply(&self, function: &Elementary) -> Option<Elementary> {
+        self.matches(function)
+            .map(|bindings| substitute(&self.replacement, &bindings))
+    }
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rule")
+            .field("pattern", &self.pattern)
+            .field("replacement", &self.replacement)
+            .field("conditional", &self.condition.is_some())
+            .finish()
+    }
+}
+
+/// An ordered collection of [rules](crate::Rule).
+///
+/// The rules are applied from the bottom up: the subexpressions of a node are rewritten before the
+/// node itself, where the first matching rule is used. This is repeated until no more rules apply.
+/// Since the rules are arbitrary, rewriting is aborted with an error if the rules are found to be
+/// cyclic, if they make the expression grow too large, or if they haven't reached a fixpoint
+/// after a maximum number of passes.
+#[derive(Debug, Clone)]
+pub struct RuleSet {
+    rules: Vec<Rule>,
+    max_passes: usize,
+    max_size: usize,
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuleSet {
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            max_passes: MAX_PASSES,
+            max_size: MAX_SIZE,
+        }
+    }
+
+    /// Parses a rule set where each rule is written as "pattern -> replacement".
+    pub fn parse(rules: &[&str]) -> Result<Self, Error> {
+        let mut rule_set = Self::new();
+        for rule in rules {
+            rule_set.add_rule(Rule::parse(rule)?);
+        }
+        Ok(rule_set)
+    }
+
+    pub fn add_rule(&mut self, rule: Rule) -> &mut Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Sets the maximum number of times the rules are applied to the whole expression.
+    pub fn set_max_passes(&mut self, max_passes: usize) -> &mut Self {
+        self.max_passes = max_passes;
+        self
+    }
+
+    /// Sets the maximum number of nodes that the rewritten expression may consist of.
+    pub fn set_max_size(&mut self, max_size: usize) -> &mut Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Applies the rules to the function until no more rules apply.
+    pub fn rewrite(&self, function: &Elementary) -> Result<Elementary, Error> {
+        let mut history = vec![function.clone()];
+
+        for _ in 0..self.max_passes {
+            let current = history.last().expect("the history is never empty");
+            let next = self.rewrite_pass(current);
+
+            if next == *current {
+                return Ok(next);
+            }
+            if history.contains(&next) {
+                return Err(Error::SimplifyError(
+                    function.clone(),
+                    String::from("The rewrite rules are cyclic"),
+                ));
+            }
+            if next.size() > self.max_size {
+                return Err(Error::SimplifyError(
+                    function.clone(),
+                    format!(
+                        "The rewrite rules made the expression grow beyond {} nodes",
+                        self.max_size
+                    ),
+                ));
+            }
+            history.push(next);
+        }
+
+        Err(Error::SimplifyError(
+            function.clone(),
+            format!(
+                "The rewrite rules did not reach a fixpoint within {} passes",
+                self.max_passes
+            ),
+        ))
+    }
+
+    fn rewrite_pass(&self, function: &Elementary) -> Elementary {
+        let function = function.map_children(|func| self.rewrite_pass(func));
+        self.rules
+            .iter()
+            .find_map(|rule| rule.apply(&function))
+            .unwrap_or(function)
+    }
+}
+
+impl Elementary {
+    /// Applies the [rules](crate::RuleSet) to the function until no more rules apply.
+    pub fn rewrite(&self, rules: &RuleSet) -> Result<Self, Error> {
+        rules.rewrite(self)
+    }
+
+    // the number of nodes in the expression tree
+    fn size(&self) -> usize {
+        1 + self
+            .children()
+            .iter()
+            .map(|func| func.size())
+            .sum::<usize>()
+    }
+
+    pub(crate) fn wildcards(&self, names: &mut Vec<String>) {
+        if let Wildcard(name) = self {
+            names.push(name.clone());
+        }
+        for child in self.children() {
+            child.wildcards(names);
+        }
+    }
+}
+
+impl Function {
+    /// Applies the [rules](crate::RuleSet) to the function until no more rules apply.
+    pub fn rewrite(&mut self, rules: &RuleSet) -> Result<(), Error> {
+        self.set_function(rules.rewrite(&self.elementary())?);
+        Ok(())
+    }
+}
+
+fn match_pattern(
+    pattern: &Elementary,
+    function: &Elementary,
+    bindings: &Bindings,
+) -> Option<Bindings> {
+    match (pattern, function) {
+        (Wildcard(name), _) => match bindings.get(name) {
+            Some(bound) => (bound == function).then(|| bindings.clone()),
+            None => {
+                let mut bindings = bindings.clone();
+                bindings.insert(name.clone(), function.clone());
+                Some(bindings)
+            }
+        },
+        // addition and multiplication are commutative
+        (Add(pattern1, pattern2), Add(func1, func2))
+        | (Mul(pattern1, pattern2), Mul(func1, func2)) => {
+            let patterns = [&**pattern1, &**pattern2];
+            match_all(&patterns, &[func1, func2], bindings)
+                .or_else(|| match_all(&patterns, &[func2, func1], bindings))
+        }
+        (Polygamma(_, order1), Polygamma(_, order2)) if order1 != order2 => None,
+        (Piecewise(branches1, _), Piecewise(branches2, _))
+            if !branches1
+                .iter()
+                .map(|(condition, _)| condition.relation)
+                .eq(branches2.iter().map(|(condition, _)| condition.relation)) =>
+        {
+            None
+        }
+        // constants are compared by value, so that 0.5 matches 1/2
+        (Con(_) | Rat(_), Con(_) | Rat(_)) => (pattern == function).then(|| bindings.clone()),
+        _ if discriminant(pattern) == discriminant(function) => {
+            match_all(&pattern.children(), &function.children(), bindings)
+        }
+        _ => None,
+    }
+}
+
+fn match_all(
+    patterns: &[&Elementary],
+    functions: &[&Elementary],
+    bindings: &Bindings,
+) -> Option<Bindings> {
+    patterns
+        .iter()
+        .zip(functions)
+        .try_fold(bindings.clone(), |bindings, (pattern, function)| {
+            match_pattern(pattern, function, &bindings)
+        })
+}
+
+fn substitute(replacement: &Elementary, bindings: &Bindings) -> Elementary {
+    match replacement {
+        Wildcard(name) => bindings[name].clone(),
+        _ => replacement.map_children(|func| substitute(func, bindings)),
+    }
+}
@@ -1,2 +1,313 @@
+use std::{
+    f64::consts::{FRAC_1_SQRT_2, PI},
+    sync::Arc,
+};
 
+use crate::{
+    utils::rational::Number,
+    Elementary::{self, *},
+    Error, Polynomial,
+};
 
+use super::rewrite::{gather_factors, simplify_with};
+
+// The simplification rewrites the expression using the following rules (see rewrite.rs):
+//
+// 1. trig functions evaluated at rational multiples of π are replaced by their exact values
+// 2. Pythagorean identities: sin² + cos² = 1, 1 + tan² = sec², 1 + cot² = csc² (and rearranged)
+// 3. reciprocals: 1/cos = sec, 1/sin = csc, 1/tan = cot (and vice versa), sin/cos = tan,
+//    cos/sin = cot
+// 4. double angle: sin(u) * cos(u) = sin(2u)/2, cos²(u) - sin²(u) = cos(2u)
+// 5. sum-to-product: sin(a) ± sin(b) and cos(a) ± cos(b) for polynomial arguments
+// 6. inverse hyperbolic functions: sinh(asinh(u)) = u, cosh(acosh(u)) = u, tanh(atanh(u)) = u
+
+// a constant is considered to be a multiple of π/12 if it is this close to one
+const MULTIPLE_TOLERANCE: f64 = 1e-9;
+
+pub fn simplify_trigonometric(function: &Elementary) -> Result<Elementary, Error> {
+    Ok(simplify_with(
+        function,
+        &[
+            pythagorean_identity,
+            reciprocal_identity,
+            double_angle,
+            sum_to_product,
+            hyperbolic_inverse,
+        ],
+    ))
+}
+
+impl Elementary {
+    /// Rewrites every tan, sec, csc and cot in the expression in terms of sin and cos.
+    pub fn to_sin_cos(&self) -> Self {
+        let inner = |func: &Arc<Elementary>| Arc::new(func.to_sin_cos());
+        match self {
+            Tan(func) => Div(Arc::new(Sin(inner(func))), Arc::new(Cos(inner(func)))),
+            Sec(func) => Div(Arc::new(Con(1.)), Arc::new(Cos(inner(func)))),
+            Csc(func) => Div(Arc::new(Con(1.)), Arc::new(Sin(inner(func)))),
+            Cot(func) => Div(Arc::new(Cos(inner(func))), Arc::new(Sin(inner(func)))),
+            _ => self.map_children(|func| func.to_sin_cos()),
+        }
+    }
+
+    // returns the exact value of a trig function evaluated at a multiple of π/6 or π/4. Floating
+    // point evaluation would for instance yield sin(π) ≈ 1.2e-16 rather than 0
+    pub(crate) fn exact_trig_value(&self) -> Option<f64> {
+        let (Sin(func) | Cos(func) | Tan(func) | Sec(func) | Csc(func) | Cot(func)) = self else {
+            return None;
+        };
+        if !func.is_constant() {
+            return None;
+        }
+
+        // the argument as a multiple of π/12
+        let multiple = (**func).clone().call()(0.) / PI * 12.;
+        if (multiple - multiple.round()).abs() > MULTIPLE_TOLERANCE {
+            return None;
+        }
+        let multiple = multiple.round() as i64;
+
+        let sin = exact_sine(multiple)?;
+        let cos = exact_sine(multiple + 6)?;
+        let value = match self {
+            Sin(_) => sin,
+            Cos(_) => cos,
+            Tan(_) => sin / cos,
+            Sec(_) => 1. / cos,
+            Csc(_) => 1. / sin,
+            _ => cos / sin,
+        };
+
+        // poles (such as tan(π/2)) have no value
+        if value.is_finite() {
+            Some(value + 0.)
+        } else {
+            None
+        }
+    }
+}
+
+// sin(kπ/12) for the values of k where the result is a "nice" number
+fn exact_sine(multiple: i64) -> Option<f64> {
+    let k = multiple.rem_euclid(24);
+    // sin(θ + π) = -sin(θ)
+    let (sign, k) = if k >= 12 { (-1., k - 12) } else { (1., k) };
+    // sin(π - θ) = sin(θ)
+    let k = if k > 6 { 12 - k } else { k };
+
+    let value = match k {
+        0 => 0.,
+        2 => 0.5,
+        3 => FRAC_1_SQRT_2,
+        4 => 3_f64.sqrt() / 2.,
+        6 => 1.,
+        _ => return None,
+    };
+    Some(sign * value)
+}
+
+// returns the function and the argument of an expression of the type f²(u)
+fn squared(function: &Elementary) -> Option<(&Elementary, &Arc<Elementary>)> {
+    if let Pow(base, exp) = function {
+        if **exp == Con(2.) {
+            if let Sin(arg) | Cos(arg) | Tan(arg) | Sec(arg) | Csc(arg) | Cot(arg) = &**base {
+                return Some((base, arg));
+            }
+        }
+    }
+    None
+}
+
+fn square(function: Elementary) -> Elementary {
+    Pow(Arc::new(function), Arc::new(Con(2.)))
+}
+
+fn pythagorean_identity(function: &Elementary) -> Option<Elementary> {
+    match function {
+        Add(func1, func2) => {
+            for (term1, term2) in [(func1, func2), (func2, func1)] {
+                // sin²(u) + cos²(u) = 1
+                if let (Some((Sin(arg1), _)), Some((Cos(arg2), _))) =
+                    (squared(term1), squared(term2))
+                {
+                    if arg1 == arg2 {
+                        return Some(Con(1.));
+                    }
+                }
+                // 1 + tan²(u) = sec²(u), 1 + cot²(u) = csc²(u)
+                if **term1 == Con(1.) {
+                    match squared(term2) {
+                        Some((Tan(arg), _)) => return Some(square(Sec(arg.clone()))),
+                        Some((Cot(arg), _)) => return Some(square(Csc(arg.clone()))),
+                        _ => {}
+                    }
+                }
+            }
+            None
+        }
+        Sub(func1, func2) => {
+            if **func1 == Con(1.) {
+                // 1 - sin²(u) = cos²(u), 1 - cos²(u) = sin²(u)
+                return match squared(func2) {
+                    Some((Sin(arg), _)) => Some(square(Cos(arg.clone()))),
+                    Some((Cos(arg), _)) => Some(square(Sin(arg.clone()))),
+                    _ => None,
+                };
+            }
+            if **func2 == Con(1.) {
+                // sec²(u) - 1 = tan²(u), csc²(u) - 1 = cot²(u)
+                return match squared(func1) {
+                    Some((Sec(arg), _)) => Some(square(Tan(arg.clone()))),
+                    Some((Csc(arg), _)) => Some(square(Cot(arg.clone()))),
+                    _ => None,
+                };
+            }
+            None
+        }
+        _ => None,
+    }
+}
+
+fn reciprocal_identity(function: &Elementary) -> Option<Elementary> {
+    let Div(numerator, denominator) = function else {
+        return None;
+    };
+
+    match (&**numerator, &**denominator) {
+        (Sin(arg1), Cos(arg2)) if arg1 == arg2 => Some(Tan(arg1.clone())),
+        (Cos(arg1), Sin(arg2)) if arg1 == arg2 => Some(Cot(arg1.clone())),
+        // c/f^n(u) = c * g^n(u) where g is the reciprocal of f
+        (Con(_) | Rat(_), Pow(base, exp)) if exp.as_number().is_some() => {
+            let reciprocal = reciprocal(base)?;
+            Some((**numerator).clone() * Pow(Arc::new(reciprocal), exp.clone()))
+        }
+        (Con(_) | Rat(_), denominator) => Some((**numerator).clone() * reciprocal(denominator)?),
+        _ => None,
+    }
+}
+
+// the compositions hold wherever the inverse is defined
+fn hyperbolic_inverse(function: &Elementary) -> Option<Elementary> {
+    match function {
+        Sinh(arg) => match &**arg {
+            Asinh(func) => Some((**func).clone()),
+            _ => None,
+        },
+        Cosh(arg) => match &**arg {
+            Acosh(func) => Some((**func).clone()),
+            _ => None,
+        },
+        Tanh(arg) => match &**arg {
+            Atanh(func) => Some((**func).clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn reciprocal(function: &Elementary) -> Option<Elementary> {
+    Some(match function {
+        Sin(arg) => Csc(arg.clone()),
+        Cos(arg) => Sec(arg.clone()),
+        Tan(arg) => Cot(arg.clone()),
+        Sec(arg) => Cos(arg.clone()),
+        Csc(arg) => Sin(arg.clone()),
+        Cot(arg) => Tan(arg.clone()),
+        _ => return None,
+    })
+}
+
+// sin(u) * cos(u) = sin(2u)/2 and cos²(u) - sin²(u) = cos(2u)
+fn double_angle(function: &Elementary) -> Option<Elementary> {
+    match function {
+        Mul(_, _) => {
+            let mut factors = Vec::new();
+            gather_factors(function, &mut factors);
+
+            // find a pair sin(u), cos(u) among the factors
+            let (sin, cos, arg) = factors.iter().enumerate().find_map(|(i, factor)| {
+                let Sin(arg) = factor else { return None };
+                let j = factors
+                    .iter()
+                    .position(|other| matches!(other, Cos(other) if other == arg))?;
+                Some((i, j, arg.clone()))
+            })?;
+
+            let mut result = Con(0.5) * Sin(Arc::new(double(&arg)));
+            for (i, factor) in factors.iter().enumerate() {
+                if i != sin && i != cos {
+                    result *= factor.clone();
+                }
+            }
+            Some(result)
+        }
+        Sub(func1, func2) => match (squared(func1), squared(func2)) {
+            (Some((Cos(arg1), _)), Some((Sin(arg2), _))) if arg1 == arg2 => {
+                Some(Cos(Arc::new(double(arg1))))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn double(arg: &Elementary) -> Elementary {
+    match arg {
+        Mul(func1, func2) => match (func1.as_number(), func2.as_number()) {
+            (Some(numb), _) | (_, Some(numb)) => {
+                let other = if func1.as_number().is_some() {
+                    func2
+                } else {
+                    func1
+                };
+                Mul(
+                    Arc::new(Elementary::from_number(Number::from(2.) * numb)),
+                    other.clone(),
+                )
+            }
+            _ => Mul(Arc::new(Con(2.)), Arc::new(arg.clone())),
+        },
+        _ => Mul(Arc::new(Con(2.)), Arc::new(arg.clone())),
+    }
+}
+
+// sin(a) + sin(b) = 2sin((a + b)/2)cos((a - b)/2)
+// sin(a) - sin(b) = 2cos((a + b)/2)sin((a - b)/2)
+// cos(a) + cos(b) = 2cos((a + b)/2)cos((a - b)/2)
+// cos(a) - cos(b) = -2sin((a + b)/2)sin((a - b)/2)
+fn sum_to_product(function: &Elementary) -> Option<Elementary> {
+    let (func1, func2, sign) = match function {
+        Add(func1, func2) => (func1, func2, 1.),
+        Sub(func1, func2) => (func1, func2, -1.),
+        _ => return None,
+    };
+
+    let (is_sin, a, b) = match (&**func1, &**func2) {
+        (Sin(a), Sin(b)) => (true, a, b),
+        (Cos(a), Cos(b)) => (false, a, b),
+        _ => return None,
+    };
+    if a == b {
+        return None;
+    }
+
+    // the new arguments are only tidy if the old ones are polynomials
+    let mut a = Polynomial::try_from(&**a).ok()?;
+    let mut b = Polynomial::try_from(&**b).ok()?;
+    // swap the terms so that (a - b)/2 has a positive leading coefficient, which changes the sign
+    // of a difference
+    let mut coefficient = 2.;
+    if (a.clone() - b.clone()).leading_coefficient() < 0. {
+        std::mem::swap(&mut a, &mut b);
+        coefficient *= sign;
+    }
+    let half_sum = Arc::new(Elementary::from((a.clone() + b.clone()) * 0.5));
+    let half_difference = Arc::new(Elementary::from((a - b) * 0.5));
+
+    Some(match (is_sin, sign > 0.) {
+        (true, true) => Con(coefficient) * Sin(half_sum) * Cos(half_difference),
+        (true, false) => Con(coefficient) * Cos(half_sum) * Sin(half_difference),
+        (false, true) => Con(coefficient) * Cos(half_sum) * Cos(half_difference),
+        (false, false) => Con(-coefficient) * Sin(half_sum) * Sin(half_difference),
+    })
+}
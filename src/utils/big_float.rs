@@ -0,0 +1,958 @@
+use std::{
+    cmp::Ordering,
+    f64::consts::LOG2_10,
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{BigUint, Constant, Error};
+
+// the number of bits kept beyond the requested number of decimal digits, to absorb the rounding
+// errors of the operations
+const GUARD_BITS: u64 = 64;
+
+/// An arbitrary-precision floating point number.
+///
+/// The number is stored as ±m * 2^e, where the mantissa m is a [BigUint](crate::BigUint) that is
+/// rounded to the precision of the number. The precision is given in decimal digits, and results
+/// of operations on two numbers have the larger precision of the two.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::BigFloat;
+/// let two = BigFloat::from_integer(2, 50);
+///
+/// assert_eq!(
+///     two.sqrt().unwrap().to_string(),
+///     "1.4142135623730950488016887242096980785696718753769"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct BigFloat {
+    negative: bool,
+    mantissa: BigUint,
+    exponent: i64,
+    digits: usize,
+}
+
+impl BigFloat {
+    pub fn zero(digits: usize) -> Self {
+        Self {
+            negative: false,
+            mantissa: BigUint::zero(),
+            exponent: 0,
+            digits,
+        }
+    }
+
+    pub fn one(digits: usize) -> Self {
+        Self::from_integer(1, digits)
+    }
+
+    pub fn from_integer(value: i128, digits: usize) -> Self {
+        Self {
+            negative: value < 0,
+            mantissa: BigUint::from(value.unsigned_abs()),
+            exponent: 0,
+            digits,
+        }
+        .normalize()
+    }
+
+    /// Converts the float exactly. Returns an [Error::InputError](crate::Error) for infinite and
+    /// NaN values.
+    pub fn from_f64(value: f64, digits: usize) -> Result<Self, Error> {
+        if !value.is_finite() {
+            return Err(Error::InputError(format!(
+                "{value} can't be represented by a BigFloat"
+            )));
+        }
+        let bits = value.to_bits();
+        let biased_exponent = ((bits >> 52) & 0x7FF) as i64;
+        let fraction = bits & ((1 << 52) - 1);
+        let (mantissa, exponent) = if biased_exponent == 0 {
+            // subnormal numbers
+            (fraction, -1074)
+        } else {
+            (fraction | 1 << 52, biased_exponent - 1075)
+        };
+        Ok(Self {
+            negative: value.is_sign_negative(),
+            mantissa: BigUint::from(mantissa),
+            exponent,
+            digits,
+        }
+        .normalize())
+    }
+
+    /// Parses a decimal number such as "-12.5" or "6.02e23".
+    pub fn from_decimal(string: &str, digits: usize) -> Result<Self, Error> {
+        let error = || Error::ParseError(format!("'{string}' is not a decimal number"));
+        let (number, exponent) = match string.to_lowercase().split_once('e') {
+            Some((number, exponent)) => (
+                number.to_string(),
+                exponent.parse::<i64>().map_err(|_| error())?,
+            ),
+            None => (string.to_string(), 0),
+        };
+        let (negative, number) = match number.strip_prefix('-') {
+            Some(number) => (true, number),
+            None => (false, number.strip_prefix('+').unwrap_or(&number)),
+        };
+        let (integer, fraction) = number.split_once('.').unwrap_or((number, ""));
+        if integer.is_empty() && fraction.is_empty()
+            || !integer
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return Err(error());
+        }
+
+        let mut mantissa = BigUint::zero();
+        for digit in integer.chars().chain(fraction.chars()) {
+            mantissa = &mantissa.mul_small(10) + &BigUint::from(digit as u64 - '0' as u64);
+        }
+        let value = Self {
+            negative,
+            mantissa,
+            exponent: 0,
+            digits,
+        };
+        let exponent = exponent - fraction.len() as i64;
+        let ten = Self::from_integer(10, digits);
+        if exponent >= 0 {
+            Ok((value * ten.powi(exponent)).normalize())
+        } else {
+            Ok(value.normalize() / ten.powi(-exponent))
+        }
+    }
+
+    /// The value of the [Constant](crate::Constant) to the provided number of digits.
+    ///
+    /// Bernstein's constant has no known efficient algorithm, and is only available to the 20
+    /// digits it is known to in this crate.
+    pub fn constant(constant: Constant, digits: usize) -> Result<Self, Error> {
+        let work = digits + 10;
+        let value = match constant {
+            Constant::Pi => Self::pi(work),
+            Constant::E => Self::one(work).exp()?,
+            Constant::Tau => Self::pi(work).mul_pow2(1),
+            Constant::GoldenRatio => {
+                (Self::from_integer(5, work).sqrt()? + Self::one(work)).mul_pow2(-1)
+            }
+            Constant::SilverRatio => Self::from_integer(2, work).sqrt()? + Self::one(work),
+            Constant::SupergoldenRatio => Self::supergolden_ratio(work),
+            Constant::EulerMascheroni => Self::euler_mascheroni(work)?,
+            Constant::Bernstein if digits <= 20 => {
+                Self::from_decimal("0.28016949902386913303", work)?
+            }
+            Constant::Bernstein => {
+                return Err(Error::InputError(String::from(
+                    "Bernstein's constant is only known to 20 digits",
+                )))
+            }
+        };
+        Ok(value.with_digits(digits))
+    }
+
+    /// The precision of the number in decimal digits.
+    pub fn digits(&self) -> usize {
+        self.digits
+    }
+
+    /// Rounds the number to the provided number of decimal digits, or extends its precision.
+    pub fn with_digits(mut self, digits: usize) -> Self {
+        self.digits = digits;
+        self.normalize()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.exponent >= 0 || (0..(-self.exponent) as u64).all(|bit| !self.mantissa.bit(bit))
+    }
+
+    pub fn abs(mut self) -> Self {
+        self.negative = false;
+        self
+    }
+
+    /// Converts the number into the nearest f64.
+    pub fn to_f64(&self) -> f64 {
+        let excess = self.mantissa.bits().saturating_sub(64);
+        let mut value = self.mantissa.shr(excess).to_f64();
+        // scale by 2^exponent in steps that can't overflow on their own
+        let mut exponent = self.exponent + excess as i64;
+        while exponent != 0 && value != 0. && value.is_finite() {
+            let step = exponent.clamp(-1000, 1000);
+            value *= 2_f64.powi(step as i32);
+            exponent -= step;
+        }
+        if self.negative {
+            -value
+        } else {
+            value
+        }
+    }
+
+    // the number of bits that the mantissa is rounded to
+    fn bits(&self) -> u64 {
+        (self.digits as f64 * LOG2_10).ceil() as u64 + GUARD_BITS
+    }
+
+    // the binary exponent of the most significant bit plus one, i.e. |self| is in
+    // [2^(magnitude - 1), 2^magnitude)
+    fn magnitude(&self) -> i64 {
+        self.exponent + self.mantissa.bits() as i64
+    }
+
+    // rounds the mantissa to the precision of the number
+    fn normalize(mut self) -> Self {
+        if self.mantissa.is_zero() {
+            self.negative = false;
+            self.exponent = 0;
+            return self;
+        }
+        let bits = self.bits();
+        let length = self.mantissa.bits();
+        if length > bits {
+            let excess = length - bits;
+            let round_up = self.mantissa.bit(excess - 1);
+            self.mantissa = self.mantissa.shr(excess);
+            if round_up {
+                self.mantissa = &self.mantissa + &BigUint::one();
+            }
+            self.exponent += excess as i64;
+        }
+        self
+    }
+
+    // multiplies the number by 2^power
+    pub(crate) fn mul_pow2(mut self, power: i64) -> Self {
+        if !self.is_zero() {
+            self.exponent += power;
+        }
+        self
+    }
+
+    pub(crate) fn mul_small(mut self, factor: u32) -> Self {
+        self.mantissa = self.mantissa.mul_small(factor);
+        self.normalize()
+    }
+
+    pub(crate) fn div_small(mut self, divisor: u32) -> Self {
+        // shift the mantissa first so that the quotient keeps its precision
+        let shift = (self.bits() + 34).saturating_sub(self.mantissa.bits());
+        self.mantissa = self.mantissa.shl(shift).div_rem_small(divisor).0;
+        self.exponent -= shift as i64;
+        self.normalize()
+    }
+
+    // the nearest integer, as its sign and magnitude
+    fn round_integer(&self) -> (bool, BigUint) {
+        let magnitude = if self.exponent >= 0 {
+            self.mantissa.shl(self.exponent as u64)
+        } else {
+            let shift = (-self.exponent) as u64;
+            (&self.mantissa + &BigUint::one().shl(shift - 1)).shr(shift)
+        };
+        (self.negative && !magnitude.is_zero(), magnitude)
+    }
+
+    fn from_big_uint(negative: bool, magnitude: BigUint, digits: usize) -> Self {
+        Self {
+            negative,
+            mantissa: magnitude,
+            exponent: 0,
+            digits,
+        }
+        .normalize()
+    }
+
+    // whether the term is negligible compared to a sum of magnitude around 2^magnitude
+    fn negligible(&self, magnitude: i64) -> bool {
+        self.is_zero() || self.magnitude() < magnitude - self.bits() as i64 - 4
+    }
+
+    /// Raises the number to an integer power.
+    pub fn powi(&self, exp: i64) -> Self {
+        let mut power = Self::one(self.digits);
+        let mut base = self.clone();
+        let mut n = exp.unsigned_abs();
+        while n > 0 {
+            if n % 2 == 1 {
+                power = power * base.clone();
+            }
+            n /= 2;
+            if n > 0 {
+                base = base.clone() * base;
+            }
+        }
+        if exp < 0 {
+            Self::one(self.digits) / power
+        } else {
+            power
+        }
+    }
+
+    /// Raises the number to the provided power. Negative numbers only have integer powers.
+    pub fn powf(&self, exp: &Self) -> Result<Self, Error> {
+        if exp.is_integer() && exp.magnitude() < 63 {
+            let (negative, magnitude) = exp.round_integer();
+            let power = magnitude.to_f64() as i64;
+            if self.is_zero() && negative {
+                return Err(Error::InputError(String::from(
+                    "0 can't be raised to a negative power",
+                )));
+            }
+            return Ok(self.powi(if negative { -power } else { power }));
+        }
+        if self.is_zero() && !exp.negative {
+            return Ok(Self::zero(self.digits.max(exp.digits)));
+        }
+        if self.negative || self.is_zero() {
+            return Err(Error::InputError(format!(
+                "{self} can't be raised to the power of {exp}"
+            )));
+        }
+        (exp.clone() * self.ln()?).exp()
+    }
+
+    pub fn sqrt(&self) -> Result<Self, Error> {
+        if self.negative {
+            return Err(Error::InputError(format!(
+                "The square root of {self} is not real"
+            )));
+        }
+        if self.is_zero() {
+            return Ok(self.clone());
+        }
+        let work = self.digits + 10;
+        // self = m * 2^(2k) with m in [1/4, 1)
+        let half_magnitude = self.magnitude().div_euclid(2);
+        let m = self.clone().with_digits(work).mul_pow2(-2 * half_magnitude);
+
+        // Newton's method doubles the number of correct bits in every iteration
+        let mut root = Self::from_f64(m.to_f64().sqrt(), work)?;
+        for _ in 0..iterations(self.bits(), 2) {
+            root = (root.clone() + m.clone() / root).mul_pow2(-1);
+        }
+        Ok(root.mul_pow2(half_magnitude).with_digits(self.digits))
+    }
+
+    pub fn exp(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Ok(Self::one(self.digits));
+        }
+        if self.to_f64().abs() > 1e15 {
+            return Err(Error::InputError(format!(
+                "e^{self} is too large to be represented"
+            )));
+        }
+        // e^x = 2^k * e^r, where r = x - k ln(2) and |r| <= ln(2)/2
+        let k = (self.to_f64() / std::f64::consts::LN_2).round() as i64;
+        // e^r = (e^(r/2^s))^2^s, where the squaring loses about s bits
+        let halvings = (self.bits() as f64).sqrt() as i64 / 2;
+        let work = self.digits + 10 + halvings as usize / 3 + digits_of(k);
+
+        let reduced =
+            self.clone().with_digits(work) - Self::ln2(work) * Self::from_integer(k as i128, work);
+        let reduced = reduced.mul_pow2(-halvings);
+
+        let mut sum = Self::one(work);
+        let mut term = Self::one(work);
+        let mut n = 1;
+        loop {
+            term = (term * reduced.clone()).div_small(n);
+            if term.negligible(1) {
+                break;
+            }
+            sum = sum + term.clone();
+            n += 1;
+        }
+        for _ in 0..halvings {
+            sum = sum.clone() * sum;
+        }
+        Ok(sum.mul_pow2(k).with_digits(self.digits))
+    }
+
+    /// The natural logarithm of the number.
+    pub fn ln(&self) -> Result<Self, Error> {
+        if self.negative || self.is_zero() {
+            return Err(Error::InputError(format!(
+                "The logarithm of {self} is not defined"
+            )));
+        }
+        let work = self.digits + 10;
+        // self = m * 2^k with m in [1/2, 1)
+        let k = self.magnitude();
+        let m = self.clone().with_digits(work).mul_pow2(-k);
+
+        // Halley's method for e^y = m triples the number of correct bits in every iteration
+        let mut log = Self::from_f64(m.to_f64().ln(), work)?;
+        for _ in 0..iterations(self.bits(), 3) {
+            let exp = log.exp()?;
+            log = log + (m.clone() - exp.clone()).mul_pow2(1) / (m.clone() + exp);
+        }
+        Ok((log + Self::ln2(work) * Self::from_integer(k as i128, work)).with_digits(self.digits))
+    }
+
+    /// The sine and cosine of the number.
+    pub fn sin_cos(&self) -> (Self, Self) {
+        if self.is_zero() {
+            return (Self::zero(self.digits), Self::one(self.digits));
+        }
+        // the reduction modulo π/2 needs as many extra digits as the integer part of the number has
+        let work = self.digits + 10 + (self.magnitude().max(0) as f64 / LOG2_10) as usize;
+        let half_pi = Self::pi(work).mul_pow2(-1);
+        let x = self.clone().with_digits(work);
+        let (negative, k) = (x.clone() / half_pi.clone()).round_integer();
+        let reduced = x - half_pi * Self::from_big_uint(negative, k.clone(), work);
+        let quadrant = match k.div_rem_small(4).1 {
+            quadrant if negative => (4 - quadrant) % 4,
+            quadrant => quadrant,
+        };
+
+        // the Taylor series of sin and cos
+        let square = reduced.clone() * reduced.clone();
+        let (mut sin, mut cos) = (reduced.clone(), Self::one(work));
+        let (mut sin_term, mut cos_term) = (reduced, Self::one(work));
+        let mut n = 1;
+        loop {
+            cos_term = -(cos_term * square.clone()).div_small((2 * n - 1) * (2 * n));
+            sin_term = -(sin_term * square.clone()).div_small((2 * n) * (2 * n + 1));
+            if cos_term.negligible(0) {
+                break;
+            }
+            cos = cos + cos_term.clone();
+            sin = sin + sin_term.clone();
+            n += 1;
+        }
+
+        let (sin, cos) = match quadrant {
+            0 => (sin, cos),
+            1 => (cos, -sin),
+            2 => (-sin, -cos),
+            _ => (-cos, sin),
+        };
+        (sin.with_digits(self.digits), cos.with_digits(self.digits))
+    }
+
+    pub fn sin(&self) -> Self {
+        self.sin_cos().0
+    }
+
+    pub fn cos(&self) -> Self {
+        self.sin_cos().1
+    }
+
+    pub fn tan(&self) -> Result<Self, Error> {
+        let (sin, cos) = self.clone().with_digits(self.digits + 10).sin_cos();
+        checked_div(sin, cos).map(|tan| tan.with_digits(self.digits))
+    }
+
+    pub fn atan(&self) -> Self {
+        if self.is_zero() {
+            return self.clone();
+        }
+        let work = self.digits + 10;
+        let one = Self::one(work);
+        let x = self.clone().with_digits(work);
+        if x.clone().abs() > one {
+            // atan(x) = ±π/2 - atan(1/x)
+            let half_pi = Self::pi(work).mul_pow2(-1);
+            let half_pi = if x.negative { -half_pi } else { half_pi };
+            return (half_pi - (one / x).atan()).with_digits(self.digits);
+        }
+
+        // atan(x) = 2 atan(x / (1 + √(1 + x²))), which is applied a few times to make the
+        // series converge faster
+        const HALVINGS: i64 = 8;
+        let mut reduced = x;
+        for _ in 0..HALVINGS {
+            let root = (one.clone() + reduced.clone() * reduced.clone())
+                .sqrt()
+                .expect("1 + x² is positive");
+            reduced = reduced / (one.clone() + root);
+        }
+
+        let square = reduced.clone() * reduced.clone();
+        let mut sum = reduced.clone();
+        let mut power = reduced.clone();
+        let mut n = 1;
+        loop {
+            power = -(power * square.clone());
+            let term = power.clone().div_small(2 * n + 1);
+            if term.negligible(reduced.magnitude()) {
+                break;
+            }
+            sum = sum + term;
+            n += 1;
+        }
+        sum.mul_pow2(HALVINGS).with_digits(self.digits)
+    }
+
+    pub fn asin(&self) -> Result<Self, Error> {
+        let work = self.digits + 10;
+        let one = Self::one(work);
+        let x = self.clone().with_digits(work);
+        match x.clone().abs().partial_cmp(&one) {
+            Some(Ordering::Greater) => {
+                Err(Error::InputError(format!("asin({self}) is not defined")))
+            }
+            Some(Ordering::Equal) => {
+                let half_pi = Self::pi(self.digits).mul_pow2(-1);
+                Ok(if self.negative { -half_pi } else { half_pi })
+            }
+            // asin(x) = atan(x / √(1 - x²))
+            _ => Ok((x.clone() / (one - x.clone() * x).sqrt()?)
+                .atan()
+                .with_digits(self.digits)),
+        }
+    }
+
+    pub fn acos(&self) -> Result<Self, Error> {
+        let asin = self.clone().with_digits(self.digits + 10).asin()?;
+        Ok((Self::pi(self.digits + 10).mul_pow2(-1) - asin).with_digits(self.digits))
+    }
+
+    pub fn sinh(&self) -> Result<Self, Error> {
+        let work = self.digits + 10;
+        let x = self.clone().with_digits(work);
+        if x.magnitude() <= 0 {
+            // the series avoids the cancellation in (e^x - e^-x)/2 for small x
+            let square = x.clone() * x.clone();
+            let mut sum = x.clone();
+            let mut term = x.clone();
+            let mut n = 1;
+            loop {
+                term = (term * square.clone()).div_small((2 * n) * (2 * n + 1));
+                if term.negligible(x.magnitude()) {
+                    break;
+                }
+                sum = sum + term.clone();
+                n += 1;
+            }
+            return Ok(sum.with_digits(self.digits));
+        }
+        let exp = x.exp()?;
+        Ok(((exp.clone() - Self::one(work) / exp).mul_pow2(-1)).with_digits(self.digits))
+    }
+
+    pub fn cosh(&self) -> Result<Self, Error> {
+        let exp = self.clone().with_digits(self.digits + 10).exp()?;
+        Ok(
+            ((exp.clone() + Self::one(self.digits + 10) / exp).mul_pow2(-1))
+                .with_digits(self.digits),
+        )
+    }
+
+    pub fn tanh(&self) -> Result<Self, Error> {
+        let x = self.clone().with_digits(self.digits + 10);
+        if x.to_f64().abs() > 2. * self.bits() as f64 {
+            // tanh(x) = ±1 to the precision of the number
+            return Ok(Self::from_integer(
+                if self.negative { -1 } else { 1 },
+                self.digits,
+            ));
+        }
+        Ok((x.sinh()? / x.cosh()?).with_digits(self.digits))
+    }
+
+    pub fn asinh(&self) -> Result<Self, Error> {
+        // the logarithm of a number close to 1 loses the digits of small arguments
+        let work =
+            self.digits + 10 + (self.magnitude().min(0).unsigned_abs() as f64 / LOG2_10) as usize;
+        let x = self.clone().with_digits(work).abs();
+        // asinh(x) = ln(x + √(x² + 1)), which is odd
+        let asinh = (x.clone() + (x.clone() * x + Self::one(work)).sqrt()?).ln()?;
+        Ok(if self.negative { -asinh } else { asinh }.with_digits(self.digits))
+    }
+
+    pub fn acosh(&self) -> Result<Self, Error> {
+        let work = self.digits + 10;
+        let x = self.clone().with_digits(work);
+        if x < Self::one(work) {
+            return Err(Error::InputError(format!("acosh({self}) is not defined")));
+        }
+        // acosh(x) = ln(x + √(x² - 1))
+        Ok((x.clone() + (x.clone() * x - Self::one(work)).sqrt()?)
+            .ln()?
+            .with_digits(self.digits))
+    }
+
+    pub fn atanh(&self) -> Result<Self, Error> {
+        let work =
+            self.digits + 10 + (self.magnitude().min(0).unsigned_abs() as f64 / LOG2_10) as usize;
+        let one = Self::one(work);
+        let x = self.clone().with_digits(work);
+        if x.clone().abs() >= one {
+            return Err(Error::InputError(format!("atanh({self}) is not defined")));
+        }
+        // atanh(x) = ln((1 + x) / (1 - x)) / 2
+        Ok(((one.clone() + x.clone()) / (one - x))
+            .ln()?
+            .mul_pow2(-1)
+            .with_digits(self.digits))
+    }
+
+    /// The real cube root of the number, which is negative for negative numbers.
+    pub fn cbrt(&self) -> Result<Self, Error> {
+        if self.is_zero() {
+            return Ok(self.clone());
+        }
+        let work = self.digits + 10;
+        let cbrt = (self.clone().with_digits(work).abs().ln()?.div_small(3)).exp()?;
+        Ok(if self.negative { -cbrt } else { cbrt }.with_digits(self.digits))
+    }
+
+    /// The largest integer less than or equal to the number.
+    pub fn floor(&self) -> Self {
+        if self.is_integer() {
+            return self.clone();
+        }
+        // the mantissa has a fractional part, so the exponent is negative
+        let magnitude = self.mantissa.shr((-self.exponent) as u64);
+        let magnitude = if self.negative {
+            &magnitude + &BigUint::one()
+        } else {
+            magnitude
+        };
+        Self::from_big_uint(self.negative, magnitude, self.digits)
+    }
+
+    /// The smallest integer greater than or equal to the number.
+    pub fn ceil(&self) -> Self {
+        -(-self.clone()).floor()
+    }
+
+    /// The gamma function, computed using [Spouge's
+    /// approximation](https://en.wikipedia.org/wiki/Spouge%27s_approximation).
+    pub fn gamma(&self) -> Result<Self, Error> {
+        if self.is_integer() && (self.negative || self.is_zero()) {
+            return Err(Error::InputError(format!(
+                "The gamma function has a pole at {self}"
+            )));
+        }
+        let work = self.digits + 10;
+        let x = self.clone().with_digits(work);
+        let one = Self::one(work);
+        if x.to_f64() < 0.5 {
+            // the reflection formula 𝜞(x) = π / (sin(πx) 𝜞(1 - x))
+            let pi = Self::pi(work);
+            let sin = (pi.clone() * x.clone()).sin();
+            return Ok((pi / (sin * (one - x).gamma()?)).with_digits(self.digits));
+        }
+
+        // the relative error of the approximation is below (2π)^-(a + 1/2)
+        let a = (self.bits() as f64 / (2. * std::f64::consts::PI).log2()).ceil() as u32 + 1;
+        // the coefficients are large and cancel each other, which loses about a log2(e) bits
+        let spouge = self.digits + 10 + (a as f64 * 1.5 / LOG2_10) as usize;
+        let z = x.with_digits(spouge) - Self::one(spouge);
+
+        // c_k = (-1)^(k - 1) (a - k)^(k - 1/2) e^(a - k) / (k - 1)!, summed from k = a - 1
+        // down to 1 so that e^(a - k) and (k - 1)! can be updated along the way
+        let e = Self::one(spouge).exp()?;
+        let mut exp = e.clone();
+        let mut factorials = vec![Self::one(spouge)];
+        for k in 1..a {
+            let factorial = factorials[k as usize - 1].clone().mul_small(k);
+            factorials.push(factorial);
+        }
+        let mut sum = Self::zero(spouge);
+        for k in (1..a).rev() {
+            let base = Self::from_integer((a - k) as i128, spouge);
+            let coefficient = base.powi(k as i64 - 1) * base.sqrt()? * exp.clone()
+                / factorials[k as usize - 1].clone();
+            let term = coefficient / (z.clone() + Self::from_integer(k as i128, spouge));
+            sum = if k % 2 == 1 { sum + term } else { sum - term };
+            exp = exp * e.clone();
+        }
+        sum = sum + Self::pi(spouge).mul_pow2(1).sqrt()?;
+
+        // (z + a)^(z + 1/2) e^-(z + a)
+        let shifted = z.clone() + Self::from_integer(a as i128, spouge);
+        let factor = ((z + Self::one(spouge).mul_pow2(-1)) * shifted.ln()? - shifted).exp()?;
+        Ok((factor * sum).with_digits(self.digits))
+    }
+
+    // π using Machin's formula π = 16 atan(1/5) - 4 atan(1/239)
+    fn pi(digits: usize) -> Self {
+        let work = digits + 10;
+        let atan_reciprocal = |n: u32| {
+            let mut power = Self::one(work).div_small(n);
+            let mut sum = power.clone();
+            let mut k = 1;
+            loop {
+                power = -power.div_small(n * n);
+                let term = power.clone().div_small(2 * k + 1);
+                if term.negligible(0) {
+                    break;
+                }
+                sum = sum + term;
+                k += 1;
+            }
+            sum
+        };
+        (atan_reciprocal(5).mul_pow2(4) - atan_reciprocal(239).mul_pow2(2)).with_digits(digits)
+    }
+
+    // ln(2) = 2 atanh(1/3) = 2 Σ 1/((2k + 1) 3^(2k + 1))
+    fn ln2(digits: usize) -> Self {
+        let work = digits + 10;
+        let mut power = Self::one(work).div_small(3);
+        let mut sum = power.clone();
+        let mut k = 1;
+        loop {
+            power = power.div_small(9);
+            let term = power.clone().div_small(2 * k + 1);
+            if term.negligible(0) {
+                break;
+            }
+            sum = sum + term;
+            k += 1;
+        }
+        sum.mul_pow2(1).with_digits(digits)
+    }
+
+    // the real root of ψ³ = ψ² + 1, using Newton's method
+    fn supergolden_ratio(digits: usize) -> Self {
+        let mut root = Self::from_f64(crate::SUPERGOLDEN_RATIO, digits).unwrap();
+        let one = Self::one(digits);
+        for _ in 0..iterations(root.bits(), 2) {
+            let square = root.clone() * root.clone();
+            let value = square.clone() * root.clone() - square.clone() - one.clone();
+            let slope = square.mul_small(3) - root.clone().mul_pow2(1);
+            root = root - value / slope;
+        }
+        root
+    }
+
+    // γ using the Brent-McMillan algorithm, with an error of about e^(-4n)
+    fn euler_mascheroni(digits: usize) -> Result<Self, Error> {
+        let n = (digits as f64 * std::f64::consts::LN_10 / 4.).ceil() as u32 + 2;
+        // the terms grow to about e^(2n) before the sums converge, so the precision is doubled
+        let work = 2 * digits + 10;
+        let square = n * n;
+
+        let mut a = -Self::from_integer(n as i128, work).ln()?;
+        let mut b = Self::one(work);
+        let (mut u, mut v) = (a.clone(), b.clone());
+        let mut k = 1;
+        loop {
+            b = b.mul_small(square).div_small(k * k);
+            a = (a.mul_small(square).div_small(k) + b.clone()).div_small(k);
+            if a.negligible(u.magnitude()) && b.negligible(v.magnitude()) {
+                break;
+            }
+            u = u + a.clone();
+            v = v + b.clone();
+            k += 1;
+        }
+        Ok((u / v).with_digits(digits))
+    }
+
+    /// Formats the number with the provided number of significant digits.
+    pub fn to_string_with_digits(&self, digits: usize) -> String {
+        if self.is_zero() {
+            return String::from("0");
+        }
+        let digits = digits.max(1);
+        let ten = BigUint::from(10_u64);
+
+        // find the decimal exponent E and the integer N = round(|self| * 10^(digits - 1 - E))
+        // with exactly the requested number of digits
+        let mut exponent = ((self.magnitude() - 1) as f64 / LOG2_10).floor() as i64;
+        let (decimal, exponent) = loop {
+            let scale = digits as i64 - 1 - exponent;
+            let mut numerator = self.mantissa.clone();
+            let mut denominator = BigUint::one();
+            if scale >= 0 {
+                numerator = &numerator * &ten.pow(scale as u32);
+            } else {
+                denominator = &denominator * &ten.pow((-scale) as u32);
+            }
+            if self.exponent >= 0 {
+                numerator = numerator.shl(self.exponent as u64);
+            } else {
+                denominator = denominator.shl((-self.exponent) as u64);
+            }
+            let rounded = &(&numerator.shl(1) + &denominator) / &denominator.shl(1);
+            let decimal = rounded.to_decimal();
+            match decimal.len().cmp(&digits) {
+                Ordering::Greater => exponent += 1,
+                Ordering::Less => exponent -= 1,
+                Ordering::Equal => break (decimal, exponent),
+            }
+        };
+
+        let sign = if self.negative { "-" } else { "" };
+        if exponent >= 0 && exponent < digits as i64 {
+            let (integer, fraction) = decimal.split_at(exponent as usize + 1);
+            if fraction.is_empty() {
+                format!("{sign}{integer}")
+            } else {
+                format!("{sign}{integer}.{fraction}")
+            }
+        } else if (-7..0).contains(&exponent) {
+            format!("{sign}0.{}{decimal}", "0".repeat((-exponent - 1) as usize))
+        } else {
+            let (first, rest) = decimal.split_at(1);
+            let rest = if rest.is_empty() {
+                String::new()
+            } else {
+                format!(".{rest}")
+            };
+            format!("{sign}{first}{rest}e{exponent}")
+        }
+    }
+}
+
+// the number of iterations needed for a method that multiplies the number of correct bits by the
+// provided order, starting from the precision of an f64
+fn iterations(bits: u64, order: u32) -> u32 {
+    let mut correct = 50.;
+    let mut iterations = 1;
+    while correct < bits as f64 {
+        correct *= order as f64;
+        iterations += 1;
+    }
+    iterations
+}
+
+// the number of decimal digits of the integer
+fn digits_of(k: i64) -> usize {
+    k.unsigned_abs()
+        .checked_ilog10()
+        .map_or(1, |log| log as usize + 1)
+}
+
+pub(crate) fn checked_div(numerator: BigFloat, denominator: BigFloat) -> Result<BigFloat, Error> {
+    if denominator.is_zero() {
+        Err(Error::InputError(String::from("Division by zero")))
+    } else {
+        Ok(numerator / denominator)
+    }
+}
+
+impl PartialEq for BigFloat {
+    fn eq(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Equal)
+    }
+}
+
+impl PartialOrd for BigFloat {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let difference = self.clone() - other.clone();
+        Some(if difference.is_zero() {
+            Ordering::Equal
+        } else if difference.negative {
+            Ordering::Less
+        } else {
+            Ordering::Greater
+        })
+    }
+}
+
+impl Add for BigFloat {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        let digits = self.digits.max(rhs.digits);
+        if rhs.is_zero() {
+            return self.with_digits(digits);
+        }
+        if self.is_zero() {
+            return rhs.with_digits(digits);
+        }
+        // an operand that is too small to affect the sum is ignored rather than shifted into place
+        let bits = (digits as f64 * LOG2_10).ceil() as i64 + GUARD_BITS as i64;
+        if self.magnitude() - rhs.magnitude() > bits + 2 {
+            return self.with_digits(digits);
+        }
+        if rhs.magnitude() - self.magnitude() > bits + 2 {
+            return rhs.with_digits(digits);
+        }
+
+        let exponent = self.exponent.min(rhs.exponent);
+        let left = self.mantissa.shl((self.exponent - exponent) as u64);
+        let right = rhs.mantissa.shl((rhs.exponent - exponent) as u64);
+        let (negative, mantissa) = if self.negative == rhs.negative {
+            (self.negative, &left + &right)
+        } else if left >= right {
+            (self.negative, &left - &right)
+        } else {
+            (rhs.negative, &right - &left)
+        };
+        Self {
+            negative,
+            mantissa,
+            exponent,
+            digits,
+        }
+        .normalize()
+    }
+}
+impl Sub for BigFloat {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Mul for BigFloat {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            negative: self.negative != rhs.negative,
+            mantissa: &self.mantissa * &rhs.mantissa,
+            exponent: self.exponent + rhs.exponent,
+            digits: self.digits.max(rhs.digits),
+        }
+        .normalize()
+    }
+}
+impl Div for BigFloat {
+    type Output = Self;
+    /// Panics if the divisor is 0.
+    fn div(self, rhs: Self) -> Self::Output {
+        assert!(!rhs.is_zero(), "Attempted to divide a BigFloat by zero");
+        let digits = self.digits.max(rhs.digits);
+        let bits = (digits as f64 * LOG2_10).ceil() as i64 + GUARD_BITS as i64;
+        // shift the dividend so that the quotient has enough bits
+        let shift = (bits + 2 + rhs.mantissa.bits() as i64 - self.mantissa.bits() as i64).max(0);
+        Self {
+            negative: self.negative != rhs.negative,
+            mantissa: &self.mantissa.shl(shift as u64) / &rhs.mantissa,
+            exponent: self.exponent - shift - rhs.exponent,
+            digits,
+        }
+        .normalize()
+    }
+}
+impl Neg for BigFloat {
+    type Output = Self;
+    fn neg(mut self) -> Self::Output {
+        if !self.is_zero() {
+            self.negative = !self.negative;
+        }
+        self
+    }
+}
+
+impl fmt::Display for BigFloat {
+    /// Displays the number with as many significant digits as its precision, or as many as
+    /// provided by the formatter (e.g. `{:.10}`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.to_string_with_digits(f.precision().unwrap_or(self.digits))
+        )
+    }
+}
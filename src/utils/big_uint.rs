@@ -0,0 +1,349 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+/// An arbitrarily large unsigned integer.
+///
+/// The integer is stored as base 2³² digits, least significant first, and is mainly used as the
+/// mantissa of [BigFloat](crate::BigFloat).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct BigUint {
+    // never has trailing (most significant) zero limbs, so that 0 is an empty vector
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn one() -> Self {
+        Self::from(1_u64)
+    }
+
+    fn from_limbs(mut limbs: Vec<u32>) -> Self {
+        while limbs.last() == Some(&0) {
+            limbs.pop();
+        }
+        Self { limbs }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    /// The number of bits needed to represent the integer, which is 0 for 0.
+    pub fn bits(&self) -> u64 {
+        match self.limbs.last() {
+            Some(last) => self.limbs.len() as u64 * 32 - last.leading_zeros() as u64,
+            None => 0,
+        }
+    }
+
+    /// Whether the bit with the provided index (counting from the least significant bit) is set.
+    pub fn bit(&self, index: u64) -> bool {
+        self.limbs
+            .get((index / 32) as usize)
+            .is_some_and(|limb| limb >> (index % 32) & 1 == 1)
+    }
+
+    /// Raises the integer to the provided power.
+    pub fn pow(&self, mut exp: u32) -> Self {
+        let mut power = Self::one();
+        let mut base = self.clone();
+        while exp > 0 {
+            if exp % 2 == 1 {
+                power = &power * &base;
+            }
+            exp /= 2;
+            if exp > 0 {
+                base = &base * &base;
+            }
+        }
+        power
+    }
+
+    /// Converts the integer into the nearest f64.
+    pub fn to_f64(&self) -> f64 {
+        self.limbs
+            .iter()
+            .rev()
+            .fold(0., |value, limb| value * 4294967296. + *limb as f64)
+    }
+
+    fn add_limbs(&self, rhs: &Self) -> Self {
+        let mut carry = 0_u64;
+        let limbs = (0..self.limbs.len().max(rhs.limbs.len()) + 1)
+            .map(|i| {
+                let sum = *self.limbs.get(i).unwrap_or(&0) as u64
+                    + *rhs.limbs.get(i).unwrap_or(&0) as u64
+                    + carry;
+                carry = sum >> 32;
+                sum as u32
+            })
+            .collect();
+        Self::from_limbs(limbs)
+    }
+
+    pub(crate) fn shl(&self, bits: u64) -> Self {
+        if self.is_zero() {
+            return Self::zero();
+        }
+        let (limb_shift, bit_shift) = ((bits / 32) as usize, (bits % 32) as u32);
+        let mut limbs = vec![0; limb_shift];
+        let mut carry = 0;
+        for limb in &self.limbs {
+            limbs.push(if bit_shift == 0 {
+                *limb
+            } else {
+                let shifted = limb << bit_shift | carry;
+                carry = limb >> (32 - bit_shift);
+                shifted
+            });
+        }
+        limbs.push(carry);
+        Self::from_limbs(limbs)
+    }
+
+    pub(crate) fn shr(&self, bits: u64) -> Self {
+        let (limb_shift, bit_shift) = ((bits / 32) as usize, (bits % 32) as u32);
+        if limb_shift >= self.limbs.len() {
+            return Self::zero();
+        }
+        let limbs = &self.limbs[limb_shift..];
+        Self::from_limbs(
+            (0..limbs.len())
+                .map(|i| {
+                    if bit_shift == 0 {
+                        limbs[i]
+                    } else {
+                        let high = limbs.get(i + 1).map_or(0, |limb| limb << (32 - bit_shift));
+                        limbs[i] >> bit_shift | high
+                    }
+                })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn mul_small(&self, factor: u32) -> Self {
+        let mut carry = 0_u64;
+        let mut limbs: Vec<u32> = self
+            .limbs
+            .iter()
+            .map(|limb| {
+                let product = *limb as u64 * factor as u64 + carry;
+                carry = product >> 32;
+                product as u32
+            })
+            .collect();
+        limbs.push(carry as u32);
+        Self::from_limbs(limbs)
+    }
+
+    pub(crate) fn div_rem_small(&self, divisor: u32) -> (Self, u32) {
+        let mut remainder = 0_u64;
+        let mut limbs = vec![0; self.limbs.len()];
+        for (i, limb) in self.limbs.iter().enumerate().rev() {
+            let dividend = remainder << 32 | *limb as u64;
+            limbs[i] = (dividend / divisor as u64) as u32;
+            remainder = dividend % divisor as u64;
+        }
+        (Self::from_limbs(limbs), remainder as u32)
+    }
+
+    /// Returns the quotient and remainder of the division, using Knuth's algorithm D.
+    ///
+    /// Panics if the divisor is 0.
+    pub fn div_rem(&self, divisor: &Self) -> (Self, Self) {
+        assert!(!divisor.is_zero(), "Attempted to divide a BigUint by zero");
+        if self < divisor {
+            return (Self::zero(), self.clone());
+        }
+        if divisor.limbs.len() == 1 {
+            let (quotient, remainder) = self.div_rem_small(divisor.limbs[0]);
+            return (quotient, Self::from(remainder as u64));
+        }
+
+        // normalize so that the most significant bit of the divisor is set
+        let shift = divisor.limbs.last().unwrap().leading_zeros() as u64;
+        let divisor = divisor.shl(shift).limbs;
+        let mut dividend = self.shl(shift).limbs;
+        dividend.resize(self.limbs.len() + 1, 0);
+        let n = divisor.len();
+        let m = dividend.len() - 1;
+        let mut quotient = vec![0_u32; m - n + 1];
+
+        for j in (0..=m - n).rev() {
+            let numerator = (dividend[j + n] as u64) << 32 | dividend[j + n - 1] as u64;
+            let mut estimate = numerator / divisor[n - 1] as u64;
+            let mut remainder = numerator % divisor[n - 1] as u64;
+            while estimate >= 1 << 32
+                || estimate * divisor[n - 2] as u64 > (remainder << 32 | dividend[j + n - 2] as u64)
+            {
+                estimate -= 1;
+                remainder += divisor[n - 1] as u64;
+                if remainder >= 1 << 32 {
+                    break;
+                }
+            }
+
+            // subtract estimate * divisor from the current part of the dividend
+            let mut borrow = 0_i64;
+            for i in 0..n {
+                let product = estimate * divisor[i] as u64;
+                let difference = dividend[i + j] as i64 - borrow - (product & 0xFFFF_FFFF) as i64;
+                dividend[i + j] = difference as u32;
+                borrow = (product >> 32) as i64 - (difference >> 32);
+            }
+            let difference = dividend[j + n] as i64 - borrow;
+            dividend[j + n] = difference as u32;
+
+            quotient[j] = estimate as u32;
+            if difference < 0 {
+                // the estimate was one too large, so the divisor is added back
+                quotient[j] = quotient[j].wrapping_sub(1);
+                let mut carry = 0_u64;
+                for i in 0..n {
+                    let sum = dividend[i + j] as u64 + divisor[i] as u64 + carry;
+                    dividend[i + j] = sum as u32;
+                    carry = sum >> 32;
+                }
+                dividend[j + n] = dividend[j + n].wrapping_add(carry as u32);
+            }
+        }
+
+        dividend.truncate(n);
+        (
+            Self::from_limbs(quotient),
+            Self::from_limbs(dividend).shr(shift),
+        )
+    }
+
+    // the decimal digits of the integer
+    pub(crate) fn to_decimal(&self) -> String {
+        if self.is_zero() {
+            return String::from("0");
+        }
+        // split off 9 decimal digits at a time
+        let mut chunks = Vec::new();
+        let mut rest = self.clone();
+        while !rest.is_zero() {
+            let (quotient, remainder) = rest.div_rem_small(1_000_000_000);
+            chunks.push(remainder);
+            rest = quotient;
+        }
+        let mut decimal = chunks.pop().unwrap().to_string();
+        for chunk in chunks.iter().rev() {
+            decimal.push_str(&format!("{chunk:09}"));
+        }
+        decimal
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> Self {
+        Self::from_limbs(vec![value as u32, (value >> 32) as u32])
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(value: u128) -> Self {
+        Self::from_limbs((0..4).map(|i| (value >> (32 * i)) as u32).collect())
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.add_limbs(rhs)
+    }
+}
+impl Sub for &BigUint {
+    type Output = BigUint;
+    /// Panics if the result would be negative.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(*self >= *rhs, "Attempted to subtract a larger BigUint");
+        let mut borrow = 0_i64;
+        let limbs = self
+            .limbs
+            .iter()
+            .enumerate()
+            .map(|(i, limb)| {
+                let mut difference = *limb as i64 - *rhs.limbs.get(i).unwrap_or(&0) as i64 - borrow;
+                borrow = 0;
+                if difference < 0 {
+                    difference += 1 << 32;
+                    borrow = 1;
+                }
+                difference as u32
+            })
+            .collect();
+        BigUint::from_limbs(limbs)
+    }
+}
+impl Mul for &BigUint {
+    type Output = BigUint;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+        let mut limbs = vec![0_u32; self.limbs.len() + rhs.limbs.len()];
+        for (i, a) in self.limbs.iter().enumerate() {
+            let mut carry = 0_u64;
+            for (j, b) in rhs.limbs.iter().enumerate() {
+                let product = *a as u64 * *b as u64 + limbs[i + j] as u64 + carry;
+                limbs[i + j] = product as u32;
+                carry = product >> 32;
+            }
+            limbs[i + rhs.limbs.len()] = carry as u32;
+        }
+        BigUint::from_limbs(limbs)
+    }
+}
+impl Div for &BigUint {
+    type Output = BigUint;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).0
+    }
+}
+impl Rem for &BigUint {
+    type Output = BigUint;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self.div_rem(rhs).1
+    }
+}
+
+/// implement the operations for owned integers by forwarding to the borrowed ones
+macro_rules! impl_owned_operations {
+    ($($trait:ident, $method:ident);+) => {
+        $(impl $trait for BigUint {
+            type Output = BigUint;
+            fn $method(self, rhs: Self) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        })*
+    };
+}
+impl_owned_operations!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem);
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_decimal())
+    }
+}
@@ -1,3 +1,5 @@
+use std::fmt;
+
 /// Euler's constant (sometimes called the Euler-Mascheroni constant) usually denoted as 𝛄.
 /// See [this article](https://en.wikipedia.org/wiki/Euler%27s_constant) for further information.
 pub const EULER_MASCHERONI: f64 = 0.5772156649015328606065120900824024310421_f64;
@@ -21,3 +23,111 @@ pub const SUPERGOLDEN_RATIO: f64 = 1.46557123187676802665_f64;
 /// Usually denoted as β, Bernstein's constant is defined as the limit lim(n➝ ∞)2nE₂ₙ(f) where Eₙ(f)is the error to the best uniform approximation to a real funciton f(x) on the interval [-1, 1] by real polynomials of no more than degree n.
 /// See [this article](https://en.wikipedia.org/wiki/Bernstein%27s_constant) for further information
 pub const BERNSTEINS_CONSTANT: f64 = 0.28016949902386913303_f64;
+
+/// A mathematical constant that is kept symbolic in [Elementary](crate::Elementary) functions, so
+/// that for instance ln(e) simplifies to exactly 1 and the constant is displayed by its name.
+///
+/// The constants are parsed from their names (such as "pi" or "phi") as well as their symbols
+/// (such as "π" or "φ").
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{Constant, Elementary};
+/// let function = Elementary::from("2π");
+/// assert!(matches!(function.clone().simplify().unwrap(), Elementary::Mul(_, _)));
+/// assert_eq!(Constant::GoldenRatio.to_string(), "φ");
+/// assert_eq!(function.call()(0.), 2. * std::f64::consts::PI);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constant {
+    /// The ratio between a circle's circumference and its diameter, π.
+    Pi,
+    /// Euler's number e, the base of the natural logarithm.
+    E,
+    /// The circle constant τ = 2π.
+    Tau,
+    /// The golden ratio φ.
+    GoldenRatio,
+    /// The silver ratio δₛ.
+    SilverRatio,
+    /// The supergolden ratio ψ.
+    SupergoldenRatio,
+    /// The Euler-Mascheroni constant γ.
+    EulerMascheroni,
+    /// Bernstein's constant β.
+    Bernstein,
+}
+
+impl Constant {
+    pub const ALL: [Self; 8] = [
+        Self::Pi,
+        Self::E,
+        Self::Tau,
+        Self::GoldenRatio,
+        Self::SilverRatio,
+        Self::SupergoldenRatio,
+        Self::EulerMascheroni,
+        Self::Bernstein,
+    ];
+
+    pub fn value(&self) -> f64 {
+        match self {
+            Self::Pi => std::f64::consts::PI,
+            Self::E => std::f64::consts::E,
+            Self::Tau => TAU,
+            Self::GoldenRatio => GOLDEN_RATIO,
+            Self::SilverRatio => SILVER_RATIO,
+            Self::SupergoldenRatio => SUPERGOLDEN_RATIO,
+            Self::EulerMascheroni => EULER_MASCHERONI,
+            Self::Bernstein => BERNSTEINS_CONSTANT,
+        }
+    }
+
+    /// The name that the constant is parsed from. The names of the functions that share a symbol
+    /// with a constant (such as the gamma function) are avoided, so that gamma(x) isn't read as γx.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Pi => "pi",
+            Self::E => "e",
+            Self::Tau => "tau",
+            Self::GoldenRatio => "phi",
+            Self::SilverRatio => "delta_s",
+            Self::SupergoldenRatio => "supergolden",
+            Self::EulerMascheroni => "euler_gamma",
+            Self::Bernstein => "bernstein",
+        }
+    }
+
+    /// The symbol that the constant is displayed as.
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Pi => "π",
+            Self::E => "e",
+            Self::Tau => "τ",
+            Self::GoldenRatio => "φ",
+            Self::SilverRatio => "δₛ",
+            Self::SupergoldenRatio => "ψ",
+            Self::EulerMascheroni => "γ",
+            Self::Bernstein => "β",
+        }
+    }
+
+    /// Returns the constant with the given name or symbol.
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|constant| constant.name() == name || constant.symbol() == name)
+    }
+}
+
+impl fmt::Display for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.symbol())
+    }
+}
+
+impl fmt::Debug for Constant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
@@ -0,0 +1,611 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{gamma_function, polygamma_function};
+
+/// A dual number a + bε, where ε² = 0.
+///
+/// Evaluating a function at x + ε yields f(x) + f'(x)ε, which makes dual numbers a way of
+/// computing derivatives exactly (up to floating point precision) without building the symbolic
+/// derivative. See [this article](https://en.wikipedia.org/wiki/Automatic_differentiation) for
+/// further information.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Dual {
+    pub value: f64,
+    pub derivative: f64,
+}
+
+impl Dual {
+    pub fn new(value: f64, derivative: f64) -> Self {
+        Self { value, derivative }
+    }
+
+    /// The dual number x + ε, representing the variable at x.
+    pub fn variable(x: f64) -> Self {
+        Self::new(x, 1.)
+    }
+
+    pub fn constant(value: f64) -> Self {
+        Self::new(value, 0.)
+    }
+
+    // f(self), given f, f' and f'' at the real part of self
+    fn chain(self, f: f64, df: f64, _d2f: f64) -> Self {
+        Self::new(f, df * self.derivative)
+    }
+}
+
+/// A hyper-dual number a + bε₁ + cε₂ + dε₁ε₂, where ε₁² = ε₂² = 0 but ε₁ε₂ ≠ 0.
+///
+/// Evaluating a function at x + ε₁ + ε₂ yields f(x) + f'(x)ε₁ + f'(x)ε₂ + f''(x)ε₁ε₂, which
+/// gives the second derivative without any truncation or cancellation error.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct HyperDual {
+    pub value: f64,
+    pub e1: f64,
+    pub e2: f64,
+    pub e12: f64,
+}
+
+impl HyperDual {
+    pub fn new(value: f64, e1: f64, e2: f64, e12: f64) -> Self {
+        Self { value, e1, e2, e12 }
+    }
+
+    /// The hyper-dual number x + ε₁ + ε₂, representing the variable at x.
+    pub fn variable(x: f64) -> Self {
+        Self::new(x, 1., 1., 0.)
+    }
+
+    pub fn constant(value: f64) -> Self {
+        Self::new(value, 0., 0., 0.)
+    }
+
+    pub fn derivative(&self) -> f64 {
+        self.e1
+    }
+
+    pub fn second_derivative(&self) -> f64 {
+        self.e12
+    }
+
+    fn chain(self, f: f64, df: f64, d2f: f64) -> Self {
+        Self::new(
+            f,
+            df * self.e1,
+            df * self.e2,
+            df * self.e12 + d2f * self.e1 * self.e2,
+        )
+    }
+}
+
+/// implement the elementary functions for dual numbers, given the first and second derivatives
+/// of every function at the real part
+macro_rules! impl_elementary_functions {
+    (for $($t:ty), +) => {
+        $(impl $t {
+            pub fn sin(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(sin, cos, -sin)
+            }
+            pub fn cos(self) -> Self {
+                let (sin, cos) = self.value.sin_cos();
+                self.chain(cos, -sin, -cos)
+            }
+            pub fn tan(self) -> Self {
+                let tan = self.value.tan();
+                let sec2 = 1. + tan * tan;
+                self.chain(tan, sec2, 2. * tan * sec2)
+            }
+            pub fn sec(self) -> Self {
+                let (sec, tan) = (1. / self.value.cos(), self.value.tan());
+                self.chain(sec, sec * tan, sec * (tan * tan + sec * sec))
+            }
+            pub fn csc(self) -> Self {
+                let (csc, cot) = (1. / self.value.sin(), 1. / self.value.tan());
+                self.chain(csc, -csc * cot, csc * (cot * cot + csc * csc))
+            }
+            pub fn cot(self) -> Self {
+                let cot = 1. / self.value.tan();
+                let csc2 = 1. + cot * cot;
+                self.chain(cot, -csc2, 2. * cot * csc2)
+            }
+            pub fn asin(self) -> Self {
+                let complement = 1. - self.value * self.value;
+                self.chain(
+                    self.value.asin(),
+                    1. / complement.sqrt(),
+                    self.value / complement.powf(1.5),
+                )
+            }
+            pub fn acos(self) -> Self {
+                let complement = 1. - self.value * self.value;
+                self.chain(
+                    self.value.acos(),
+                    -1. / complement.sqrt(),
+                    -self.value / complement.powf(1.5),
+                )
+            }
+            pub fn atan(self) -> Self {
+                let sum = 1. + self.value * self.value;
+                self.chain(self.value.atan(), 1. / sum, -2. * self.value / (sum * sum))
+            }
+            pub fn sinh(self) -> Self {
+                let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                self.chain(sinh, cosh, sinh)
+            }
+            pub fn cosh(self) -> Self {
+                let (sinh, cosh) = (self.value.sinh(), self.value.cosh());
+                self.chain(cosh, sinh, cosh)
+            }
+            pub fn tanh(self) -> Self {
+                let tanh = self.value.tanh();
+                let sech2 = 1. - tanh * tanh;
+                self.chain(tanh, sech2, -2. * tanh * sech2)
+            }
+            pub fn exp(self) -> Self {
+                let exp = self.value.exp();
+                self.chain(exp, exp, exp)
+            }
+            pub fn ln(self) -> Self {
+                let reciprocal = 1. / self.value;
+                self.chain(self.value.ln(), reciprocal, -reciprocal * reciprocal)
+            }
+            /// Raises the number to a constant power.
+            pub fn powf(self, exp: f64) -> Self {
+                self.chain(
+                    self.value.powf(exp),
+                    exp * self.value.powf(exp - 1.),
+                    exp * (exp - 1.) * self.value.powf(exp - 2.),
+                )
+            }
+            pub fn gamma(self) -> Self {
+                let gamma = gamma_function(self.value);
+                let digamma = polygamma_function(self.value, 0);
+                let trigamma = polygamma_function(self.value, 1);
+                self.chain(gamma, gamma * digamma, gamma * (digamma * digamma + trigamma))
+            }
+            pub fn polygamma(self, order: usize) -> Self {
+                self.chain(
+                    polygamma_function(self.value, order),
+                    polygamma_function(self.value, order + 1),
+                    polygamma_function(self.value, order + 2),
+                )
+            }
+            pub fn abs(self) -> Self {
+                self.chain(self.value.abs(), self.value.signum(), 0.)
+            }
+            pub fn asinh(self) -> Self {
+                let sum = self.value * self.value + 1.;
+                self.chain(
+                    self.value.asinh(),
+                    1. / sum.sqrt(),
+                    -self.value / sum.powf(1.5),
+                )
+            }
+            pub fn acosh(self) -> Self {
+                let difference = self.value * self.value - 1.;
+                self.chain(
+                    self.value.acosh(),
+                    1. / difference.sqrt(),
+                    -self.value / difference.powf(1.5),
+                )
+            }
+            pub fn atanh(self) -> Self {
+                let complement = 1. - self.value * self.value;
+                self.chain(
+                    self.value.atanh(),
+                    1. / complement,
+                    2. * self.value / (complement * complement),
+                )
+            }
+            pub fn cbrt(self) -> Self {
+                let cbrt = self.value.cbrt();
+                self.chain(
+                    cbrt,
+                    1. / (3. * cbrt * cbrt),
+                    -2. / (9. * self.value * cbrt * cbrt),
+                )
+            }
+            // floor and ceil are constant between the integers, where they jump
+            pub fn floor(self) -> Self {
+                self.chain(self.value.floor(), 0., 0.)
+            }
+            pub fn ceil(self) -> Self {
+                self.chain(self.value.ceil(), 0., 0.)
+            }
+            pub fn recip(self) -> Self {
+                let reciprocal = 1. / self.value;
+                self.chain(
+                    reciprocal,
+                    -reciprocal * reciprocal,
+                    2. * reciprocal * reciprocal * reciprocal,
+                )
+            }
+        })*
+    };
+}
+impl_elementary_functions!(for Dual, HyperDual);
+
+impl Add for Dual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+impl Sub for Dual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+impl Mul for Dual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.derivative + self.derivative * rhs.value,
+        )
+    }
+}
+impl Div for Dual {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value / rhs.value,
+            (self.derivative * rhs.value - self.value * rhs.derivative) / (rhs.value * rhs.value),
+        )
+    }
+}
+impl Neg for Dual {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, -self.derivative)
+    }
+}
+
+impl Add for HyperDual {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value + rhs.value,
+            self.e1 + rhs.e1,
+            self.e2 + rhs.e2,
+            self.e12 + rhs.e12,
+        )
+    }
+}
+impl Sub for HyperDual {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Mul for HyperDual {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::new(
+            self.value * rhs.value,
+            self.value * rhs.e1 + self.e1 * rhs.value,
+            self.value * rhs.e2 + self.e2 * rhs.value,
+            self.value * rhs.e12 + self.e1 * rhs.e2 + self.e2 * rhs.e1 + self.e12 * rhs.value,
+        )
+    }
+}
+impl Div for HyperDual {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        Mul::mul(self, rhs.recip())
+    }
+}
+impl Neg for HyperDual {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        Self::new(-self.value, -self.e1, -self.e2, -self.e12)
+    }
+}
+
+/// A truncated Taylor series f(a) + f'(a)h + f''(a)/2! h² + ... + f⁽ⁿ⁾(a)/n! hⁿ, also known as a
+/// jet.
+///
+/// Jets generalize dual numbers to derivatives of any order: evaluating a function at the jet of
+/// the variable x yields the jet of the function, from which all derivatives up to the order of the
+/// jet can be read.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Jet {
+    coefficients: Vec<f64>,
+}
+
+impl Jet {
+    /// The jet of the variable at x, truncated after the provided order.
+    pub fn variable(x: f64, order: usize) -> Self {
+        let mut coefficients = vec![0.; order + 1];
+        coefficients[0] = x;
+        if order > 0 {
+            coefficients[1] = 1.;
+        }
+        Self { coefficients }
+    }
+
+    pub fn constant(value: f64, order: usize) -> Self {
+        let mut coefficients = vec![0.; order + 1];
+        coefficients[0] = value;
+        Self { coefficients }
+    }
+
+    pub fn order(&self) -> usize {
+        self.coefficients.len() - 1
+    }
+
+    pub fn value(&self) -> f64 {
+        self.coefficients[0]
+    }
+
+    /// The Taylor coefficients f⁽ᵏ⁾(a)/k! for k = 0, 1, ..., n.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// The derivatives f⁽ᵏ⁾(a) for k = 0, 1, ..., n.
+    pub fn derivatives(&self) -> Vec<f64> {
+        let mut factorial = 1.;
+        self.coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, coefficient)| {
+                if k > 0 {
+                    factorial *= k as f64;
+                }
+                coefficient * factorial
+            })
+            .collect()
+    }
+
+    fn truncate(&self, order: usize) -> Self {
+        Self {
+            coefficients: self.coefficients[..=order].to_vec(),
+        }
+    }
+
+    fn scale(mut self, factor: f64) -> Self {
+        self.coefficients
+            .iter_mut()
+            .for_each(|coefficient| *coefficient *= factor);
+        self
+    }
+
+    // the jet w of the function whose derivative is g(self) * self', where w(a) = value and g is
+    // the jet of g(self)
+    fn integrate(&self, value: f64, derivative: &Jet) -> Self {
+        let mut coefficients = vec![value];
+        for k in 1..=self.order() {
+            let sum: f64 = (1..=k)
+                .map(|j| j as f64 * self.coefficients[j] * derivative.coefficients[k - j])
+                .sum();
+            coefficients.push(sum / k as f64);
+        }
+        Self { coefficients }
+    }
+
+    // sin and cos together, since the coefficients of each depend on the other
+    fn sin_cos(&self, hyperbolic: bool) -> (Self, Self) {
+        let value = self.value();
+        let (mut sin, mut cos) = if hyperbolic {
+            (vec![value.sinh()], vec![value.cosh()])
+        } else {
+            (vec![value.sin()], vec![value.cos()])
+        };
+        let sign = if hyperbolic { 1. } else { -1. };
+        for k in 1..=self.order() {
+            let (mut sin_sum, mut cos_sum) = (0., 0.);
+            for j in 1..=k {
+                sin_sum += j as f64 * self.coefficients[j] * cos[k - j];
+                cos_sum += j as f64 * self.coefficients[j] * sin[k - j];
+            }
+            sin.push(sin_sum / k as f64);
+            cos.push(sign * cos_sum / k as f64);
+        }
+        (Self { coefficients: sin }, Self { coefficients: cos })
+    }
+
+    fn like(&self, value: f64) -> Self {
+        Self::constant(value, self.order())
+    }
+
+    pub fn sin(&self) -> Self {
+        self.sin_cos(false).0
+    }
+    pub fn cos(&self) -> Self {
+        self.sin_cos(false).1
+    }
+    pub fn tan(&self) -> Self {
+        let (sin, cos) = self.sin_cos(false);
+        sin / cos
+    }
+    pub fn sec(&self) -> Self {
+        self.like(1.) / self.cos()
+    }
+    pub fn csc(&self) -> Self {
+        self.like(1.) / self.sin()
+    }
+    pub fn cot(&self) -> Self {
+        let (sin, cos) = self.sin_cos(false);
+        cos / sin
+    }
+    pub fn asin(&self) -> Self {
+        // asin' = (1 - x²)^(-1/2)
+        let derivative = (self.like(1.) - self.clone() * self.clone()).powf(-0.5);
+        self.integrate(self.value().asin(), &derivative)
+    }
+    pub fn acos(&self) -> Self {
+        let derivative = -(self.like(1.) - self.clone() * self.clone()).powf(-0.5);
+        self.integrate(self.value().acos(), &derivative)
+    }
+    pub fn atan(&self) -> Self {
+        let derivative = self.like(1.) / (self.like(1.) + self.clone() * self.clone());
+        self.integrate(self.value().atan(), &derivative)
+    }
+    pub fn sinh(&self) -> Self {
+        self.sin_cos(true).0
+    }
+    pub fn cosh(&self) -> Self {
+        self.sin_cos(true).1
+    }
+    pub fn tanh(&self) -> Self {
+        let (sinh, cosh) = self.sin_cos(true);
+        sinh / cosh
+    }
+    pub fn asinh(&self) -> Self {
+        // asinh' = (x² + 1)^(-1/2)
+        let derivative = (self.clone() * self.clone() + self.like(1.)).powf(-0.5);
+        self.integrate(self.value().asinh(), &derivative)
+    }
+    pub fn acosh(&self) -> Self {
+        let derivative = (self.clone() * self.clone() - self.like(1.)).powf(-0.5);
+        self.integrate(self.value().acosh(), &derivative)
+    }
+    pub fn atanh(&self) -> Self {
+        let derivative = self.like(1.) / (self.like(1.) - self.clone() * self.clone());
+        self.integrate(self.value().atanh(), &derivative)
+    }
+    pub fn cbrt(&self) -> Self {
+        // x^(1/3) is odd, so the jet of a negative number is found from that of its magnitude
+        self.abs().powf(1. / 3.).scale(self.value().signum())
+    }
+    pub fn floor(&self) -> Self {
+        self.like(self.value().floor())
+    }
+    pub fn ceil(&self) -> Self {
+        self.like(self.value().ceil())
+    }
+    pub fn exp(&self) -> Self {
+        let mut coefficients = vec![self.value().exp()];
+        for k in 1..=self.order() {
+            let sum: f64 = (1..=k)
+                .map(|j| j as f64 * self.coefficients[j] * coefficients[k - j])
+                .sum();
+            coefficients.push(sum / k as f64);
+        }
+        Self { coefficients }
+    }
+    pub fn ln(&self) -> Self {
+        let value = self.value();
+        let mut coefficients = vec![value.ln()];
+        for k in 1..=self.order() {
+            let sum: f64 = (1..k)
+                .map(|j| j as f64 * coefficients[j] * self.coefficients[k - j])
+                .sum();
+            coefficients.push((self.coefficients[k] - sum / k as f64) / value);
+        }
+        Self { coefficients }
+    }
+    /// Raises the jet to a constant power.
+    pub fn powf(&self, exp: f64) -> Self {
+        if exp.fract() == 0. && exp.abs() <= 64. {
+            // repeated multiplication also works at 0, where the recurrence below doesn't
+            let mut power = self.like(1.);
+            let mut base = self.clone();
+            let mut n = exp.abs() as u32;
+            while n > 0 {
+                if n % 2 == 1 {
+                    power = power * base.clone();
+                }
+                base = base.clone() * base;
+                n /= 2;
+            }
+            return if exp < 0. {
+                self.like(1.) / power
+            } else {
+                power
+            };
+        }
+
+        let value = self.value();
+        let mut coefficients = vec![value.powf(exp)];
+        for k in 1..=self.order() {
+            let sum: f64 = (1..=k)
+                .map(|j| {
+                    (exp * j as f64 - (k - j) as f64) * self.coefficients[j] * coefficients[k - j]
+                })
+                .sum();
+            coefficients.push(sum / (k as f64 * value));
+        }
+        Self { coefficients }
+    }
+    pub fn gamma(&self) -> Self {
+        // 𝜞(x) = 𝜞(a) * e^(ln 𝜞(x) - ln 𝜞(a)), where (ln 𝜞)' = 𝝍0
+        let log_gamma = self.integrate(0., &self.polygamma_truncated(0));
+        log_gamma.exp().scale(gamma_function(self.value()))
+    }
+    pub fn polygamma(&self, order: usize) -> Self {
+        // 𝝍m' = 𝝍(m + 1), so the coefficients of 𝝍m are found from the jet of 𝝍(m + 1) of one
+        // order less
+        if self.order() == 0 {
+            return self.like(polygamma_function(self.value(), order));
+        }
+        self.integrate(
+            polygamma_function(self.value(), order),
+            &self.polygamma_truncated(order + 1),
+        )
+    }
+    // the jet of 𝝍m truncated one order below self, which is all that integrate() uses
+    fn polygamma_truncated(&self, order: usize) -> Self {
+        if self.order() == 0 {
+            return self.like(polygamma_function(self.value(), order));
+        }
+        let mut derivative = self.truncate(self.order() - 1).polygamma(order);
+        derivative.coefficients.push(0.);
+        derivative
+    }
+    pub fn abs(&self) -> Self {
+        self.clone().scale(self.value().signum())
+    }
+}
+
+impl Add for Jet {
+    type Output = Self;
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self.coefficients
+            .iter_mut()
+            .zip(rhs.coefficients)
+            .for_each(|(coefficient, rhs)| *coefficient += rhs);
+        self
+    }
+}
+impl Sub for Jet {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Mul for Jet {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        let coefficients = (0..=self.order())
+            .map(|k| {
+                (0..=k)
+                    .map(|j| self.coefficients[j] * rhs.coefficients[k - j])
+                    .sum()
+            })
+            .collect();
+        Self { coefficients }
+    }
+}
+impl Div for Jet {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        let mut coefficients: Vec<f64> = Vec::new();
+        for k in 0..=self.order() {
+            let sum: f64 = (1..=k)
+                .map(|j| rhs.coefficients[j] * coefficients[k - j])
+                .sum();
+            coefficients.push((self.coefficients[k] - sum) / rhs.coefficients[0]);
+        }
+        Self { coefficients }
+    }
+}
+impl Neg for Jet {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        self.scale(-1.)
+    }
+}
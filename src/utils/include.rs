@@ -1,14 +1,40 @@
 pub use crate::functions::calc::{
-    abs, acos, asin, atan, cos, cosh, cot, csc, factorial, ln, nth_root, sec, sin, sinh, sqrt, tan,
-    tanh,
+    abs, acos, acosh, acot, acsc, asec, asin, asinh, atan, atanh, cbrt, ceil, cos, cosh, cot, coth,
+    csc, csch, exp, factorial, floor, ln, log, nth_root, sec, sech, sin, sinh, sqrt, tan, tanh,
 };
 
 pub use crate::functions::{
+    arena::{ExpressionArena, NodeId},
+    checked_evaluation::{EvalError, EvalErrorKind},
+    curve_analysis::{Asymptote, CurveReport, Towards},
     differentiation::derivative_of,
+    extrema::{CriticalPoint, CriticalPointKind},
     integration::{Integral, Integrate},
+    limits::Direction,
+    piecewise::{heaviside, max, min, sign, Condition, Relation},
+    range::RangeMode,
+    reverse_mode::Tape,
+    roots::{ComplexRoot, Root},
     series_expansions::SeriesExpansion,
 };
 
 pub use crate::functions::{calc::Function, utils::useful_functions::*};
 
-pub use super::consts::*;
+pub use crate::simplify::{
+    canonical::Canonical,
+    polynomial::Polynomial,
+    rational::{PartialFraction, PartialFractions, RationalFunction},
+    rules::{Bindings, Rule, RuleSet},
+};
+
+pub use super::{
+    big_float::BigFloat,
+    big_uint::BigUint,
+    complex::Complex,
+    consts::*,
+    dual::{Dual, HyperDual, Jet},
+    interval::Interval,
+    interval_set::{IntervalSet, Lattice, Periodic, Span},
+    rational::Rational,
+    scalar::Scalar,
+};
@@ -0,0 +1,597 @@
+use std::{
+    f64::consts::{FRAC_PI_2, PI, TAU},
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+// library functions such as sin and ln aren't correctly rounded, so their results are widened by a
+// few ulps rather than one
+const LIBRARY_ULPS: u32 = 4;
+
+// the relative error of lanczos_gamma() and asymptotic_polygamma(), with a good margin
+const APPROXIMATION_ERROR: f64 = 1e-12;
+
+// the positive minimum of the gamma function, 𝜞(1.4616321449683622) = 0.8856031944108887
+const GAMMA_MINIMUM_AT: f64 = 1.4616321449683622;
+const GAMMA_MINIMUM: f64 = 0.8856031944108887;
+
+/// A closed interval [lo, hi] of real numbers, used for rigorous bounds on the values of a
+/// function.
+///
+/// Every operation on intervals is rounded outward, so that the resulting interval is guaranteed to
+/// contain the exact result for every point of the operands. Bounds may be infinite, and an
+/// operation that is undefined everywhere on its operands (such as ln([-2, -1])) results in the
+/// [empty](crate::Interval::empty) interval. See [this
+/// article](https://en.wikipedia.org/wiki/Interval_arithmetic) for further information.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Interval {
+    pub lo: f64,
+    pub hi: f64,
+}
+
+impl Interval {
+    pub fn new(lo: f64, hi: f64) -> Self {
+        Self { lo, hi }
+    }
+
+    /// The degenerate interval [x, x].
+    pub fn point(x: f64) -> Self {
+        Self::new(x, x)
+    }
+
+    /// The interval (-∞, ∞).
+    pub fn entire() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY)
+    }
+
+    /// The interval containing no numbers.
+    pub fn empty() -> Self {
+        Self::new(f64::INFINITY, f64::NEG_INFINITY)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        // NaN bounds are treated as empty as well
+        self.lo
+            .partial_cmp(&self.hi)
+            .is_none_or(|ordering| ordering.is_gt())
+    }
+
+    pub fn is_entire(&self) -> bool {
+        self.lo == f64::NEG_INFINITY && self.hi == f64::INFINITY
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.lo <= x && x <= self.hi
+    }
+
+    pub fn width(&self) -> f64 {
+        self.hi - self.lo
+    }
+
+    pub fn midpoint(&self) -> f64 {
+        self.lo / 2. + self.hi / 2.
+    }
+
+    /// The smallest interval containing both intervals.
+    pub fn hull(&self, other: &Self) -> Self {
+        if self.is_empty() {
+            *other
+        } else if other.is_empty() {
+            *self
+        } else {
+            Self::new(self.lo.min(other.lo), self.hi.max(other.hi))
+        }
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let intersection = Self::new(self.lo.max(other.lo), self.hi.min(other.hi));
+        if intersection.is_empty() {
+            Self::empty()
+        } else {
+            intersection
+        }
+    }
+
+    // rounds the bounds outward by the provided number of ulps
+    pub(crate) fn widen(self, ulps: u32) -> Self {
+        if self.is_empty() {
+            return Self::empty();
+        }
+        // a bound of ∞ (as in [∞, ∞]) is kept rather than rounded down to f64::MAX
+        let lo = match self.lo {
+            f64::INFINITY => self.lo,
+            lo => (0..ulps).fold(lo, |lo, _| lo.next_down()),
+        };
+        let hi = match self.hi {
+            f64::NEG_INFINITY => self.hi,
+            hi => (0..ulps).fold(hi, |hi, _| hi.next_up()),
+        };
+        Self::new(lo, hi)
+    }
+
+    // widens the bounds by the provided error relative to their magnitude
+    fn widen_relative(self, error: f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(
+            self.lo - self.lo.abs() * error,
+            self.hi + self.hi.abs() * error,
+        )
+        .widen(1)
+    }
+
+    // the image of a monotonically increasing library function
+    fn increasing(self, f: impl Fn(f64) -> f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(f(self.lo), f(self.hi)).widen(LIBRARY_ULPS)
+    }
+
+    // the image of a monotonically decreasing library function
+    fn decreasing(self, f: impl Fn(f64) -> f64) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(f(self.hi), f(self.lo)).widen(LIBRARY_ULPS)
+    }
+
+    // whether the interval contains offset + k * period for some integer k. The interval is widened
+    // to make up for the rounding of π, whose error grows with the number of periods
+    fn contains_period_point(&self, offset: f64, period: f64) -> bool {
+        let slack = 1e-9 * (1. + self.lo.abs().max(self.hi.abs()) / period);
+        let first = ((self.lo - offset) / period - slack).ceil();
+        let last = ((self.hi - offset) / period + slack).floor();
+        first <= last
+    }
+
+    pub fn sin(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.width() >= TAU || !self.width().is_finite() {
+            return Self::new(-1., 1.);
+        }
+        let (sin_lo, sin_hi) = (self.lo.sin(), self.hi.sin());
+        let mut image = Self::new(sin_lo.min(sin_hi), sin_lo.max(sin_hi)).widen(LIBRARY_ULPS);
+        if self.contains_period_point(FRAC_PI_2, TAU) {
+            image.hi = 1.;
+        }
+        if self.contains_period_point(-FRAC_PI_2, TAU) {
+            image.lo = -1.;
+        }
+        image.intersection(&Self::new(-1., 1.))
+    }
+
+    pub fn cos(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.width() >= TAU || !self.width().is_finite() {
+            return Self::new(-1., 1.);
+        }
+        let (cos_lo, cos_hi) = (self.lo.cos(), self.hi.cos());
+        let mut image = Self::new(cos_lo.min(cos_hi), cos_lo.max(cos_hi)).widen(LIBRARY_ULPS);
+        if self.contains_period_point(0., TAU) {
+            image.hi = 1.;
+        }
+        if self.contains_period_point(PI, TAU) {
+            image.lo = -1.;
+        }
+        image.intersection(&Self::new(-1., 1.))
+    }
+
+    pub fn tan(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        // tan is increasing between its poles at π/2 + kπ
+        if self.width() >= PI || self.contains_period_point(FRAC_PI_2, PI) {
+            return Self::entire();
+        }
+        self.increasing(f64::tan)
+    }
+
+    pub fn sec(self) -> Self {
+        Self::point(1.) / self.cos()
+    }
+
+    pub fn csc(self) -> Self {
+        Self::point(1.) / self.sin()
+    }
+
+    pub fn cot(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        // cot is decreasing between its poles at kπ
+        if self.width() >= PI || self.contains_period_point(0., PI) {
+            return Self::entire();
+        }
+        self.decreasing(|x| 1. / x.tan())
+    }
+
+    pub fn asin(self) -> Self {
+        self.intersection(&Self::new(-1., 1.))
+            .increasing(f64::asin)
+            .intersection(&Self::new(-FRAC_PI_2, FRAC_PI_2).widen(1))
+    }
+
+    pub fn acos(self) -> Self {
+        self.intersection(&Self::new(-1., 1.))
+            .decreasing(f64::acos)
+            .intersection(&Self::new(0., PI).widen(1))
+    }
+
+    pub fn atan(self) -> Self {
+        self.increasing(f64::atan)
+    }
+
+    pub fn sinh(self) -> Self {
+        self.increasing(f64::sinh)
+    }
+
+    pub fn cosh(self) -> Self {
+        self.abs()
+            .increasing(f64::cosh)
+            .intersection(&Self::new(1., f64::INFINITY))
+    }
+
+    pub fn tanh(self) -> Self {
+        self.increasing(f64::tanh).intersection(&Self::new(-1., 1.))
+    }
+
+    pub fn sech(self) -> Self {
+        Self::point(1.) / self.cosh()
+    }
+
+    pub fn csch(self) -> Self {
+        Self::point(1.) / self.sinh()
+    }
+
+    pub fn coth(self) -> Self {
+        Self::point(1.) / self.tanh()
+    }
+
+    pub fn acot(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        // acot(x) = atan(1/x) is decreasing on either side of its jump at 0
+        if self.contains(0.) {
+            return Self::new(-FRAC_PI_2, FRAC_PI_2).widen(1);
+        }
+        self.decreasing(|x| (1. / x).atan())
+    }
+
+    pub fn asec(self) -> Self {
+        (Self::point(1.) / self).acos()
+    }
+
+    pub fn acsc(self) -> Self {
+        (Self::point(1.) / self).asin()
+    }
+
+    pub fn asinh(self) -> Self {
+        self.increasing(f64::asinh)
+    }
+
+    /// The inverse hyperbolic cosine of the part of the interval where it is defined, i.e.
+    /// [1, ∞).
+    pub fn acosh(self) -> Self {
+        self.intersection(&Self::new(1., f64::INFINITY))
+            .increasing(f64::acosh)
+            .intersection(&Self::new(0., f64::INFINITY))
+    }
+
+    /// The inverse hyperbolic tangent of the part of the interval where it is defined, i.e.
+    /// [-1, 1].
+    pub fn atanh(self) -> Self {
+        self.intersection(&Self::new(-1., 1.))
+            .increasing(f64::atanh)
+    }
+
+    pub fn cbrt(self) -> Self {
+        self.increasing(f64::cbrt)
+    }
+
+    pub fn floor(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(self.lo.floor(), self.hi.floor())
+    }
+
+    pub fn ceil(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(self.lo.ceil(), self.hi.ceil())
+    }
+
+    pub fn exp(self) -> Self {
+        self.increasing(f64::exp)
+            .intersection(&Self::new(0., f64::INFINITY))
+    }
+
+    /// The natural logarithm of the part of the interval where it is defined, i.e. (0, ∞).
+    pub fn ln(self) -> Self {
+        self.intersection(&Self::new(0., f64::INFINITY))
+            .increasing(f64::ln)
+    }
+
+    /// The square root of the part of the interval where it is defined, i.e. [0, ∞).
+    pub fn sqrt(self) -> Self {
+        self.intersection(&Self::new(0., f64::INFINITY))
+            .increasing(f64::sqrt)
+            .intersection(&Self::new(0., f64::INFINITY))
+    }
+
+    /// Raises the interval to a constant power. Non-integer powers are only defined for
+    /// non-negative numbers.
+    pub fn powf(self, exp: f64) -> Self {
+        if self.is_empty() || exp.is_nan() {
+            return Self::empty();
+        }
+        if exp == 0. {
+            return Self::point(1.);
+        }
+        if exp.fract() == 0. {
+            if exp < 0. {
+                return Self::point(1.) / self.powf(-exp);
+            }
+            if exp % 2. == 0. {
+                // even powers are decreasing for negative numbers and increasing for positive ones
+                return self
+                    .abs()
+                    .increasing(|x| x.powf(exp))
+                    .intersection(&Self::new(0., f64::INFINITY));
+            }
+            return self.increasing(|x| x.powf(exp));
+        }
+
+        let domain = self.intersection(&Self::new(0., f64::INFINITY));
+        if exp > 0. {
+            domain.increasing(|x| x.powf(exp))
+        } else {
+            domain.decreasing(|x| x.powf(exp))
+        }
+        .intersection(&Self::new(0., f64::INFINITY))
+    }
+
+    /// Raises the interval to the power of another interval. Negative bases only have a power
+    /// where the exponent is an integer.
+    pub fn pow(self, exp: Self) -> Self {
+        if self.is_empty() || exp.is_empty() {
+            return Self::empty();
+        }
+        if exp.lo == exp.hi {
+            return self.powf(exp.lo);
+        }
+        let positive = self.intersection(&Self::new(0., f64::INFINITY));
+        let mut power = (exp * positive.ln()).exp();
+
+        // for negative bases, b^n = ±|b|^n depending on whether n is even
+        if self.lo < 0. && exp.lo.ceil() <= exp.hi.floor() {
+            let magnitude = (exp * (-self).intersection(&Self::new(0., f64::INFINITY)).ln()).exp();
+            power = power.hull(&Self::new(-magnitude.hi, magnitude.hi));
+        }
+        power
+    }
+
+    /// The gamma function of the interval. Since [gamma_function()](crate::gamma_function) is
+    /// computed by numerical integration, the bounds are found from a more accurate approximation
+    /// and enclose the exact values of 𝜞 rather than those of gamma_function().
+    pub fn gamma(self) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        // 𝜞 has poles at the non-positive integers and changes monotonicity between each of them,
+        // so only positive numbers get a bound other than the entire real line
+        if self.lo <= 0. {
+            return Self::entire();
+        }
+        let image = if self.hi <= GAMMA_MINIMUM_AT {
+            Self::new(lanczos_gamma(self.hi), lanczos_gamma(self.lo))
+        } else if self.lo >= GAMMA_MINIMUM_AT {
+            Self::new(lanczos_gamma(self.lo), lanczos_gamma(self.hi))
+        } else {
+            let hi = lanczos_gamma(self.lo).max(lanczos_gamma(self.hi));
+            Self::new(GAMMA_MINIMUM, hi)
+        };
+        image.widen_relative(APPROXIMATION_ERROR)
+    }
+
+    /// The polygamma function 𝝍m of the interval, which like [gamma()](crate::Interval::gamma)
+    /// encloses the exact values of 𝝍m rather than those of
+    /// [polygamma_function()](crate::polygamma_function).
+    pub fn polygamma(self, order: usize) -> Self {
+        if self.is_empty() {
+            return self;
+        }
+        if self.lo <= 0. {
+            return Self::entire();
+        }
+        // for positive numbers, 𝝍m is increasing for even m and decreasing for odd m
+        let image = if order.is_multiple_of(2) {
+            Self::new(
+                asymptotic_polygamma(self.lo, order),
+                asymptotic_polygamma(self.hi, order),
+            )
+        } else {
+            Self::new(
+                asymptotic_polygamma(self.hi, order),
+                asymptotic_polygamma(self.lo, order),
+            )
+        };
+        image.widen_relative(APPROXIMATION_ERROR)
+    }
+
+    pub fn abs(self) -> Self {
+        if self.is_empty() || self.lo >= 0. {
+            self
+        } else if self.hi <= 0. {
+            -self
+        } else {
+            Self::new(0., self.hi.max(-self.lo))
+        }
+    }
+}
+
+impl From<f64> for Interval {
+    fn from(value: f64) -> Self {
+        Self::point(value)
+    }
+}
+
+impl Add for Interval {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty();
+        }
+        Self::new(self.lo + rhs.lo, self.hi + rhs.hi).widen(1)
+    }
+}
+impl Sub for Interval {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+impl Mul for Interval {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        if self.is_empty() || rhs.is_empty() {
+            return Self::empty();
+        }
+        // 0 * ∞ is taken to be 0, since the infinite bound is never attained
+        let product = |a: f64, b: f64| if a == 0. || b == 0. { 0. } else { a * b };
+        let products = [
+            product(self.lo, rhs.lo),
+            product(self.lo, rhs.hi),
+            product(self.hi, rhs.lo),
+            product(self.hi, rhs.hi),
+        ];
+        Self::new(
+            products.into_iter().fold(f64::INFINITY, f64::min),
+            products.into_iter().fold(f64::NEG_INFINITY, f64::max),
+        )
+        .widen(1)
+    }
+}
+impl Div for Interval {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        if self.is_empty() || rhs.is_empty() || (rhs.lo == 0. && rhs.hi == 0.) {
+            return Self::empty();
+        }
+        let reciprocal = if rhs.lo > 0. || rhs.hi < 0. {
+            Self::new(1. / rhs.hi, 1. / rhs.lo).widen(1)
+        } else if rhs.lo == 0. {
+            // [0, b] only approaches 0 from above
+            Self::new((1. / rhs.hi).next_down(), f64::INFINITY)
+        } else if rhs.hi == 0. {
+            Self::new(f64::NEG_INFINITY, (1. / rhs.lo).next_up())
+        } else {
+            // the reciprocal of an interval containing 0 in its interior is the union of two
+            // half-lines, which is only contained in the entire real line
+            return Self::entire();
+        };
+        self * reciprocal
+    }
+}
+impl Neg for Interval {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        if self.is_empty() {
+            return self;
+        }
+        Self::new(-self.hi, -self.lo)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            write!(f, "∅")
+        } else {
+            write!(f, "[{}, {}]", self.lo, self.hi)
+        }
+    }
+}
+
+// 𝜞(x) for x > 0 using the Lanczos approximation with g = 7, which is accurate to about 15
+// significant figures
+fn lanczos_gamma(x: f64) -> f64 {
+    const G: f64 = 7.;
+    const COEFFICIENTS: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    if x < 0.5 {
+        // 𝜞(x) = 𝜞(x + 1) / x
+        return lanczos_gamma(x + 1.) / x;
+    }
+    let x = x - 1.;
+    let sum = COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(COEFFICIENTS[0], |sum, (i, coefficient)| {
+            sum + coefficient / (x + i as f64 + 1.)
+        });
+    let t = x + G + 0.5;
+    TAU.sqrt() * t.powf(x + 0.5) * (-t).exp() * sum
+}
+
+// 𝝍m(x) for x > 0, found by shifting x up using 𝝍m(x) = 𝝍m(x + 1) - (-1)^m m!/x^(m + 1) and then
+// using the asymptotic expansion of 𝝍m, see https://dlmf.nist.gov/5.15
+fn asymptotic_polygamma(mut x: f64, order: usize) -> f64 {
+    // the Bernoulli numbers B2, B4, ..., B14
+    const BERNOULLI: [f64; 7] = [
+        1. / 6.,
+        -1. / 30.,
+        1. / 42.,
+        -1. / 30.,
+        5. / 66.,
+        -691. / 2730.,
+        7. / 6.,
+    ];
+    let m = order as f64;
+    let factorial = |n: usize| (1..=n).map(|k| k as f64).product::<f64>();
+    let sign = if order.is_multiple_of(2) { -1. } else { 1. };
+
+    let mut shifted = 0.;
+    while x < 20. + m {
+        shifted += factorial(order) / x.powf(m + 1.);
+        x += 1.;
+    }
+
+    if order == 0 {
+        let series: f64 = BERNOULLI
+            .iter()
+            .enumerate()
+            .map(|(k, bernoulli)| bernoulli / ((2 * k + 2) as f64 * x.powi(2 * k as i32 + 2)))
+            .sum();
+        return x.ln() - 1. / (2. * x) - series - shifted;
+    }
+
+    let series: f64 = BERNOULLI
+        .iter()
+        .enumerate()
+        .map(|(k, bernoulli)| {
+            let k = 2 * k + 2;
+            bernoulli * factorial(k + order - 1) / (factorial(k) * x.powf((k + order) as f64))
+        })
+        .sum();
+    let asymptotic = factorial(order - 1) / x.powf(m) + factorial(order) / (2. * x.powf(m + 1.));
+    sign * (asymptotic + series + shifted)
+}
@@ -0,0 +1,575 @@
+use std::{cmp::Ordering, fmt};
+
+// points whose lattice index is this close to an integer lie on the lattice
+const LATTICE_TOLERANCE: f64 = 1e-9;
+// lattices with at most this many points on a span are restricted to it point by point
+const MAX_LATTICE_POINTS: f64 = 10_000.;
+
+/// An interval whose ends may be open or closed, such as (0, 1] or [2, ∞). Infinite ends are
+/// always open.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    pub lo: f64,
+    pub hi: f64,
+    pub lo_closed: bool,
+    pub hi_closed: bool,
+}
+
+impl Span {
+    pub fn new(lo: f64, hi: f64, lo_closed: bool, hi_closed: bool) -> Self {
+        Self {
+            lo,
+            hi,
+            lo_closed: lo_closed && lo.is_finite(),
+            hi_closed: hi_closed && hi.is_finite(),
+        }
+    }
+
+    pub fn open(lo: f64, hi: f64) -> Self {
+        Self::new(lo, hi, false, false)
+    }
+
+    pub fn closed(lo: f64, hi: f64) -> Self {
+        Self::new(lo, hi, true, true)
+    }
+
+    /// The single point [x, x].
+    pub fn point(x: f64) -> Self {
+        Self::closed(x, x)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        match self.lo.partial_cmp(&self.hi) {
+            Some(Ordering::Less) => false,
+            Some(Ordering::Equal) => !(self.lo_closed && self.hi_closed),
+            _ => true,
+        }
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        (self.lo < x || self.lo_closed && self.lo == x)
+            && (x < self.hi || self.hi_closed && self.hi == x)
+    }
+
+    fn intersection(&self, other: &Self) -> Self {
+        let (lo, lo_closed) = match self.lo.total_cmp(&other.lo) {
+            Ordering::Less => (other.lo, other.lo_closed),
+            Ordering::Greater => (self.lo, self.lo_closed),
+            Ordering::Equal => (self.lo, self.lo_closed && other.lo_closed),
+        };
+        let (hi, hi_closed) = match self.hi.total_cmp(&other.hi) {
+            Ordering::Less => (self.hi, self.hi_closed),
+            Ordering::Greater => (other.hi, other.hi_closed),
+            Ordering::Equal => (self.hi, self.hi_closed && other.hi_closed),
+        };
+        Self::new(lo, hi, lo_closed, hi_closed)
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.lo == self.hi {
+            return write!(f, "{{{}}}", self.lo);
+        }
+        let end = |value: f64| match value {
+            f64::INFINITY => String::from("∞"),
+            f64::NEG_INFINITY => String::from("-∞"),
+            value => value.to_string(),
+        };
+        write!(
+            f,
+            "{}{}, {}{}",
+            if self.lo_closed { "[" } else { "(" },
+            end(self.lo),
+            end(self.hi),
+            if self.hi_closed { "]" } else { ")" }
+        )
+    }
+}
+
+/// The evenly spaced points offset + k * period, either for every integer k or only for k ≥ 0
+/// if the lattice is one-sided.
+///
+/// For instance, tan(x) is undefined on the lattice π/2 + kπ and 𝜞(x) on the one-sided lattice
+/// 0 - k.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lattice {
+    pub offset: f64,
+    pub period: f64,
+    pub one_sided: bool,
+}
+
+impl Lattice {
+    pub fn new(offset: f64, period: f64, one_sided: bool) -> Self {
+        if one_sided {
+            Self {
+                offset,
+                period,
+                one_sided,
+            }
+        } else {
+            // a two-sided lattice is the same for every offset within it, so the smallest
+            // non-negative one is used
+            let period = period.abs();
+            Self {
+                offset: offset.rem_euclid(period),
+                period,
+                one_sided,
+            }
+        }
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        let index = (x - self.offset) / self.period;
+        let nearest = index.round();
+        (index - nearest).abs() < LATTICE_TOLERANCE * nearest.abs().max(1.)
+            && (!self.one_sided || nearest >= 0.)
+    }
+
+    // the points of the lattice that lie within the span, either listed or as a one-sided
+    // lattice if there are too many of them. Lattices that extend both ways are kept as they are
+    fn restricted_to(&self, span: &Span) -> Result<Vec<f64>, Self> {
+        let index = |x: f64| (x - self.offset) / self.period;
+        let (from, to) = (index(span.lo), index(span.hi));
+        let (mut first, last) = (from.min(to).ceil(), from.max(to).floor());
+        if self.one_sided {
+            first = first.max(0.);
+        }
+        let point = |index: f64| self.offset + index * self.period;
+
+        if first.is_finite() && last.is_finite() && last - first < MAX_LATTICE_POINTS {
+            return Ok((first as i64..=last as i64)
+                .map(|index| point(index as f64))
+                .filter(|x| span.contains(*x))
+                .collect());
+        }
+        // the lattice starts at the first of its points within the span, which may lie on an
+        // open end of the span
+        match (first.is_finite(), last.is_finite()) {
+            (true, false) => {
+                let first = if span.contains(point(first)) {
+                    first
+                } else {
+                    first + 1.
+                };
+                Err(Self::new(point(first), self.period, true))
+            }
+            (false, true) => {
+                let last = if span.contains(point(last)) {
+                    last
+                } else {
+                    last - 1.
+                };
+                Err(Self::new(point(last), -self.period, true))
+            }
+            _ => Err(*self),
+        }
+    }
+}
+
+impl fmt::Display for Lattice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if self.period < 0. { "-" } else { "+" };
+        write!(
+            f,
+            "{{{} {sign} {}k : k ∈ {}}}",
+            self.offset,
+            self.period.abs(),
+            if self.one_sided { "ℕ" } else { "ℤ" }
+        )
+    }
+}
+
+/// The points x where x mod period lies within the pattern, restricted to the spans within which
+/// the restriction applies. The spans of the pattern lie within [0, period).
+///
+/// For instance, ln(sin(x)) is defined where x mod 2π lies in (0, π).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Periodic {
+    pub pattern: Vec<Span>,
+    pub period: f64,
+    pub within: Vec<Span>,
+}
+
+impl Periodic {
+    /// Creates the restriction to the pattern repeated with the provided period on all of ℝ. The
+    /// spans of the pattern are reduced modulo the period.
+    pub fn new(pattern: Vec<Span>, period: f64) -> Self {
+        let period = period.abs();
+        let pattern = pattern
+            .into_iter()
+            .flat_map(|span| {
+                // the span is shifted into [0, period), and split where it wraps around
+                let shift = (span.lo / period).floor() * period;
+                let (lo, hi) = (span.lo - shift, span.hi - shift);
+                if span.hi - span.lo >= period {
+                    vec![Span::new(0., period, true, false)]
+                } else if hi < period || hi == period && !span.hi_closed {
+                    vec![Span::new(lo, hi, span.lo_closed, span.hi_closed)]
+                } else {
+                    vec![
+                        Span::new(lo, period, span.lo_closed, false),
+                        Span::new(0., hi - period, true, span.hi_closed),
+                    ]
+                }
+            })
+            .collect();
+        Self {
+            pattern: IntervalSet::from_spans(pattern).spans,
+            period,
+            within: IntervalSet::real_line().spans,
+        }
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        !self.within.iter().any(|span| span.contains(x))
+            || self
+                .pattern
+                .iter()
+                .any(|span| span.contains(x.rem_euclid(self.period)))
+    }
+
+    // the points of the span where x mod period lies within the pattern, unless the span is
+    // unbounded or covers too many periods
+    fn restricted_to(&self, span: &Span) -> Option<Vec<Span>> {
+        let (first, last) = (
+            (span.lo / self.period).floor(),
+            (span.hi / self.period).floor(),
+        );
+        if !(first.is_finite() && last.is_finite()) || last - first >= MAX_LATTICE_POINTS {
+            return None;
+        }
+        Some(
+            (first as i64..=last as i64)
+                .flat_map(|index| {
+                    let shift = index as f64 * self.period;
+                    self.pattern.iter().map(move |piece| {
+                        span.intersection(&Span::new(
+                            piece.lo + shift,
+                            piece.hi + shift,
+                            piece.lo_closed,
+                            piece.hi_closed,
+                        ))
+                    })
+                })
+                .collect(),
+        )
+    }
+}
+
+impl fmt::Display for Periodic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let spans = |spans: &[Span]| {
+            spans
+                .iter()
+                .map(|span| span.to_string())
+                .collect::<Vec<String>>()
+                .join(" ∪ ")
+        };
+        let within = if self.within == IntervalSet::real_line().spans {
+            String::new()
+        } else {
+            format!(" ∈ {}", spans(&self.within))
+        };
+        let pattern = if self.pattern.is_empty() {
+            String::from("∅")
+        } else {
+            spans(&self.pattern)
+        };
+        write!(f, "{{x{within} : x mod {} ∈ {pattern}}}", self.period)
+    }
+}
+
+/// A set of real numbers made up of finitely many disjoint [Span](crate::Span)s, from which the
+/// points of finitely many [Lattice](crate::Lattice)s are excluded, and which may be restricted
+/// further by [Periodic](crate::Periodic) patterns.
+///
+/// This is the type of the [domain](crate::Function::domain) of a function.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::{IntervalSet, Span};
+/// let set = IntervalSet::from_spans(vec![Span::closed(0., 1.), Span::open(1., 2.)]);
+///
+/// // the spans are merged
+/// assert_eq!(set.spans(), [Span::new(0., 2., true, false)]);
+/// assert_eq!(set.to_string(), "[0, 2)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalSet {
+    // sorted, disjoint and non-empty spans
+    spans: Vec<Span>,
+    excluded: Vec<Lattice>,
+    // restrictions that only apply within the spans of the set, since those on bounded parts of
+    // the set are resolved into spans
+    periodic: Vec<Periodic>,
+}
+
+impl IntervalSet {
+    pub fn empty() -> Self {
+        Self {
+            spans: Vec::new(),
+            excluded: Vec::new(),
+            periodic: Vec::new(),
+        }
+    }
+
+    pub fn real_line() -> Self {
+        Self::from_spans(vec![Span::open(f64::NEG_INFINITY, f64::INFINITY)])
+    }
+
+    /// Creates the union of the spans, merging the ones that overlap or touch.
+    pub fn from_spans(mut spans: Vec<Span>) -> Self {
+        spans.retain(|span| !span.is_empty());
+        // spans starting at the same point are sorted with the closed ones first, so that
+        // [p, p] and (p, q) are merged into [p, q)
+        spans.sort_by(|span1, span2| {
+            span1
+                .lo
+                .total_cmp(&span2.lo)
+                .then(span2.lo_closed.cmp(&span1.lo_closed))
+        });
+
+        let mut merged: Vec<Span> = Vec::new();
+        for span in spans {
+            match merged.last_mut() {
+                Some(last)
+                    if last.hi > span.lo
+                        || last.hi == span.lo && (last.hi_closed || span.lo_closed) =>
+                {
+                    match span.hi.total_cmp(&last.hi) {
+                        Ordering::Greater => {
+                            last.hi = span.hi;
+                            last.hi_closed = span.hi_closed;
+                        }
+                        Ordering::Equal => last.hi_closed |= span.hi_closed,
+                        Ordering::Less => {}
+                    }
+                }
+                _ => merged.push(span),
+            }
+        }
+        Self {
+            spans: merged,
+            excluded: Vec::new(),
+            periodic: Vec::new(),
+        }
+    }
+
+    /// The spans of the set in increasing order.
+    pub fn spans(&self) -> &[Span] {
+        &self.spans
+    }
+
+    /// The lattices whose points are excluded from the spans.
+    pub fn excluded(&self) -> &[Lattice] {
+        &self.excluded
+    }
+
+    /// The periodic patterns that the points of the spans are restricted to.
+    pub fn periodic(&self) -> &[Periodic] {
+        &self.periodic
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+            || self
+                .periodic
+                .iter()
+                .any(|periodic| periodic.pattern.is_empty())
+    }
+
+    /// Whether the set is all of ℝ.
+    pub fn is_real_line(&self) -> bool {
+        self.excluded.is_empty()
+            && self.periodic.is_empty()
+            && self.spans == [Span::open(f64::NEG_INFINITY, f64::INFINITY)]
+    }
+
+    pub fn contains(&self, x: f64) -> bool {
+        self.spans.iter().any(|span| span.contains(x))
+            && !self.excluded.iter().any(|lattice| lattice.contains(x))
+            && self.periodic.iter().all(|periodic| periodic.contains(x))
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::from_spans(intersect_spans(&self.spans, &other.spans));
+        for lattice in self.excluded.iter().chain(&other.excluded) {
+            intersection = intersection.excluding(*lattice);
+        }
+        for periodic in self.periodic.iter().chain(&other.periodic) {
+            intersection = intersection.restricted(periodic.clone());
+        }
+        intersection
+    }
+
+    /// Restricts the points of the set within the spans of the [Periodic](crate::Periodic)
+    /// restriction to its pattern.
+    pub fn restricted(mut self, mut periodic: Periodic) -> Self {
+        periodic.within = intersect_spans(&periodic.within, &self.spans);
+        if periodic.within.is_empty() {
+            return self;
+        }
+
+        // the restriction is resolved into spans where it applies to a bounded part of the set
+        let bounded: Option<Vec<Vec<Span>>> = periodic
+            .within
+            .iter()
+            .map(|span| periodic.restricted_to(span))
+            .collect();
+        if let Some(restricted) = bounded {
+            let rest = intersect_spans(&self.spans, &complement(&periodic.within));
+            let spans = [rest, restricted.concat()].concat();
+            return Self {
+                spans: Self::from_spans(spans).spans,
+                ..self
+            };
+        }
+
+        match self.periodic.iter_mut().find(|existing| {
+            existing.pattern == periodic.pattern && existing.period == periodic.period
+        }) {
+            // the restrictions only differ in where they apply
+            Some(existing) => {
+                existing.within =
+                    Self::from_spans([existing.within.clone(), periodic.within].concat()).spans
+            }
+            None => self.periodic.push(periodic),
+        }
+        self
+    }
+
+    /// The union of the two sets.
+    ///
+    /// A lattice excluded by only one of the sets remains excluded from that set's spans, except
+    /// at the points that the other set contains. When the lattice has infinitely many points on
+    /// one of the spans, the points that the other set contains can't be told apart, so they
+    /// remain excluded as well. Similarly, a periodic restriction of only one of the sets applies
+    /// to the points of that set that the other set's spans don't contain, so the points within
+    /// the spans of both sets are included even if both of their restrictions exclude them.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = Self::from_spans([self.spans.clone(), other.spans.clone()].concat());
+        for (set, rest) in [(self, other), (other, self)] {
+            for periodic in &set.periodic {
+                // the points of the other set that are restricted in the same way
+                let shared: Vec<Span> = rest
+                    .periodic
+                    .iter()
+                    .filter(|other| {
+                        other.pattern == periodic.pattern && other.period == periodic.period
+                    })
+                    .flat_map(|other| other.within.clone())
+                    .collect();
+                let unrestricted =
+                    intersect_spans(&rest.spans, &complement(&Self::from_spans(shared).spans));
+                union = union.restricted(Periodic {
+                    within: intersect_spans(&periodic.within, &complement(&unrestricted)),
+                    ..periodic.clone()
+                });
+            }
+        }
+        for lattice in &self.excluded {
+            if other.excluded.contains(lattice) {
+                union = union.excluding(*lattice);
+            }
+        }
+
+        for (set, rest) in [(self, other), (other, self)] {
+            for lattice in set
+                .excluded
+                .iter()
+                .filter(|lattice| !rest.excluded.contains(lattice))
+            {
+                for span in &set.spans {
+                    union = match lattice.restricted_to(span) {
+                        Ok(points) => {
+                            let points: Vec<f64> =
+                                points.into_iter().filter(|x| !rest.contains(*x)).collect();
+                            union.without_points(&points)
+                        }
+                        Err(lattice) => union.excluding(lattice),
+                    };
+                }
+            }
+        }
+        union
+    }
+
+    /// Removes the points of the lattice from the set.
+    pub fn excluding(mut self, lattice: Lattice) -> Self {
+        if !self.excluded.contains(&lattice) {
+            self.excluded.push(lattice);
+        }
+        self
+    }
+
+    /// Removes the provided points from the set.
+    pub fn without_points(&self, points: &[f64]) -> Self {
+        let mut spans = self.spans.clone();
+        for point in points {
+            spans = spans
+                .into_iter()
+                .flat_map(|span| {
+                    if span.contains(*point) {
+                        vec![
+                            Span::new(span.lo, *point, span.lo_closed, false),
+                            Span::new(*point, span.hi, false, span.hi_closed),
+                        ]
+                    } else {
+                        vec![span]
+                    }
+                })
+                .collect();
+        }
+        Self {
+            spans: Self::from_spans(spans).spans,
+            ..self.clone()
+        }
+    }
+}
+
+// the intersection of two sets of sorted and disjoint spans
+fn intersect_spans(spans1: &[Span], spans2: &[Span]) -> Vec<Span> {
+    let mut spans = Vec::new();
+    for span1 in spans1 {
+        for span2 in spans2 {
+            spans.push(span1.intersection(span2));
+        }
+    }
+    IntervalSet::from_spans(spans).spans
+}
+
+// the points that none of the sorted and disjoint spans contain
+fn complement(spans: &[Span]) -> Vec<Span> {
+    let mut complement = Vec::new();
+    let (mut lo, mut lo_closed) = (f64::NEG_INFINITY, false);
+    for span in spans {
+        complement.push(Span::new(lo, span.lo, lo_closed, !span.lo_closed));
+        (lo, lo_closed) = (span.hi, !span.hi_closed);
+    }
+    complement.push(Span::new(lo, f64::INFINITY, lo_closed, false));
+    IntervalSet::from_spans(complement).spans
+}
+
+impl From<Span> for IntervalSet {
+    fn from(value: Span) -> Self {
+        Self::from_spans(vec![value])
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return write!(f, "∅");
+        }
+        let spans: Vec<String> = self.spans.iter().map(|span| span.to_string()).collect();
+        write!(f, "{}", spans.join(" ∪ "))?;
+        for lattice in &self.excluded {
+            write!(f, " \\ {lattice}")?;
+        }
+        for periodic in &self.periodic {
+            write!(f, " ∩ {periodic}")?;
+        }
+        Ok(())
+    }
+}
@@ -1,3 +1,11 @@
+pub mod big_float;
+pub mod big_uint;
+pub mod complex;
 pub mod consts;
+pub mod dual;
 pub mod include;
+pub mod interval;
+pub mod interval_set;
 pub mod parse;
+pub mod rational;
+pub mod scalar;
@@ -1,18 +1,58 @@
-use crate::Error;
-use std::{
-    f64::consts::{E, PI},
-    sync::Arc,
+use crate::{
+    functions::piecewise::signum, utils::rational::Rational, Condition, Constant, Error, Relation,
 };
+use std::sync::Arc;
 
 use crate::Elementary::{self, *};
 
 impl<'a> From<&'a str> for Elementary {
     fn from(value: &'a str) -> Self {
-        let value: String = value.split_whitespace().collect();
-        Self::to_elementary(&value).unwrap()
+        Self::to_elementary(&Self::normalize(value)).unwrap()
     }
 }
 impl Elementary {
+    // parses the string without panicking if it isn't a valid function
+    pub(crate) fn try_parse(value: &str) -> Result<Self, Error> {
+        Self::to_elementary(&Self::normalize(value))
+    }
+
+    // removes whitespace and replaces the symbols of constants (such as π) by their names, so that
+    // the rest of the parser only has to deal with ASCII
+    fn normalize(value: &str) -> String {
+        let mut value: String = value.split_whitespace().collect();
+        for constant in Constant::ALL {
+            value = value.replace(constant.symbol(), constant.name());
+        }
+        value
+    }
+
+    // returns the name of the constant that the value starts with at index i, unless the index is
+    // in the middle of a word (such as the e in sec)
+    fn constant_at(value: &str, cut_index: usize, i: usize) -> Option<&'static str> {
+        if cut_index != i {
+            return None;
+        }
+        let rest = value.get(i..)?;
+        Constant::ALL
+            .into_iter()
+            .map(|constant| constant.name())
+            .filter(|name| rest.starts_with(name))
+            .max_by_key(|name| name.len())
+    }
+
+    // returns the name of the function that the value starts with at index i, so that the letters
+    // of the name aren't mistaken for x or constants (such as the pi in piecewise)
+    fn function_at(value: &str, cut_index: usize, i: usize) -> Option<&'static str> {
+        if cut_index != i {
+            return None;
+        }
+        let rest = value.get(i..)?;
+        FUNCTION_NAMES
+            .into_iter()
+            .filter(|name| rest.starts_with(name) && rest[name.len()..].starts_with('('))
+            .max_by_key(|name| name.len())
+    }
+
     fn split_function(value: &str) -> Vec<&str> {
         let mut interp_slice: Vec<&str> = value.split("").collect();
         // remove the first and last element because they are just empty string slices
@@ -73,12 +113,28 @@ impl Elementary {
                 } else if interp_slice[i] == "x" {
                     chunks.push(&value[cut_index..=i]);
                     cut_index = i + 1;
-                } else if interp_slice[i] == "e" {
-                    chunks.push(&value[cut_index..=i]);
-                    cut_index = i + 1;
-                } else if interp_slice[i] == "pi" || interp_slice[i] == "π" {
-                    chunks.push("pi");
-                    cut_index = i + 1;
+                } else if let Some(name) = Self::function_at(value, cut_index, i) {
+                    // the name is pushed along with its contents once the parenthesis is closed
+                    skip = name.len() - 1;
+                } else if let Some(name) = Self::constant_at(value, cut_index, i) {
+                    chunks.push(&value[i..i + name.len()]);
+                    skip = name.len() - 1;
+                    cut_index = i + name.len();
+                } else if interp_slice[i] == "?" {
+                    // wildcards consist of a question mark followed by a name made up of letters
+                    // and digits
+                    let mut last_index = i;
+                    while last_index + 1 < interp_slice.len()
+                        && interp_slice[last_index + 1]
+                            .chars()
+                            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+                    {
+                        last_index += 1;
+                    }
+                    chunks.push(&value[cut_index..=last_index]);
+
+                    skip = last_index - i;
+                    cut_index = last_index + 1;
                 } else {
                     // checking for numbers
                     if let Ok(_) = &value[cut_index..=i].parse::<f64>() {
@@ -119,8 +175,8 @@ impl Elementary {
         let mut functions: Vec<ElemRef> = strings
             .clone()
             .iter()
-            .map(|s| Self::parse_function(s).unwrap())
-            .collect();
+            .map(|s| Self::parse_function(s))
+            .collect::<Result<_, _>>()?;
 
         let mut iteration = 0;
 
@@ -230,8 +286,20 @@ impl Elementary {
         }
 
         // check for special function (independent variable) x, and then check for constants
-        if string == "x" {
+        if let Some(name) = string.strip_prefix('?') {
+            if name.is_empty() {
+                return Err(Error::ParseError(String::from(
+                    "Wildcards must have a name",
+                )));
+            }
+            return Ok(ElemRef::Function(Wildcard(name.to_string())));
+        } else if string == "x" {
             return Ok(ElemRef::Function(X));
+        } else if let Some(constant) = Constant::from_name(&string) {
+            return Ok(ElemRef::Function(Sym(constant)));
+        } else if let Some(rational) = Rational::from_decimal(&string) {
+            // decimals are kept exact so that 0.1 + 0.2 is exactly 3/10
+            return Ok(ElemRef::Function(Elementary::from(rational)));
         } else if let Ok(number) = string.parse::<f64>() {
             return Ok(ElemRef::Function(Con(number)));
         }
@@ -243,9 +311,6 @@ impl Elementary {
             "/" => Ok(ElemRef::Div),
             "+" => Ok(ElemRef::Add),
             "-" => Ok(ElemRef::Sub),
-            // also constants
-            "e" => Ok(ElemRef::Function(Con(E))),
-            "pi" => Ok(ElemRef::Function(Con(PI))),
             "!" => Ok(ElemRef::Factorial),
             _ => {
                 // if we do not have an operation, we must have a function consisting of a function
@@ -258,6 +323,12 @@ impl Elementary {
                 graphemes.remove(0);
                 graphemes.pop();
                 let cont: String = graphemes.iter().map(|x| *x).collect();
+                // the contents are still enclosed in parentheses, which the functions with
+                // several arguments have to remove before splitting them
+                let arguments = cont
+                    .strip_prefix('(')
+                    .and_then(|cont| cont.strip_suffix(')'))
+                    .unwrap_or(&cont);
 
                 match func {
                     "sin" => Ok(ElemRef::Function(Sin(Arc::new(Self::to_elementary(
@@ -287,6 +358,15 @@ impl Elementary {
                     "atan" => Ok(ElemRef::Function(Atan(Arc::new(Self::to_elementary(
                         &cont,
                     )?)))),
+                    "acot" => Ok(ElemRef::Function(Acot(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "asec" => Ok(ElemRef::Function(Asec(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "acsc" => Ok(ElemRef::Function(Acsc(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
                     "sinh" => Ok(ElemRef::Function(Sinh(Arc::new(Self::to_elementary(
                         &cont,
                     )?)))),
@@ -296,20 +376,65 @@ impl Elementary {
                     "tanh" => Ok(ElemRef::Function(Tanh(Arc::new(Self::to_elementary(
                         &cont,
                     )?)))),
+                    "sech" => Ok(ElemRef::Function(Sech(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "csch" => Ok(ElemRef::Function(Csch(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "coth" => Ok(ElemRef::Function(Coth(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "asinh" => Ok(ElemRef::Function(Asinh(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "acosh" => Ok(ElemRef::Function(Acosh(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "atanh" => Ok(ElemRef::Function(Atanh(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
                     "ln" => Ok(ElemRef::Function(Log(
-                        Arc::new(Con(E)), //ln is equivalent to log base e of its contents
+                        Arc::new(Sym(Constant::E)), //ln is equivalent to log base e of its contents
+                        Arc::new(Self::to_elementary(&cont)?),
+                    ))),
+                    // the base comes first, as in log(2, x)
+                    "log" => match split_top_level(arguments, ',')[..] {
+                        [base, func] => Ok(ElemRef::Function(Log(
+                            Arc::new(Self::to_elementary(base)?),
+                            Arc::new(Self::to_elementary(func)?),
+                        ))),
+                        _ => Err(Error::ParseError(format!(
+                            "log takes a base and an argument, such as log(2, x), but got ({arguments}). Use ln for the natural logarithm"
+                        ))),
+                    },
+                    "exp" => Ok(ElemRef::Function(Pow(
+                        Arc::new(Sym(Constant::E)),
                         Arc::new(Self::to_elementary(&cont)?),
                     ))),
                     "abs" => Ok(ElemRef::Function(Abs(Arc::new(Self::to_elementary(
                         &cont,
                     )?)))),
+                    "sign" => Ok(ElemRef::Function(signum(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
                     "sqrt" => Ok(ElemRef::Function(Pow(
                         Arc::new(Self::to_elementary(&cont)?),
                         Arc::new(Con(0.5)),
                     ))),
+                    "cbrt" => Ok(ElemRef::Function(Cbrt(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "floor" => Ok(ElemRef::Function(Floor(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
+                    "ceil" => Ok(ElemRef::Function(Ceil(Arc::new(Self::to_elementary(
+                        &cont,
+                    )?)))),
                     "d" => Ok(ElemRef::Function(
                         Self::to_elementary(&cont)?.derivative_unsimplified(),
                     )),
+                    "piecewise" => Ok(ElemRef::Function(Self::parse_piecewise(arguments)?)),
                     _ => Err(Error::ParseError(format!(
                         "Function identifier '{func}' not recognized"
                     ))),
@@ -317,6 +442,133 @@ impl Elementary {
             }
         }
     }
+
+    // parses the branches of a piecewise function, written as "condition: value" and separated by
+    // commas, along with an optional last value that applies when none of the conditions hold
+    // (such as "x<0: -x, x"). Without it, the function is undefined where none of them hold
+    fn parse_piecewise(string: &str) -> Result<Self, Error> {
+        let parts = split_top_level(string, ',');
+        let mut branches = Vec::new();
+        let mut otherwise = None;
+        for (index, part) in parts.iter().enumerate() {
+            match split_top_level(part, ':')[..] {
+                [condition, value] => {
+                    let condition = Self::parse_condition(condition)?;
+                    branches.push((condition, Self::to_elementary(value)?));
+                }
+                [value] if index == parts.len() - 1 && !value.is_empty() => {
+                    otherwise = Some(Self::to_elementary(value)?);
+                }
+                _ => {
+                    return Err(Error::ParseError(format!(
+                        "Invalid branch '{part}' of the piecewise function: {string}"
+                    )))
+                }
+            }
+        }
+        Ok(Self::piecewise(
+            branches,
+            otherwise.unwrap_or(Con(f64::NAN)),
+        ))
+    }
+
+    fn parse_condition(string: &str) -> Result<Condition, Error> {
+        let mut depth = 0;
+        for (i, c) in string.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                '<' | '>' if depth == 0 => {
+                    // the relation is made up of every comparison character in a row, so that
+                    // malformed relations such as << are rejected rather than parsed as < <
+                    let length = string[i..]
+                        .find(|c| !matches!(c, '<' | '>' | '='))
+                        .unwrap_or(string.len() - i);
+                    let relation = match &string[i..i + length] {
+                        "<" => Relation::Less,
+                        "<=" => Relation::LessEqual,
+                        ">" => Relation::Greater,
+                        ">=" => Relation::GreaterEqual,
+                        relation => {
+                            return Err(Error::ParseError(format!(
+                                "Invalid relation '{relation}' in the condition '{string}'"
+                            )))
+                        }
+                    };
+                    let (lhs, rhs) = (&string[..i], &string[i + length..]);
+                    if lhs.is_empty() || rhs.is_empty() {
+                        return Err(Error::ParseError(format!(
+                            "The condition '{string}' must compare two functions"
+                        )));
+                    }
+                    return Ok(Condition::new(
+                        Self::to_elementary(lhs)?,
+                        relation,
+                        Self::to_elementary(rhs)?,
+                    ));
+                }
+                _ => {}
+            }
+        }
+        Err(Error::ParseError(format!(
+            "The condition '{string}' must compare two functions using <, <=, > or >="
+        )))
+    }
+}
+
+// the names of the functions that the parser recognizes
+const FUNCTION_NAMES: [&str; 32] = [
+    "sin",
+    "cos",
+    "tan",
+    "sec",
+    "csc",
+    "cot",
+    "asin",
+    "acos",
+    "atan",
+    "acot",
+    "asec",
+    "acsc",
+    "sinh",
+    "cosh",
+    "tanh",
+    "sech",
+    "csch",
+    "coth",
+    "asinh",
+    "acosh",
+    "atanh",
+    "ln",
+    "log",
+    "exp",
+    "abs",
+    "sign",
+    "sqrt",
+    "cbrt",
+    "floor",
+    "ceil",
+    "d",
+    "piecewise",
+];
+
+// splits the string at the separators that aren't enclosed in parentheses
+fn split_top_level(string: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0, 0);
+    for (i, c) in string.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            _ if c == separator && depth == 0 => {
+                parts.push(&string[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&string[start..]);
+    parts
 }
 
 // all instances of an operation must be handled before the parsing method can move on to the next.
@@ -334,10 +586,14 @@ fn iterate_operation(functions: &mut Vec<ElemRef>, operation: ElemRef) -> Result
                         Arc::new(functions[i - 1].clone().convert()?),
                         Arc::new(functions[i + 1].clone().convert()?),
                     )),
-                    ElemRef::Div => ElemRef::Function(Div(
-                        Arc::new(functions[i - 1].clone().convert()?),
-                        Arc::new(functions[i + 1].clone().convert()?),
-                    )),
+                    ElemRef::Div => {
+                        let numerator = functions[i - 1].clone().convert()?;
+                        let denominator = functions[i + 1].clone().convert()?;
+                        ElemRef::Function(
+                            exact_quotient(&numerator, &denominator)
+                                .unwrap_or_else(|| Div(Arc::new(numerator), Arc::new(denominator))),
+                        )
+                    }
                     ElemRef::Add => ElemRef::Function(Add(
                         Arc::new(functions[i + 1].clone().convert()?),
                         Arc::new(functions[i - 1].clone().convert()?),
@@ -390,6 +646,16 @@ fn iterate_operation(functions: &mut Vec<ElemRef>, operation: ElemRef) -> Result
     Ok(())
 }
 
+// the quotient of two integer literals (such as 1/3) is kept as an exact rational
+fn exact_quotient(numerator: &Elementary, denominator: &Elementary) -> Option<Elementary> {
+    let (Con(numerator), Con(denominator)) = (numerator, denominator) else {
+        return None;
+    };
+    let quotient = Rational::from_integer_f64(*numerator)?
+        .checked_div(Rational::from_integer_f64(*denominator)?)?;
+    Some(Elementary::from(quotient))
+}
+
 // enum to allow operations to be described as the same type without carrying two functions
 #[derive(Debug, Clone, PartialEq)]
 enum ElemRef {
@@ -0,0 +1,368 @@
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use crate::{
+    Elementary::{self, *},
+    Error,
+};
+
+/// An exact rational number p/q with integer numerator and denominator.
+///
+/// Rationals are used by [Elementary](crate::Elementary) to keep constants such as 1/3 exact
+/// while functions are parsed, differentiated and simplified. A rational is always stored in
+/// lowest terms with a positive denominator. Since the numerator and denominator are bounded, the
+/// arithmetic is checked, and constants are promoted to floating point numbers once an operation
+/// would overflow.
+///
+/// Example:
+/// ```rust
+/// # use number_diff::Rational;
+/// let third = Rational::new(2, 6).unwrap();
+/// assert_eq!(third.numerator(), 1);
+/// assert_eq!(third.denominator(), 3);
+///
+/// let sum = third.checked_add(Rational::new(1, 6).unwrap()).unwrap();
+/// assert_eq!(sum, Rational::new(1, 2).unwrap());
+/// assert_eq!(sum.to_string(), "1/2");
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub(crate) const HALF: Self = Self {
+        numerator: 1,
+        denominator: 2,
+    };
+
+    /// Creates the rational number numerator/denominator in lowest terms.
+    ///
+    /// If the denominator is zero (or the rational cannot be represented), an
+    /// [Error::InputError](crate::Error) is returned.
+    pub fn new(numerator: i128, denominator: i128) -> Result<Self, Error> {
+        Self::reduced(numerator, denominator).ok_or(Error::InputError(format!(
+            "The rational number {numerator}/{denominator} cannot be represented"
+        )))
+    }
+
+    pub fn integer(value: i128) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Converts the float into a rational if it is an integer (that fits in an i128). Other floats
+    /// are not converted, since their binary representations rarely match the intended decimals.
+    pub fn from_integer_f64(value: f64) -> Option<Self> {
+        // i128::MAX rounds up to 2^127 as a float, which is excluded by the strict inequality
+        if value.fract() == 0. && value.abs() < i128::MAX as f64 {
+            Some(Self::integer(value as i128))
+        } else {
+            None
+        }
+    }
+
+    /// Parses a decimal literal such as "0.25" into the exact rational 1/4. Returns None for
+    /// literals in scientific notation or literals with too many digits to be represented.
+    pub(crate) fn from_decimal(literal: &str) -> Option<Self> {
+        let (whole, fraction) = literal.split_once('.').unwrap_or((literal, ""));
+        let (sign, whole) = match whole.strip_prefix('-') {
+            Some(whole) => (-1, whole),
+            None => (1, whole),
+        };
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let digits: i128 = format!("{whole}{fraction}").parse().ok()?;
+        let denominator = 10_i128.checked_pow(u32::try_from(fraction.len()).ok()?)?;
+        Self::reduced(sign * digits, denominator)
+    }
+
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator,
+        }
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        let numerator = self
+            .numerator
+            .checked_mul(rhs.denominator)?
+            .checked_add(rhs.numerator.checked_mul(self.denominator)?)?;
+        Self::reduced(numerator, self.denominator.checked_mul(rhs.denominator)?)
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self> {
+        // cross-cancelling first keeps the intermediate products small
+        let gcd1 = gcd(self.numerator, rhs.denominator).max(1);
+        let gcd2 = gcd(rhs.numerator, self.denominator).max(1);
+        Self::reduced(
+            (self.numerator / gcd1).checked_mul(rhs.numerator / gcd2)?,
+            (self.denominator / gcd2).checked_mul(rhs.denominator / gcd1)?,
+        )
+    }
+
+    /// Returns None if the divisor is zero or if the quotient overflows.
+    pub fn checked_div(self, rhs: Self) -> Option<Self> {
+        self.checked_mul(rhs.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self {
+            numerator: self.numerator.checked_neg()?,
+            denominator: self.denominator,
+        })
+    }
+
+    /// Returns None if the rational is zero.
+    pub fn checked_recip(self) -> Option<Self> {
+        Self::reduced(self.denominator, self.numerator)
+    }
+
+    pub fn checked_pow(self, exp: i32) -> Option<Self> {
+        let base = if exp < 0 { self.checked_recip()? } else { self };
+        Some(Self {
+            numerator: base.numerator.checked_pow(exp.unsigned_abs())?,
+            denominator: base.denominator.checked_pow(exp.unsigned_abs())?,
+        })
+    }
+
+    /// Returns the greatest common divisor of the two rationals, that is, the largest rational g
+    /// such that both rationals are integer multiples of g.
+    pub fn checked_gcd(self, rhs: Self) -> Option<Self> {
+        Self::reduced(
+            gcd(
+                self.numerator.checked_mul(rhs.denominator)?,
+                rhs.numerator.checked_mul(self.denominator)?,
+            ),
+            self.denominator.checked_mul(rhs.denominator)?,
+        )
+    }
+
+    // puts the rational in lowest terms with a positive denominator
+    fn reduced(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+        let divisor = gcd(numerator, denominator) * denominator.signum();
+        Some(Self {
+            numerator: numerator.checked_div(divisor)?,
+            denominator: denominator.checked_div(divisor)?,
+        })
+    }
+}
+
+fn gcd(numb1: i128, numb2: i128) -> i128 {
+    let (mut a, mut b) = (numb1.unsigned_abs(), numb2.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // the gcd only exceeds i128::MAX for gcd(i128::MIN, 0), in which case the rational cannot be
+    // reduced anyway
+    i128::try_from(a).unwrap_or(1)
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self::integer(value)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl fmt::Debug for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+// a constant that is kept exact for as long as possible. It is promoted to a float once an
+// operation overflows or involves a float that isn't an integer
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum Number {
+    Exact(Rational),
+    Float(f64),
+}
+
+impl Number {
+    pub(crate) fn to_f64(self) -> f64 {
+        match self {
+            Self::Exact(rational) => rational.to_f64(),
+            Self::Float(numb) => numb,
+        }
+    }
+
+    pub(crate) fn is_zero(self) -> bool {
+        self.to_f64() == 0.
+    }
+
+    pub(crate) fn is_one(self) -> bool {
+        self.to_f64() == 1.
+    }
+
+    pub(crate) fn is_negative(self) -> bool {
+        self.to_f64() < 0.
+    }
+
+    pub(crate) fn is_finite(self) -> bool {
+        self.to_f64().is_finite()
+    }
+
+    pub(crate) fn abs(self) -> Self {
+        match self {
+            Self::Exact(rational) => Self::Exact(rational.abs()),
+            Self::Float(numb) => Self::Float(numb.abs()),
+        }
+    }
+
+    pub(crate) fn powi(self, exp: i32) -> Self {
+        match self {
+            Self::Exact(rational) => rational
+                .checked_pow(exp)
+                .map_or(Self::Float(rational.to_f64().powi(exp)), Self::Exact),
+            Self::Float(numb) => Self::Float(numb.powi(exp)),
+        }
+    }
+
+    // applies the exact operation if both numbers are exact, and the float operation otherwise
+    fn apply(
+        self,
+        rhs: Self,
+        exact: fn(Rational, Rational) -> Option<Rational>,
+        float: fn(f64, f64) -> f64,
+    ) -> Self {
+        match (self, rhs) {
+            (Self::Exact(rational1), Self::Exact(rational2)) => exact(rational1, rational2).map_or(
+                Self::Float(float(rational1.to_f64(), rational2.to_f64())),
+                Self::Exact,
+            ),
+            _ => Self::Float(float(self.to_f64(), rhs.to_f64())),
+        }
+    }
+}
+
+impl From<f64> for Number {
+    fn from(value: f64) -> Self {
+        Rational::from_integer_f64(value).map_or(Self::Float(value), Self::Exact)
+    }
+}
+
+impl From<Rational> for Number {
+    fn from(value: Rational) -> Self {
+        Self::Exact(value)
+    }
+}
+
+impl PartialEq for Number {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Exact(rational1), Self::Exact(rational2)) => rational1 == rational2,
+            _ => self.to_f64() == other.to_f64(),
+        }
+    }
+}
+
+impl Add for Number {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, Rational::checked_add, |a, b| a + b)
+    }
+}
+impl Sub for Number {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, Rational::checked_sub, |a, b| a - b)
+    }
+}
+impl Mul for Number {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, Rational::checked_mul, |a, b| a * b)
+    }
+}
+impl Div for Number {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self::Output {
+        self.apply(rhs, Rational::checked_div, |a, b| a / b)
+    }
+}
+impl Neg for Number {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
+        match self {
+            Self::Exact(rational) => rational
+                .checked_neg()
+                .map_or(Self::Float(-rational.to_f64()), Self::Exact),
+            Self::Float(numb) => Self::Float(-numb),
+        }
+    }
+}
+
+impl From<Rational> for Elementary {
+    fn from(value: Rational) -> Self {
+        if value.is_integer() {
+            Con(value.to_f64())
+        } else {
+            Rat(value)
+        }
+    }
+}
+
+impl Elementary {
+    // returns the value of the function if it is a number (exact or not)
+    pub(crate) fn as_number(&self) -> Option<Number> {
+        match self {
+            Con(numb) => Some(Number::from(*numb)),
+            Rat(rational) => Some(Number::Exact(*rational)),
+            _ => None,
+        }
+    }
+
+    // integers are stored as floats, since they are exact either way
+    pub(crate) fn from_number(number: Number) -> Self {
+        match number {
+            Number::Exact(rational) => Self::from(rational),
+            Number::Float(numb) => Con(numb),
+        }
+    }
+}
@@ -0,0 +1,219 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use crate::{gamma_function, polygamma_function, Dual, HyperDual, Interval, Jet, Relation};
+
+/// A number type that functions can be evaluated at using [eval()](crate::Function::eval).
+///
+/// The trait is implemented for f32, f64, [Dual](crate::Dual), [HyperDual](crate::HyperDual),
+/// [Jet](crate::Jet) and [Interval](crate::Interval), and can be implemented for other number
+/// types as well. Only the functions that can't be expressed through the others have to be
+/// implemented, while the remaining ones default to their usual identities (such as tan(x) =
+/// sin(x)/cos(x)).
+///
+/// Example:
+/// ```rust
+/// # use number_diff::Function;
+/// let function = Function::from("sin(x)^2 + x");
+///
+/// let single: f32 = function.eval(1_f32);
+/// let double: f64 = function.eval(1_f64);
+///
+/// assert!((single as f64 - double).abs() < 1e-6);
+/// ```
+pub trait Scalar:
+    Clone + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self>
+{
+    /// The constant with the provided value. The constant has the same shape as self, e.g. the
+    /// same order for a [Jet](crate::Jet).
+    fn lift(&self, value: f64) -> Self;
+
+    fn sin(&self) -> Self;
+    fn cos(&self) -> Self;
+    fn tan(&self) -> Self {
+        self.sin() / self.cos()
+    }
+    fn sec(&self) -> Self {
+        self.lift(1.) / self.cos()
+    }
+    fn csc(&self) -> Self {
+        self.lift(1.) / self.sin()
+    }
+    fn cot(&self) -> Self {
+        self.cos() / self.sin()
+    }
+    fn asin(&self) -> Self;
+    fn acos(&self) -> Self;
+    fn atan(&self) -> Self;
+    fn sinh(&self) -> Self {
+        let exp = self.exp();
+        (exp.clone() - self.lift(1.) / exp) / self.lift(2.)
+    }
+    fn cosh(&self) -> Self {
+        let exp = self.exp();
+        (exp.clone() + self.lift(1.) / exp) / self.lift(2.)
+    }
+    fn tanh(&self) -> Self {
+        self.sinh() / self.cosh()
+    }
+    fn sech(&self) -> Self {
+        self.lift(1.) / self.cosh()
+    }
+    fn csch(&self) -> Self {
+        self.lift(1.) / self.sinh()
+    }
+    fn coth(&self) -> Self {
+        self.lift(1.) / self.tanh()
+    }
+    fn acot(&self) -> Self {
+        (self.lift(1.) / self.clone()).atan()
+    }
+    fn asec(&self) -> Self {
+        (self.lift(1.) / self.clone()).acos()
+    }
+    fn acsc(&self) -> Self {
+        (self.lift(1.) / self.clone()).asin()
+    }
+    fn asinh(&self) -> Self {
+        (self.clone() + (self.clone() * self.clone() + self.lift(1.)).powf(0.5)).ln()
+    }
+    fn acosh(&self) -> Self {
+        (self.clone() + (self.clone() * self.clone() - self.lift(1.)).powf(0.5)).ln()
+    }
+    fn atanh(&self) -> Self {
+        ((self.lift(1.) + self.clone()) / (self.lift(1.) - self.clone())).ln() / self.lift(2.)
+    }
+    fn exp(&self) -> Self;
+    fn ln(&self) -> Self;
+    /// Raises the number to a constant power.
+    fn powf(&self, exp: f64) -> Self;
+    /// Raises the number to a power that isn't constant, which defaults to e^(exp ln(self)).
+    fn pow(&self, exp: &Self) -> Self {
+        (exp.clone() * self.ln()).exp()
+    }
+    fn gamma(&self) -> Self;
+    fn polygamma(&self, order: usize) -> Self;
+    fn abs(&self) -> Self;
+    /// The real cube root, which defaults to self^(1/3) and is thus only defined for non-negative
+    /// numbers unless it is implemented.
+    fn cbrt(&self) -> Self {
+        self.powf(1. / 3.)
+    }
+    /// The largest integer not greater than the number. Since it can't be expressed through the
+    /// other functions, it defaults to NaN.
+    fn floor(&self) -> Self {
+        self.lift(f64::NAN)
+    }
+    /// The smallest integer not less than the number, which defaults to NaN like
+    /// [floor()](crate::Scalar::floor).
+    fn ceil(&self) -> Self {
+        self.lift(f64::NAN)
+    }
+
+    /// Whether the number compares to 0 as the relation requires, which decides the branch of a
+    /// piecewise function that applies. None if it can't be decided, which is the default.
+    fn satisfies(&self, _relation: Relation) -> Option<bool> {
+        None
+    }
+    /// Combines the values of the branches of a piecewise function when it can't be decided
+    /// which of them applies, which defaults to NaN.
+    fn join(&self, _other: &Self) -> Self {
+        self.lift(f64::NAN)
+    }
+}
+
+/// implement Scalar for the primitive floats, which have their own versions of most functions
+macro_rules! impl_scalar_float {
+    (for $($t:ty), +) => {
+        $(impl Scalar for $t {
+            fn lift(&self, value: f64) -> Self { value as $t }
+            fn sin(&self) -> Self { <$t>::sin(*self) }
+            fn cos(&self) -> Self { <$t>::cos(*self) }
+            fn tan(&self) -> Self { <$t>::tan(*self) }
+            fn asin(&self) -> Self { <$t>::asin(*self) }
+            fn acos(&self) -> Self { <$t>::acos(*self) }
+            fn atan(&self) -> Self { <$t>::atan(*self) }
+            fn sinh(&self) -> Self { <$t>::sinh(*self) }
+            fn cosh(&self) -> Self { <$t>::cosh(*self) }
+            fn tanh(&self) -> Self { <$t>::tanh(*self) }
+            fn asinh(&self) -> Self { <$t>::asinh(*self) }
+            fn acosh(&self) -> Self { <$t>::acosh(*self) }
+            fn atanh(&self) -> Self { <$t>::atanh(*self) }
+            fn exp(&self) -> Self { <$t>::exp(*self) }
+            fn ln(&self) -> Self { <$t>::ln(*self) }
+            fn powf(&self, exp: f64) -> Self { <$t>::powf(*self, exp as $t) }
+            fn pow(&self, exp: &Self) -> Self { <$t>::powf(*self, *exp) }
+            fn gamma(&self) -> Self { gamma_function(*self as f64) as $t }
+            fn polygamma(&self, order: usize) -> Self {
+                polygamma_function(*self as f64, order) as $t
+            }
+            fn abs(&self) -> Self { <$t>::abs(*self) }
+            fn cbrt(&self) -> Self { <$t>::cbrt(*self) }
+            fn floor(&self) -> Self { <$t>::floor(*self) }
+            fn ceil(&self) -> Self { <$t>::ceil(*self) }
+            fn satisfies(&self, relation: Relation) -> Option<bool> {
+                Some(relation.holds(*self as f64, 0.))
+            }
+        })*
+    };
+}
+impl_scalar_float!(for f32, f64);
+
+/// implement Scalar by forwarding to the inherent methods of the number types
+macro_rules! impl_scalar {
+    ($($t:ty => $lift:expr, $satisfies:expr $(, $join:expr)?);+ $(;)?) => {
+        $(impl Scalar for $t {
+            fn lift(&self, value: f64) -> Self {
+                $lift(self, value)
+            }
+            fn sin(&self) -> Self { self.clone().sin() }
+            fn cos(&self) -> Self { self.clone().cos() }
+            fn tan(&self) -> Self { self.clone().tan() }
+            fn sec(&self) -> Self { self.clone().sec() }
+            fn csc(&self) -> Self { self.clone().csc() }
+            fn cot(&self) -> Self { self.clone().cot() }
+            fn asin(&self) -> Self { self.clone().asin() }
+            fn acos(&self) -> Self { self.clone().acos() }
+            fn atan(&self) -> Self { self.clone().atan() }
+            fn sinh(&self) -> Self { self.clone().sinh() }
+            fn cosh(&self) -> Self { self.clone().cosh() }
+            fn tanh(&self) -> Self { self.clone().tanh() }
+            fn asinh(&self) -> Self { self.clone().asinh() }
+            fn acosh(&self) -> Self { self.clone().acosh() }
+            fn atanh(&self) -> Self { self.clone().atanh() }
+            fn exp(&self) -> Self { self.clone().exp() }
+            fn ln(&self) -> Self { self.clone().ln() }
+            fn powf(&self, exp: f64) -> Self { self.clone().powf(exp) }
+            fn gamma(&self) -> Self { self.clone().gamma() }
+            fn polygamma(&self, order: usize) -> Self { self.clone().polygamma(order) }
+            fn abs(&self) -> Self { self.clone().abs() }
+            fn cbrt(&self) -> Self { self.clone().cbrt() }
+            fn floor(&self) -> Self { self.clone().floor() }
+            fn ceil(&self) -> Self { self.clone().ceil() }
+            fn satisfies(&self, relation: Relation) -> Option<bool> {
+                $satisfies(self, relation)
+            }
+            $(fn join(&self, other: &Self) -> Self {
+                $join(self, other)
+            })?
+        })*
+    };
+}
+impl_scalar!(
+    // the branches of the dual numbers are decided by their values
+    Dual => |_: &Dual, value| Dual::constant(value),
+        |dual: &Dual, relation: Relation| Some(relation.holds(dual.value, 0.));
+    HyperDual => |_: &HyperDual, value| HyperDual::constant(value),
+        |dual: &HyperDual, relation: Relation| Some(relation.holds(dual.value, 0.));
+    Jet => |jet: &Jet, value| Jet::constant(value, jet.order()),
+        |jet: &Jet, relation: Relation| Some(relation.holds(jet.value(), 0.));
+    // constants that aren't integers may have been rounded, so they are widened to keep the
+    // enclosures valid
+    Interval => |_: &Interval, value: f64| if value.fract() == 0. {
+        Interval::point(value)
+    } else {
+        Interval::point(value).widen(1)
+    },
+        |interval: &Interval, relation: Relation| relation.holds_on(*interval),
+        // the enclosure of a branch that may apply is joined with the others
+        |interval: &Interval, other: &Interval| interval.hull(other)
+);
@@ -0,0 +1,75 @@
+use number_diff::{Elementary, ExpressionArena};
+
+#[test]
+fn interning() {
+    let mut arena = ExpressionArena::new();
+    let function = arena.intern(&Elementary::from("sin(x)*cos(x) + sin(x)"));
+    let sin = arena.intern(&Elementary::from("sin(x)"));
+
+    // interning the same expression twice yields the same node
+    assert_eq!(sin, arena.intern(&Elementary::from("sin(x)")));
+    // x, sin, cos, the product and the sum
+    assert_eq!(arena.size(function), 5);
+    assert_eq!(arena.len(), 5);
+
+    let function = Elementary::from("tan(x^2)/x");
+    let id = arena.intern(&function);
+    assert_eq!(arena.to_elementary(id), function);
+    assert_eq!(function.dag_size(), 5);
+}
+
+#[test]
+fn differentiation() {
+    let mut arena = ExpressionArena::new();
+    let function = arena.intern(&Elementary::from("tan(x)*x^3"));
+    let derivative = arena.derivative(function);
+
+    // derivatives are memoized
+    let nodes = arena.len();
+    assert_eq!(arena.derivative(function), derivative);
+    assert_eq!(arena.len(), nodes);
+
+    let expected = Elementary::from("tan(x)*x^3")
+        .derivative_unsimplified()
+        .call();
+    for x in [-1., -0.3, 0.5, 1.2] {
+        assert!((arena.evaluate(derivative, x) - expected(x)).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn evaluation() {
+    let mut arena = ExpressionArena::new();
+    for function in [
+        "sin(x)^2 + cos(x)^2",
+        "ln(x)*e^x",
+        "x!/abs(x - 3)",
+        "atan(x)/pi",
+    ] {
+        let id = arena.intern(&Elementary::from(function));
+        let expected = Elementary::from(function).call();
+        for x in [0.5, 1.5, 2.5] {
+            assert!((arena.evaluate(id, x) - expected(x)).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn nested_taylor_expansion() {
+    let mut arena = ExpressionArena::new();
+    let mut derivative = arena.intern(&Elementary::from("tan(sin(x))"));
+    for _ in 0..10 {
+        derivative = arena.derivative(derivative);
+    }
+    // the tree of the 10th derivative would have millions of nodes
+    assert!(arena.size(derivative) < 5000);
+
+    let expansion = Elementary::from("tan(sin(x))")
+        .expand_maclaurin(10)
+        .unwrap()
+        .get_function();
+
+    for x in [-0.2, 0.1, 0.25] {
+        assert!((expansion.call(x) - x.sin().tan()).abs() < 1e-6);
+    }
+}
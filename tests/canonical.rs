@@ -0,0 +1,89 @@
+use std::{collections::HashMap, sync::Arc};
+
+use number_diff::{Canonical, Elementary, Elementary::*, Function};
+
+fn canonical(function: &str) -> Elementary {
+    Elementary::from(function).canonical()
+}
+
+#[test]
+fn like_terms() {
+    assert_eq!(
+        canonical("x + 2*x + x*3"),
+        Mul(Arc::new(Con(6.)), Arc::new(X))
+    );
+    assert_eq!(canonical("x - x"), Con(0.));
+    assert_eq!(canonical("2/x + 1/x"), Div(Arc::new(Con(3.)), Arc::new(X)));
+}
+
+#[test]
+fn term_order() {
+    // polynomials are written with their leading term first and the constant term last
+    let expected = Add(
+        Arc::new(Add(
+            Arc::new(Pow(Arc::new(X), Arc::new(Con(2.)))),
+            Arc::new(Mul(Arc::new(Con(3.)), Arc::new(X))),
+        )),
+        Arc::new(Con(1.)),
+    );
+    assert_eq!(canonical("x^2 + 1 + 3*x"), expected);
+    assert_eq!(canonical("1 + 3*x + x^2"), expected);
+    assert_eq!(canonical("3*x + x^2 + 1"), expected);
+
+    assert_eq!(canonical("cos(x)*sin(x)"), canonical("sin(x)*cos(x)"));
+}
+
+#[test]
+fn powers() {
+    assert_eq!(
+        canonical("(x+1)*(1+x)"),
+        Pow(
+            Arc::new(Add(Arc::new(X), Arc::new(Con(1.)))),
+            Arc::new(Con(2.))
+        )
+    );
+    assert_eq!(canonical("x*x^2/x"), Pow(Arc::new(X), Arc::new(Con(2.))));
+    assert_eq!(canonical("sin(x)*x*sin(x)"), canonical("x*sin(x)^2"));
+}
+
+#[test]
+fn constant_folding() {
+    assert_eq!(canonical("2*3 + x"), Add(Arc::new(X), Arc::new(Con(6.))));
+    assert_eq!(canonical("sin(pi)"), Con(0.));
+    assert_eq!(canonical("2^3"), Con(8.));
+}
+
+#[test]
+fn idempotence() {
+    for function in [
+        "x^2 + 1 + 3*x",
+        "sin(x)*cos(x) + cos(x)*sin(x)",
+        "(x+1)/(2*x)",
+        "e^x * e^(2x) - ln(x)",
+    ] {
+        let once = canonical(function);
+        assert_eq!(once.canonical(), once);
+    }
+
+    let mut function = Function::from("x + x");
+    function.canonicalize();
+    assert_eq!(function.elementary(), canonical("2*x"));
+}
+
+#[test]
+fn hashing() {
+    let mut derivatives = HashMap::new();
+    derivatives.insert(Canonical::from(Elementary::from("x*3 + x^2")), "2x + 3");
+
+    assert_eq!(
+        derivatives.get(&Canonical::from(Elementary::from("x^2 + 2*x + x"))),
+        Some(&"2x + 3")
+    );
+    assert_eq!(
+        derivatives.get(&Canonical::from(Elementary::from("x^2 + 2*x"))),
+        None
+    );
+
+    // -0 and 0 are structurally equal
+    assert_eq!(Canonical::from(Con(-0.)), Canonical::from(Con(0.)));
+}
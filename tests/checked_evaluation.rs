@@ -0,0 +1,65 @@
+use number_diff::{Elementary, EvalErrorKind, Function};
+
+#[test]
+fn agrees_with_call() {
+    for function in [
+        "sin(x)*cos(x) + tan(x/4)",
+        "sec(x) - csc(x) + cot(x)",
+        "asin(x/5) - acos(x/5) + atan(x)",
+        "sinh(x) + cosh(x) - tanh(x)",
+        "e^x + ln(x^2 + 1) + sqrt(x^2)",
+        "x^3 - 2/x + abs(x)",
+    ] {
+        let call = Elementary::from(function).call();
+        let function = Function::from(function);
+        for x in [-2.5, -0.3, 0.7, 1.9] {
+            assert_eq!(function.try_call(x).unwrap(), call(x));
+        }
+    }
+}
+
+#[test]
+fn domain_errors() {
+    for (function, x, kind, argument) in [
+        ("ln(x)", -1., EvalErrorKind::NonPositiveLogarithm, -1.),
+        ("ln(x)", 0., EvalErrorKind::NonPositiveLogarithm, 0.),
+        ("asin(x)", 2., EvalErrorKind::InverseTrigonometricRange, 2.),
+        (
+            "acos(x - 3)",
+            1.,
+            EvalErrorKind::InverseTrigonometricRange,
+            -2.,
+        ),
+        ("1/x", 0., EvalErrorKind::DivisionByZero, 0.),
+        ("x^(-2)", 0., EvalErrorKind::DivisionByZero, 0.),
+        ("sqrt(-x)", 4., EvalErrorKind::NegativeBase, -4.),
+        ("x!", -3., EvalErrorKind::NegativeIntegerFactorial, -3.),
+        ("cot(x)", 0., EvalErrorKind::Pole, 0.),
+        ("?a*x", 1., EvalErrorKind::UnboundWildcard, f64::NAN),
+    ] {
+        let error = Function::from(function).try_call(x).unwrap_err();
+        assert_eq!(error.kind, kind, "{function} at {x}");
+        assert!(
+            error.argument == argument || argument.is_nan() && error.argument.is_nan(),
+            "{function} at {x}: {}",
+            error.argument
+        );
+    }
+}
+
+#[test]
+fn innermost_subexpression() {
+    let error = Function::from("sin(ln(x - 1)) + 2")
+        .try_call(0.5)
+        .unwrap_err();
+    assert_eq!(error.subexpression, Elementary::from("ln(x - 1)"));
+    assert_eq!(error.argument, -0.5);
+}
+
+#[test]
+fn infinities_are_not_errors() {
+    // e^1000 overflows to +∞, which is a legitimate (if imprecise) value rather than an error
+    assert_eq!(Function::from("e^x").try_call(1000.), Ok(f64::INFINITY));
+    // call keeps returning NaN for domain errors
+    assert!(Function::from("ln(x)").call(-1.).is_nan());
+}
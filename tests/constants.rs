@@ -0,0 +1,81 @@
+use std::f64::consts::PI;
+
+use number_diff::{Constant, Elementary, Elementary::*};
+
+fn simplify(function: &str) -> Elementary {
+    Elementary::from(function).simplify().unwrap()
+}
+
+#[test]
+fn parsing() {
+    assert!(matches!(Elementary::from("pi"), Sym(Constant::Pi)));
+    assert!(matches!(Elementary::from("π"), Sym(Constant::Pi)));
+    assert!(matches!(Elementary::from("φ"), Sym(Constant::GoldenRatio)));
+    assert!(matches!(
+        Elementary::from("euler_gamma"),
+        Sym(Constant::EulerMascheroni)
+    ));
+    assert!(matches!(
+        Elementary::from("γ"),
+        Sym(Constant::EulerMascheroni)
+    ));
+
+    assert!(matches!(
+        Elementary::from("2π"),
+        Mul(factor1, factor2) if *factor1 == Con(2.) && matches!(*factor2, Sym(Constant::Pi))
+    ));
+    assert_eq!(Elementary::from("2*pi*x").call()(1.), 2. * PI);
+    assert_eq!(Elementary::from("tau/2").call()(0.), PI);
+
+    // the e in sec is not mistaken for the constant
+    assert!(matches!(Elementary::from("sec(x)"), Sec(_)));
+}
+
+#[test]
+fn display() {
+    assert_eq!(Constant::Pi.to_string(), "π");
+    assert_eq!(
+        Constant::from_name("supergolden"),
+        Some(Constant::SupergoldenRatio)
+    );
+    assert_eq!(Constant::from_name("ψ"), Some(Constant::SupergoldenRatio));
+
+    // the names of functions aren't taken for constants
+    for name in ["gamma", "psi", "beta"] {
+        assert_eq!(Constant::from_name(name), None);
+    }
+    assert_eq!(format!("{:?}", Elementary::from("τ")), "Sym(τ)");
+}
+
+#[test]
+fn exact_simplification() {
+    assert_eq!(simplify("ln(e)"), Con(1.));
+    assert_eq!(simplify("ln(e^3)"), Con(3.));
+    assert_eq!(simplify("sin(pi)"), Con(0.));
+    assert_eq!(simplify("pi/pi"), Con(1.));
+
+    // the constants are not evaluated
+    assert!(matches!(
+        simplify("2*pi*3"),
+        Mul(factor1, factor2) if *factor1 == Con(6.) && matches!(*factor2, Sym(Constant::Pi))
+    ));
+    assert!(matches!(
+        simplify("pi*pi"),
+        Pow(base, exp) if matches!(*base, Sym(Constant::Pi)) && *exp == Con(2.)
+    ));
+}
+
+#[test]
+fn differentiation() {
+    // d/dx e^(2x) = 2e^(2x), where e stays symbolic
+    let derivative = Elementary::from("e^(2x)").derivative().unwrap();
+    assert!(matches!(
+        &derivative,
+        Mul(factor1, factor2) if **factor1 == Con(2.)
+            && matches!(&**factor2, Pow(base, _) if matches!(**base, Sym(Constant::E)))
+    ));
+    assert_eq!(
+        Elementary::from("pi*x").derivative().unwrap(),
+        Sym(Constant::Pi)
+    );
+}
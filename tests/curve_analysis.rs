@@ -0,0 +1,104 @@
+use number_diff::{Asymptote, Function, Towards};
+
+fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-8, "{actual:?} != {expected:?}");
+    }
+}
+
+fn assert_intervals_close(actual: &[(f64, f64)], expected: &[(f64, f64)]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+    for (a, e) in actual.iter().zip(expected) {
+        assert!(
+            (a.0 - e.0).abs() < 1e-8 && (a.1 - e.1).abs() < 1e-8,
+            "{actual:?} != {expected:?}"
+        );
+    }
+}
+
+#[test]
+fn polynomial() {
+    let function = Function::from("x^3 - 3x");
+    let report = function.analyze(-3., 3.).unwrap();
+
+    assert!(report.domain_gaps.is_empty());
+    assert_close(&report.x_intercepts, &[-(3_f64.sqrt()), 0., 3_f64.sqrt()]);
+    assert_eq!(report.y_intercept, Some(0.));
+    assert_intervals_close(&report.increasing, &[(-3., -1.), (1., 3.)]);
+    assert_intervals_close(&report.decreasing, &[(-1., 1.)]);
+    assert_intervals_close(&report.concave_down, &[(-3., 0.)]);
+    assert_intervals_close(&report.concave_up, &[(0., 3.)]);
+    assert_close(&report.inflection_points, &[0.]);
+    assert!(report.asymptotes.is_empty());
+}
+
+#[test]
+fn rational() {
+    let reciprocal = Function::from("1/x");
+    let report = reciprocal.analyze(-2., 2.).unwrap();
+
+    assert_eq!(report.domain_gaps, vec![(0., 0.)]);
+    assert!(report.x_intercepts.is_empty());
+    assert_eq!(report.y_intercept, None);
+    assert!(report.increasing.is_empty());
+    assert_intervals_close(&report.decreasing, &[(-2., 0.), (0., 2.)]);
+    assert_intervals_close(&report.concave_down, &[(-2., 0.)]);
+    assert_intervals_close(&report.concave_up, &[(0., 2.)]);
+    // the concavity changes at x = 0 but the function is undefined there
+    assert!(report.inflection_points.is_empty());
+    assert_eq!(
+        report.asymptotes,
+        vec![
+            Asymptote::Vertical(0.),
+            Asymptote::Horizontal {
+                value: 0.,
+                towards: Towards::NegativeInfinity
+            },
+            Asymptote::Horizontal {
+                value: 0.,
+                towards: Towards::PositiveInfinity
+            },
+        ]
+    );
+
+    // poles that do not change the sign of the function are found as well
+    let square = Function::from("1/x^2");
+    let report = square.analyze(-1., 1.5).unwrap();
+    assert_eq!(report.domain_gaps, vec![(0., 0.)]);
+    assert!(report.asymptotes.contains(&Asymptote::Vertical(0.)));
+    assert_intervals_close(&report.increasing, &[(-1., 0.)]);
+    assert_intervals_close(&report.decreasing, &[(0., 1.5)]);
+}
+
+#[test]
+fn oblique_asymptotes() {
+    // (x² + 1)/x = x + 1/x approaches the line y = x
+    let function = Function::from("(x^2 + 1)/x");
+    let report = function.analyze(-4., 4.).unwrap();
+
+    assert!(report.asymptotes.contains(&Asymptote::Vertical(0.)));
+    for towards in [Towards::NegativeInfinity, Towards::PositiveInfinity] {
+        assert!(report.asymptotes.iter().any(|asymptote| matches!(
+            asymptote,
+            Asymptote::Oblique { slope, intercept, towards: t }
+                if (slope - 1.).abs() < 1e-8 && intercept.abs() < 1e-8 && *t == towards
+        )));
+    }
+    assert_intervals_close(&report.increasing, &[(-4., -1.), (1., 4.)]);
+    assert_intervals_close(&report.decreasing, &[(-1., 0.), (0., 1.)]);
+}
+
+#[test]
+fn domain_gaps() {
+    // ln(x) is undefined for x ≤ 0 and diverges as x ⟶ 0⁺
+    let ln = Function::from("ln(x)");
+    let report = ln.analyze(-1., 3.).unwrap();
+
+    assert_intervals_close(&report.domain_gaps, &[(-1., 0.)]);
+    assert!(report.asymptotes.contains(&Asymptote::Vertical(0.)));
+    assert_close(&report.x_intercepts, &[1.]);
+    assert_eq!(report.y_intercept, None);
+    assert_intervals_close(&report.increasing, &[(0., 3.)]);
+    assert_intervals_close(&report.concave_down, &[(0., 3.)]);
+}
@@ -0,0 +1,160 @@
+use std::{
+    f64::consts::{FRAC_PI_2, FRAC_PI_4, PI},
+    sync::Arc,
+};
+
+use number_diff::{Elementary, Function, IntervalSet, Lattice, Periodic, Span};
+
+fn domain(function: &str) -> String {
+    Function::from(function).domain().to_string()
+}
+
+#[test]
+fn rational_constraints() {
+    assert_eq!(domain("ln(x^2 - 1) + sqrt(x + 4)"), "[-4, -1) ∪ (1, ∞)");
+    assert_eq!(domain("1/x"), "(-∞, 0) ∪ (0, ∞)");
+    assert_eq!(domain("sqrt(x)/(x - 2)"), "[0, 2) ∪ (2, ∞)");
+    assert_eq!(domain("asin(x/2)"), "[-2, 2]");
+    assert_eq!(domain("1/(x^2 + 1)"), "(-∞, ∞)");
+    assert_eq!(domain("sqrt(-x^2)"), "{0}");
+    assert_eq!(domain("x^(-1/2)"), "(0, ∞)");
+    assert_eq!(domain("ln(-1)"), "∅");
+    assert_eq!(
+        domain("sqrt(x^2 - 4)/(x - 3)"),
+        "(-∞, -2] ∪ [2, 3) ∪ (3, ∞)"
+    );
+}
+
+#[test]
+fn periodic_poles() {
+    let domain = Function::from("tan(x)").domain();
+    assert_eq!(domain.excluded(), [Lattice::new(FRAC_PI_2, PI, false)]);
+    assert!(domain.contains(0.));
+    assert!(!domain.contains(FRAC_PI_2 + 3. * PI));
+
+    let domain = Function::from("cot(2*x + 1)").domain();
+    assert!(!domain.contains(-0.5));
+    assert!(!domain.contains(-0.5 + PI / 2.));
+    assert!(domain.contains(0.));
+
+    // the poles of tan(x²) are packed densely near the ends of the window
+    let domain = Function::from("tan(x^2)").domain();
+    let pole = (FRAC_PI_2 + 2813. * PI).sqrt();
+    assert!((pole - 94.01526966868138).abs() < 1e-12);
+    assert!(!domain.contains(pole) && !domain.contains(-pole));
+    assert!(domain.contains(94.) && domain.contains(0.));
+    // beyond the window, the poles can't be listed, so the domain is bounded
+    assert!(!domain.contains(150.) && domain.spans().iter().all(|span| span.hi <= 100.));
+
+    // the poles of a periodic argument repeat with its period
+    let domain = Function::from("tan(2sin(x))").domain();
+    let pole = (FRAC_PI_4).asin();
+    assert!(!domain.contains(pole + 100. * PI) && !domain.contains(-pole - 201. * PI));
+    assert!(domain.contains(1000.));
+}
+
+#[test]
+fn factorial_and_gamma() {
+    let domain = Function::from("x!").domain();
+    assert!(!domain.contains(-1.) && !domain.contains(-7.));
+    assert!(domain.contains(0.) && domain.contains(-0.5) && domain.contains(3.));
+
+    let domain = Elementary::Gamma(Arc::new(Elementary::X)).domain();
+    assert!(!domain.contains(0.) && !domain.contains(-2.));
+    assert!(domain.contains(1.));
+}
+
+#[test]
+fn numerical_constraints() {
+    assert_eq!(domain("asin(e^x)"), "(-∞, 0]");
+
+    let domain = Function::from("ln(e^x - 10)").domain();
+    assert_eq!(domain.spans().len(), 1);
+    assert!((domain.spans()[0].lo - 10_f64.ln()).abs() < 1e-12);
+    assert!(domain.spans()[0].hi.is_infinite());
+
+    // sin(x) > 0 on (2kπ, (2k + 1)π)
+    let domain = Function::from("ln(sin(x))").domain();
+    assert!(domain.contains(1.) && domain.contains(2. * PI + 1.));
+    assert!(!domain.contains(-1.) && !domain.contains(PI + 1.));
+    assert!(domain.contains(1. + 100. * PI) && !domain.contains(-1. - 100. * PI));
+    assert_eq!(
+        domain.to_string(),
+        format!("(-∞, ∞) ∩ {{x : x mod {} ∈ (0, {PI})}}", 2. * PI)
+    );
+
+    // where only isolated points are excluded, they form lattices
+    let domain = Function::from("1/sin(x)").domain();
+    assert_eq!(
+        domain.excluded(),
+        [
+            Lattice::new(0., 2. * PI, false),
+            Lattice::new(PI, 2. * PI, false)
+        ]
+    );
+}
+
+#[test]
+fn agrees_with_call() {
+    for function in [
+        "ln(x^2 - 1) + sqrt(x + 4)",
+        "acos(x^2 - 1)/(x - 1)",
+        "sqrt(x^3 - x)",
+        "x^x",
+        "tan(x)/ln(x + 5)",
+        // periodic arguments, which are solved beyond the window as well
+        "ln(sin(x))",
+        "sqrt(sin(x))",
+        "ln(cos(x) + 0.5)",
+        "sqrt(sin(2x) + cos(x))/sin(x)",
+        "ln(e^x - 10) + asin(e^(-x))",
+    ] {
+        let domain = Function::from(function).domain();
+        let call = Elementary::from(function).call();
+        for i in -100..=100 {
+            let x = i as f64 / 10. + 0.01;
+            assert_eq!(domain.contains(x), call(x).is_finite(), "{function} at {x}");
+        }
+        // far from 0, the values of x^x overflow rather than being undefined
+        for i in -1000..=1000 {
+            let x = i as f64 * PI / 3. + 0.01;
+            assert_eq!(domain.contains(x), !call(x).is_nan(), "{function} at {x}");
+        }
+    }
+}
+
+#[test]
+fn interval_sets() {
+    let set = IntervalSet::from_spans(vec![
+        Span::open(3., 4.),
+        Span::closed(0., 1.),
+        Span::open(1., 2.),
+        Span::point(2.),
+    ]);
+    assert_eq!(set.to_string(), "[0, 2] ∪ (3, 4)");
+
+    let intersection = set.intersection(&Span::new(1.5, 3.5, false, true).into());
+    assert_eq!(intersection.to_string(), "(1.5, 2] ∪ (3, 3.5]");
+    assert!(!intersection.contains(3.));
+
+    let punctured = intersection.without_points(&[2.]);
+    assert_eq!(punctured.to_string(), "(1.5, 2) ∪ (3, 3.5]");
+
+    // periodic restrictions are resolved into spans on the bounded parts of a set
+    let periodic = IntervalSet::real_line().restricted(Periodic::new(vec![Span::open(0., 1.)], 2.));
+    assert!(periodic.contains(4.5) && !periodic.contains(-0.5));
+    assert_eq!(
+        periodic
+            .intersection(&Span::closed(0., 4.).into())
+            .to_string(),
+        "(0, 1) ∪ (2, 3)"
+    );
+    // and only restrict the points of the union that the other set doesn't contain
+    let union = periodic
+        .intersection(&Span::open(f64::NEG_INFINITY, 0.).into())
+        .union(&Span::new(0., f64::INFINITY, true, false).into());
+    assert!(union.contains(1.5) && union.contains(-1.5) && !union.contains(-0.5));
+
+    assert!(IntervalSet::real_line().is_real_line());
+    assert!(IntervalSet::empty().is_empty());
+}
@@ -0,0 +1,213 @@
+use number_diff::{
+    acosh, asinh, cbrt, exp, floor, log, sech, BigFloat, Direction, Dual, Elementary,
+    EvalErrorKind, ExpressionArena, Function, Integrate, Interval,
+};
+
+const NAMES: [&str; 15] = [
+    "exp", "asinh", "acosh", "atanh", "sech", "csch", "coth", "acot", "asec", "acsc", "cbrt",
+    "floor", "ceil", "sign", "ln",
+];
+
+// an argument that keeps the function within its domain
+fn argument(name: &str) -> &'static str {
+    if name == "atanh" {
+        "sin(x)/2"
+    } else {
+        "x^2 + 1"
+    }
+}
+
+#[test]
+fn parsing() {
+    let call = Elementary::from("exp(x)").call();
+    assert_eq!(call(1.), std::f64::consts::E);
+
+    // the base of the logarithm comes first
+    let call = Elementary::from("log(2, x) + log(x, 27)").call();
+    assert!((call(3.) - (3_f64.log2() + 3.)).abs() < 1e-12);
+
+    let call = Elementary::from("floor(x) + ceil(x) + sign(x - 1)").call();
+    assert_eq!(call(0.5), 0.);
+    assert_eq!(call(1.), 2.);
+    assert_eq!(call(2.5), 6.);
+
+    // the letters of the names aren't taken for constants or x
+    for name in NAMES {
+        let call = Elementary::from(&format!("{name}({})", argument(name))[..]).call();
+        assert!(call(0.5).is_finite(), "{name}");
+    }
+
+    assert_eq!(
+        Elementary::from("cbrt(-8) + acot(1) * 4/pi").call()(0.),
+        -1.
+    );
+}
+
+#[test]
+fn helpers() {
+    let call = exp(Function::from("2x")).elementary().call();
+    assert_eq!(call(0.5), std::f64::consts::E);
+
+    let call = log(Function::from("10"), Function::from("x"))
+        .elementary()
+        .call();
+    assert!((call(1000.) - 3.).abs() < 1e-12);
+
+    let call = asinh(sech(Function::default())).elementary().call();
+    assert!((call(0.) - 1_f64.asinh()).abs() < 1e-12);
+
+    assert_eq!(acosh(Function::from("1")).elementary().call()(0.), 0.);
+    assert_eq!(cbrt(Function::from("x^2")).elementary().call()(-8.), 4.);
+    assert_eq!(floor(Function::from("-x")).elementary().call()(0.5), -1.);
+}
+
+#[test]
+fn derivatives() {
+    let h = 1e-6;
+    for name in NAMES {
+        let function = Elementary::from(&format!("{name}({})", argument(name))[..]);
+        let call = function.clone().call();
+        let derivative = function.clone().derivative().unwrap().call();
+
+        let mut arena = ExpressionArena::new();
+        let id = arena.intern(&function);
+        let arena_derivative = arena.derivative(id);
+        let tape = function.record_tape();
+
+        for x in [-0.7, 0.4, 1.2] {
+            let numeric = (call(x + h) - call(x - h)) / (2. * h);
+            assert!((derivative(x) - numeric).abs() < 1e-5, "{name} at {x}");
+            assert!((arena.evaluate(arena_derivative, x) - numeric).abs() < 1e-5);
+            assert!((tape.value_and_gradient(&[x]).unwrap().1[0] - numeric).abs() < 1e-5);
+            assert!((function.eval(Dual::variable(x)).derivative - numeric).abs() < 1e-5);
+        }
+    }
+
+    // the logarithm of any base
+    let derivative = Elementary::from("log(2, x)").derivative().unwrap().call();
+    assert!((derivative(4.) - 1. / (4. * 2_f64.ln())).abs() < 1e-12);
+}
+
+#[test]
+fn simplification() {
+    assert_eq!(
+        Elementary::from("asinh(sinh(x))").simplify().unwrap(),
+        Elementary::X
+    );
+    assert_eq!(
+        Elementary::from("tanh(atanh(x))").simplify().unwrap(),
+        Elementary::X
+    );
+    assert_eq!(
+        Elementary::from("cbrt(x^3)").simplify().unwrap(),
+        Elementary::X
+    );
+    assert_eq!(
+        Elementary::from("floor(ceil(x))").simplify().unwrap(),
+        Elementary::from("ceil(x)")
+    );
+    assert_eq!(
+        Elementary::from("exp(ln(x))").simplify().unwrap(),
+        Elementary::X
+    );
+}
+
+#[test]
+fn evaluation() {
+    // enclosures of the monotonic functions are exact up to rounding
+    let enclosure = Function::from("atanh(x)").eval_interval(0., 0.5);
+    assert!(enclosure.lo <= 0. && enclosure.hi >= 0.5_f64.atanh());
+    assert_eq!(
+        Function::from("floor(x)").eval(Interval::new(0.5, 2.5)),
+        Interval::new(0., 2.)
+    );
+    let enclosure = Function::from("sech(x)").eval_interval(-1., 1.);
+    assert!(enclosure.lo <= 1_f64.cosh().recip() && enclosure.hi >= 1.);
+
+    assert_eq!(
+        Function::from("cbrt(x)")
+            .eval_precise(-27., 30)
+            .unwrap()
+            .to_f64(),
+        -3.
+    );
+    assert_eq!(
+        Elementary::from("floor(x)")
+            .eval_precise(&BigFloat::from_decimal("-2.5", 20).unwrap())
+            .unwrap()
+            .to_f64(),
+        -3.
+    );
+    assert_eq!(
+        Function::from("asinh(x)")
+            .eval_precise(1., 30)
+            .unwrap()
+            .to_f64(),
+        1_f64.asinh()
+    );
+
+    let error = Elementary::from("acosh(x)").try_call(0.5).unwrap_err();
+    assert_eq!(error.kind, EvalErrorKind::InverseHyperbolicRange);
+    let error = Elementary::from("coth(x)").try_call(0.).unwrap_err();
+    assert_eq!(error.kind, EvalErrorKind::Pole);
+    let error = Elementary::from("asec(x)").try_call(0.5).unwrap_err();
+    assert_eq!(error.kind, EvalErrorKind::InverseTrigonometricRange);
+}
+
+#[test]
+fn domains() {
+    assert_eq!(Function::from("acosh(x)").domain().to_string(), "[1, ∞)");
+    assert_eq!(
+        Function::from("atanh(x - 1)").domain().to_string(),
+        "(0, 2)"
+    );
+    assert_eq!(
+        Function::from("asec(x)").domain().to_string(),
+        "(-∞, -1] ∪ [1, ∞)"
+    );
+    assert_eq!(
+        Function::from("coth(x)").domain().to_string(),
+        "(-∞, 0) ∪ (0, ∞)"
+    );
+
+    // the restrictions on the step functions are solved exactly
+    assert_eq!(
+        Function::from("1/floor(x)").domain().to_string(),
+        "(-∞, 0) ∪ [1, ∞)"
+    );
+    assert_eq!(Function::from("ln(ceil(x))").domain().to_string(), "(0, ∞)");
+    assert_eq!(
+        Function::from("cot(floor(x/2))").domain().to_string(),
+        "(-∞, 0) ∪ [2, ∞)"
+    );
+    assert!(Function::from("tan(ceil(x))").domain().is_real_line());
+
+    assert!(Function::from("acot(x) + cbrt(x) + sech(x)")
+        .domain()
+        .is_real_line());
+}
+
+#[test]
+fn limits() {
+    assert_eq!(Function::from("acot(x)").limit_at_infinity().unwrap(), 0.);
+    assert_eq!(Function::from("coth(x)").limit_at_infinity().unwrap(), 1.);
+    assert_eq!(
+        Function::from("sech(x)")
+            .limit_at_negative_infinity()
+            .unwrap(),
+        0.
+    );
+
+    let floor = Function::from("floor(x)");
+    assert_eq!(floor.limit(1., Direction::Left).unwrap(), 0.);
+    assert_eq!(floor.limit(1., Direction::Right).unwrap(), 1.);
+    assert!(floor.limit(1., Direction::Both).is_err());
+}
+
+#[test]
+fn integration() {
+    // the integral is split at each jump of the rounding functions
+    assert_eq!(Function::from("floor(x)").evaluate_integral(0., 3.), 3.);
+    assert_eq!(Function::from("ceil(x)").evaluate_integral(-0.5, 2.), 3.);
+    assert_eq!(Function::from("cbrt(x)").evaluate_integral(-1., 8.), 11.25);
+}
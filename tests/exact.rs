@@ -0,0 +1,132 @@
+use std::sync::Arc;
+
+use number_diff::{Elementary, Elementary::*, Rational};
+
+fn rational(numerator: i128, denominator: i128) -> Elementary {
+    Rat(Rational::new(numerator, denominator).unwrap())
+}
+
+#[test]
+fn arithmetic() {
+    let third = Rational::new(-2, -6).unwrap();
+    assert_eq!((third.numerator(), third.denominator()), (1, 3));
+    assert_eq!(Rational::new(3, -6).unwrap().to_string(), "-1/2");
+    assert!(Rational::new(1, 0).is_err());
+
+    let sixth = Rational::new(1, 6).unwrap();
+    assert_eq!(third.checked_add(sixth), Rational::new(1, 2).ok());
+    assert_eq!(third.checked_sub(sixth), Some(sixth));
+    assert_eq!(third.checked_mul(sixth), Rational::new(1, 18).ok());
+    assert_eq!(third.checked_div(sixth), Some(Rational::integer(2)));
+    assert_eq!(third.checked_pow(-2), Some(Rational::integer(9)));
+    assert_eq!(sixth.checked_div(Rational::integer(0)), None);
+
+    // overflows are reported instead of wrapping
+    assert_eq!(Rational::integer(i128::MAX).checked_add(third), None);
+}
+
+#[test]
+fn parsing() {
+    assert_eq!(Elementary::from("0.25"), rational(1, 4));
+    assert!(matches!(Elementary::from("0.25"), Rat(_)));
+    // integers are still stored as floats
+    assert!(matches!(Elementary::from("3"), Con(_)));
+    assert!(matches!(Elementary::from("2.0"), Con(_)));
+
+    // quotients of integer literals are exact as well
+    assert_eq!(Elementary::from("1/3"), rational(1, 3));
+    assert!(matches!(Elementary::from("2/4"), Rat(value) if value == Rational::new(1, 2).unwrap()));
+    assert!(matches!(Elementary::from("4/2"), Con(_)));
+    assert_eq!(Elementary::from("x/3"), Div(Arc::new(X), Arc::new(Con(3.))));
+}
+
+#[test]
+fn folding() {
+    assert_eq!(
+        Elementary::from("1/3 + 1/6").simplify().unwrap(),
+        rational(1, 2)
+    );
+    assert!(matches!(
+        Elementary::from("0.1 + 0.2").simplify().unwrap(),
+        Rat(value) if value == Rational::new(3, 10).unwrap()
+    ));
+    assert_eq!(
+        Elementary::from("(2/3)^3").simplify().unwrap(),
+        rational(8, 27)
+    );
+    // the coefficients of the canonical form are exact as well
+    assert_eq!(
+        Elementary::from("x/3 + x/6").canonical(),
+        Mul(Arc::new(rational(1, 2)), Arc::new(X))
+    );
+}
+
+#[test]
+fn differentiation() {
+    // d/dx x^(1/3) = 1/3 x^(-2/3)
+    let derivative = Elementary::from("x^(1/3)").derivative().unwrap();
+    assert_eq!(
+        derivative,
+        Mul(
+            Arc::new(rational(1, 3)),
+            Arc::new(Pow(Arc::new(X), Arc::new(rational(-2, 3))))
+        )
+    );
+    assert!((derivative.call()(8.) - 1. / 12.).abs() < 1e-12);
+
+    // the power rule lowers rational exponents exactly, before any simplification
+    assert_eq!(
+        Elementary::from("x^(2/3)").derivative_unsimplified(),
+        Mul(
+            Arc::new(Mul(
+                Arc::new(rational(2, 3)),
+                Arc::new(Pow(Arc::new(X), Arc::new(rational(-1, 3))))
+            )),
+            Arc::new(Con(1.))
+        )
+    );
+
+    // d/dx (x^3/6 + x) = 1/2 x^2 + 1
+    assert_eq!(
+        Elementary::from("x^3/6 + x").derivative().unwrap(),
+        Add(
+            Arc::new(Mul(
+                Arc::new(rational(1, 2)),
+                Arc::new(Pow(Arc::new(X), Arc::new(Con(2.))))
+            )),
+            Arc::new(Con(1.))
+        )
+    );
+}
+
+#[test]
+fn series() {
+    // sin(x) ≈ x - 1/6 x^3
+    let series = Elementary::from("sin(x)")
+        .expand_maclaurin(3)
+        .unwrap()
+        .get_elementary();
+    assert_eq!(
+        series,
+        Add(
+            Arc::new(Mul(
+                Arc::new(rational(-1, 6)),
+                Arc::new(Pow(Arc::new(X), Arc::new(Con(3.))))
+            )),
+            Arc::new(X)
+        )
+    );
+}
+
+#[test]
+fn promotion() {
+    // constants that can't be represented exactly are evaluated as floats
+    let huge = Elementary::from("(1/3)^100").simplify().unwrap();
+    assert!(matches!(huge, Con(_)));
+    assert!((huge.call()(0.) - (1. / 3_f64).powi(100)).abs() < 1e-60);
+
+    assert!(matches!(
+        Elementary::from("1/3 + sin(1)").simplify().unwrap(),
+        Con(_)
+    ));
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use number_diff::{Constant, Elementary, Elementary::*, Function};
+
+fn simplify(function: &str) -> Elementary {
+    Function::from(function).elementary().simplify().unwrap()
+}
+
+fn exp(function: Elementary) -> Elementary {
+    Pow(Arc::new(Sym(Constant::E)), Arc::new(function))
+}
+
+fn ln(function: Elementary) -> Elementary {
+    Log(Arc::new(Sym(Constant::E)), Arc::new(function))
+}
+
+fn times(coefficient: f64, function: Elementary) -> Elementary {
+    Mul(Arc::new(Con(coefficient)), Arc::new(function))
+}
+
+#[test]
+fn inverses() {
+    assert_eq!(simplify("e^(ln(x))"), X);
+    assert_eq!(simplify("ln(e^(2x))"), times(2., X));
+}
+
+#[test]
+fn logarithm_rules() {
+    // log of powers (the absolute value keeps the expression defined for negative x)
+    assert_eq!(simplify("ln(x^3)"), times(3., ln(X)));
+    assert_eq!(simplify("ln(x^2)"), times(2., ln(Abs(Arc::new(X)))));
+
+    // log of products where one of the factors is known to be positive (where ln(2) is kept exact)
+    assert_eq!(
+        simplify("ln(2x)"),
+        Add(Arc::new(ln(Con(2.))), Arc::new(ln(X)))
+    );
+
+    // change of base
+    let plus_one = Add(Arc::new(X), Arc::new(Con(1.)));
+    let quotient = Div(Arc::new(ln(X)), Arc::new(ln(plus_one.clone())));
+    assert_eq!(
+        quotient.simplify().unwrap(),
+        Log(Arc::new(plus_one), Arc::new(X))
+    );
+}
+
+#[test]
+fn combining_exponentials() {
+    assert_eq!(simplify("e^x * e^(2x)"), exp(times(3., X)));
+    assert_eq!(simplify("e^(2x)/e^x"), exp(X));
+    assert_eq!(simplify("(e^x)^2"), exp(times(2., X)));
+}
+
+#[test]
+fn readable_derivatives() {
+    let derivative = |function: &str| Function::from(function).elementary().derivative().unwrap();
+
+    assert_eq!(derivative("e^x"), exp(X));
+    assert_eq!(derivative("ln(x)"), Div(Arc::new(Con(1.)), Arc::new(X)));
+    assert_eq!(derivative("ln(x^3)"), Div(Arc::new(Con(3.)), Arc::new(X)));
+    assert_eq!(derivative("e^x * e^(2x)"), times(3., exp(times(3., X))));
+    assert_eq!(
+        derivative("2^x"),
+        Mul(
+            Arc::new(Pow(Arc::new(Con(2.)), Arc::new(X))),
+            Arc::new(ln(Con(2.)))
+        )
+    );
+
+    // logarithms of composite functions, where ln(e) = 1 and 0/e = 0 must not be left over
+    assert_eq!(derivative("ln(sin(x))"), Cot(Arc::new(X)));
+    assert_eq!(derivative("ln(e^x)"), Con(1.));
+    assert_eq!(
+        derivative("ln(x^2 + 1)"),
+        Div(
+            Arc::new(times(2., X)),
+            Arc::new(Add(
+                Arc::new(Pow(Arc::new(X), Arc::new(Con(2.)))),
+                Arc::new(Con(1.))
+            ))
+        )
+    );
+
+    // logarithms with other constant bases are divided by the natural logarithm of the base
+    let log2 = derivative("log(2, x^2 + 1)").call();
+    assert!((log2(3.) - 6. / (10. * 2_f64.ln())).abs() < 1e-12);
+}
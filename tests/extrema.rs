@@ -0,0 +1,68 @@
+use std::f64::consts::PI;
+
+use number_diff::{CriticalPointKind, Function};
+
+#[test]
+fn critical_points() {
+    let cubic = Function::from("x^3 - 3x");
+    let points = cubic.critical_points(-3., 3.).unwrap();
+    assert_eq!(points.len(), 2);
+    assert!((points[0].x + 1.).abs() < 1e-10);
+    assert!((points[0].value - 2.).abs() < 1e-10);
+    assert_eq!(points[0].kind, CriticalPointKind::LocalMaximum);
+    assert!((points[1].x - 1.).abs() < 1e-10);
+    assert!((points[1].value + 2.).abs() < 1e-10);
+    assert_eq!(points[1].kind, CriticalPointKind::LocalMinimum);
+
+    let sin = Function::from("sin(x)");
+    let points = sin.critical_points(0., 2. * PI).unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].kind, CriticalPointKind::LocalMaximum);
+    assert_eq!(points[1].kind, CriticalPointKind::LocalMinimum);
+
+    // constant functions have no critical points to classify
+    let constant = Function::from("5");
+    assert!(constant.critical_points(-1., 1.).unwrap().is_empty());
+}
+
+#[test]
+fn higher_order_classification() {
+    // the second derivative vanishes at x = 0 for all of these functions
+    let saddle = Function::from("x^3");
+    let points = saddle.critical_points(-1., 1.).unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].kind, CriticalPointKind::Saddle);
+
+    let minimum = Function::from("x^4");
+    let points = minimum.critical_points(-1., 1.).unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].kind, CriticalPointKind::LocalMinimum);
+
+    let maximum = Function::from("1 - x^4");
+    let points = maximum.critical_points(-1., 1.).unwrap();
+    assert_eq!(points.len(), 1);
+    assert_eq!(points[0].kind, CriticalPointKind::LocalMaximum);
+}
+
+#[test]
+fn global_extrema() {
+    let cubic = Function::from("x^3 - 3x");
+
+    // the local maximum is the global maximum on a small enough interval
+    let (x, value) = cubic.global_max_on(-1.5, 1.5).unwrap();
+    assert!((x + 1.).abs() < 1e-10);
+    assert!((value - 2.).abs() < 1e-10);
+
+    // on a larger interval the endpoints win
+    assert_eq!(cubic.global_max_on(-3., 3.).unwrap(), (3., 18.));
+    assert_eq!(cubic.global_min_on(-3., 3.).unwrap(), (-3., -18.));
+
+    let (x, value) = cubic.global_min_on(0., 3.).unwrap();
+    assert!((x - 1.).abs() < 1e-10);
+    assert!((value + 2.).abs() < 1e-10);
+
+    // monotonic functions attain their extrema at the endpoints
+    let exponential = Function::from("e^x");
+    assert_eq!(exponential.global_min_on(0., 1.).unwrap().0, 0.);
+    assert_eq!(exponential.global_max_on(0., 1.).unwrap().0, 1.);
+}
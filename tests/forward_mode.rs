@@ -0,0 +1,83 @@
+use number_diff::{Dual, Elementary, Function, HyperDual, Jet};
+
+const FUNCTIONS: [&str; 14] = [
+    "sin(x^2)",
+    "cos(3*x)",
+    "tan(x^2)",
+    "sec(x)*csc(x)",
+    "cot(2*x)",
+    "asin(x/2)",
+    "acos(x/3)",
+    "atan(x^3)",
+    "sinh(x)*cosh(x)",
+    "tanh(x^2)",
+    "ln(x)*e^x",
+    "x^x",
+    "x!",
+    "abs(x - 1)",
+];
+
+#[test]
+fn dual_numbers() {
+    assert_eq!(Dual::variable(3.) * Dual::variable(3.), Dual::new(9., 6.));
+    assert_eq!(
+        Elementary::from("x^3").call_dual(Dual::variable(2.)),
+        Dual::new(8., 12.)
+    );
+
+    let hyper_dual = Elementary::from("x^3").call_hyper_dual(HyperDual::variable(2.));
+    assert_eq!(hyper_dual.value, 8.);
+    assert_eq!(hyper_dual.derivative(), 12.);
+    assert_eq!(hyper_dual.second_derivative(), 12.);
+}
+
+// the symbolic derivatives of differentiation.rs should agree with automatic differentiation
+#[test]
+fn symbolic_derivatives() {
+    for function in FUNCTIONS {
+        let symbolic = Elementary::from(function).derivative_unsimplified().call();
+        let second_symbolic = Elementary::from(function)
+            .derivative_unsimplified()
+            .derivative_unsimplified()
+            .call();
+        let function = Function::from(function);
+
+        for x in [0.3, 0.7, 1.4] {
+            let (value, derivative) = function.value_and_derivative(x);
+            assert!((value - function.call(x)).abs() < 1e-12);
+            assert!((derivative - symbolic(x)).abs() < 1e-9 * symbolic(x).abs().max(1.));
+
+            let (_, first, second) = function.value_and_second_derivative(x);
+            assert!((first - derivative).abs() < 1e-12 * derivative.abs().max(1.));
+            assert!((second - second_symbolic(x)).abs() < 1e-8 * second.abs().max(1.));
+        }
+    }
+}
+
+#[test]
+fn higher_derivatives() {
+    // the derivatives of sin cycle through cos, -sin, -cos and sin
+    let derivatives = Function::from("sin(x)").value_and_derivatives(1., 8);
+    for (k, derivative) in derivatives.iter().enumerate() {
+        let expected = [1_f64.sin(), 1_f64.cos(), -1_f64.sin(), -1_f64.cos()][k % 4];
+        assert!((derivative - expected).abs() < 1e-12);
+    }
+
+    // the jets agree with the hyper-dual numbers for the first two derivatives
+    for function in FUNCTIONS {
+        let function = Function::from(function);
+        let derivatives = function.value_and_derivatives(0.6, 2);
+        let (value, first, second) = function.value_and_second_derivative(0.6);
+        assert!((derivatives[0] - value).abs() < 1e-12);
+        assert!((derivatives[1] - first).abs() < 1e-9 * first.abs().max(1.));
+        assert!((derivatives[2] - second).abs() < 1e-9 * second.abs().max(1.));
+    }
+
+    // d⁴/dx⁴ x^5 = 120x
+    assert_eq!(
+        Elementary::from("x^5")
+            .call_jet(&Jet::variable(2., 5))
+            .derivatives(),
+        vec![32., 80., 160., 240., 240., 120.]
+    );
+}
@@ -0,0 +1,131 @@
+use std::f64::consts::PI;
+
+use number_diff::{Elementary, Function, Interval};
+
+// checks that the enclosure contains the value of the function at points sampled from the interval
+fn assert_encloses(function: &str, lo: f64, hi: f64) {
+    let enclosure = Function::from(function).eval_interval(lo, hi);
+    let call = Elementary::from(function).call();
+    for i in 0..=200 {
+        let x = lo + (hi - lo) * i as f64 / 200.;
+        let value = call(x);
+        if value.is_finite() {
+            assert!(
+                enclosure.contains(value),
+                "{function}({x}) = {value} is not in {enclosure}"
+            );
+        }
+    }
+}
+
+#[test]
+fn enclosures() {
+    for function in [
+        "sin(x)*cos(x)",
+        "tan(x/4)",
+        "sec(x)",
+        "asin(x/5)",
+        "acos(x/5)",
+        "atan(x^3)",
+        "sinh(x) - cosh(x)",
+        "tanh(2*x)",
+        "e^x/x",
+        "x^x",
+        "x^3 - x^2",
+        "abs(x - 1)",
+        "sqrt(x)",
+        "ln(x)",
+    ] {
+        assert_encloses(function, -3., 4.);
+        assert_encloses(function, 0.25, 0.75);
+        assert_encloses(function, 1.5, 1.5);
+    }
+}
+
+#[test]
+fn trigonometric() {
+    // sin attains its maximum of 1 at π/2
+    let sin = Function::from("sin(x)").eval_interval(0., PI);
+    assert_eq!(sin.hi, 1.);
+    assert!(sin.lo <= 0. && sin.lo > -1e-15);
+
+    // on [2, 3], cos is decreasing
+    let cos = Function::from("cos(x)").eval_interval(2., 3.);
+    assert!(cos.lo <= 3_f64.cos() && cos.lo > 3_f64.cos() - 1e-15);
+    assert!(cos.hi >= 2_f64.cos() && cos.hi < 2_f64.cos() + 1e-15);
+
+    // the periodicity holds far away from 0
+    let sin = Function::from("sin(x)").eval_interval(1e6, 1e6 + 3.);
+    assert!(sin.contains(1.) || sin.contains(-1.));
+
+    assert!(Function::from("tan(x)").eval_interval(1., 2.).is_entire());
+    assert!(!Function::from("tan(x)").eval_interval(-1., 1.).is_entire());
+}
+
+#[test]
+fn division_and_domains() {
+    assert!(Function::from("1/x").eval_interval(-1., 1.).is_entire());
+    assert_eq!(
+        Function::from("1/x").eval_interval(0., 2.).hi,
+        f64::INFINITY
+    );
+    assert!(Function::from("1/x").eval_interval(0., 2.).lo > 0.49);
+
+    // ln and sqrt are only evaluated where they are defined
+    assert!(Function::from("ln(x)").eval_interval(-2., -1.).is_empty());
+    let ln = Function::from("ln(x)").eval_interval(-2., 1.);
+    assert_eq!(ln.lo, f64::NEG_INFINITY);
+    assert!(ln.hi >= 0.);
+    let sqrt = Function::from("sqrt(x)").eval_interval(-4., 4.);
+    assert_eq!(sqrt.lo, 0.);
+    assert!(sqrt.hi >= 2. && sqrt.hi < 2. + 1e-14);
+
+    // the even power of an interval containing 0
+    let square = Function::from("x^2").eval_interval(-3., 2.);
+    assert_eq!(square.lo, 0.);
+    assert!(square.hi >= 9.);
+}
+
+#[test]
+fn outward_rounding() {
+    // 0.1 + 0.2 isn't exactly 0.3 in floating point, but the enclosure contains both
+    let sum = Interval::point(0.1) + Interval::point(0.2);
+    assert!(sum.contains(0.1 + 0.2) && sum.contains(0.3));
+    assert!(sum.width() > 0.);
+
+    let pi = Function::from("pi").eval_interval(0., 1.);
+    assert!(pi.contains(PI) && pi.width() > 0.);
+}
+
+#[test]
+fn taylor_error_bound() {
+    // the remainder of the degree 3 Maclaurin polynomial of sin on [0, 1/2] is bounded by
+    // max|sin⁽⁴⁾| * (1/2)^4 / 4!
+    let fourth = Function::from("sin(x)")
+        .elementary()
+        .derivative_unsimplified();
+    let fourth = fourth
+        .derivative_unsimplified()
+        .derivative_unsimplified()
+        .derivative_unsimplified();
+    let bound = fourth.eval_interval(Interval::new(0., 0.5)).abs().hi * 0.5_f64.powi(4) / 24.;
+
+    let error = (0.5_f64.sin() - (0.5 - 0.5_f64.powi(3) / 6.)).abs();
+    assert!(error <= bound);
+    assert!(bound < 2e-3);
+}
+
+#[test]
+fn gamma() {
+    // the bounds enclose the exact values of 𝜞 and 𝝍m
+    assert!(Interval::point(0.5).gamma().contains(PI.sqrt()));
+    assert!(Function::from("x!").eval_interval(4., 4.).contains(24.));
+    assert!(Interval::point(1.).polygamma(1).contains(PI * PI / 6.));
+
+    // 𝜞 has its positive minimum between 1 and 2
+    let gamma = Interval::new(1., 2.).gamma();
+    assert!(gamma.lo < 0.8857 && gamma.lo > 0.8856);
+    assert!(gamma.contains(1.));
+
+    assert!(Interval::new(-1.5, 0.5).gamma().is_entire());
+}
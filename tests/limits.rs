@@ -0,0 +1,76 @@
+use std::f64::consts::PI;
+
+use number_diff::{Direction, Function, Round};
+
+#[test]
+fn removable_singularities() {
+    let sinc = Function::from("sin(x)/x");
+    assert_eq!(sinc.limit(0., Direction::Both).unwrap(), 1.);
+
+    let versine = Function::from("(1 - cos(x))/x^2");
+    assert_eq!(
+        versine.limit(0., Direction::Both).unwrap().round_to(10),
+        0.5
+    );
+
+    // the integrand used by the digamma function, (1 - x^(z-1))/(1 - x), has a removable
+    // singularity at x = 1 where it approaches z - 1
+    let digamma_integrand = Function::from("(1 - x^2.5)/(1 - x)");
+    assert_eq!(
+        digamma_integrand
+            .limit(1., Direction::Both)
+            .unwrap()
+            .round_to(10),
+        2.5
+    );
+}
+
+#[test]
+fn one_sided_limits() {
+    let x_ln_x = Function::from("x*ln(x)");
+    assert_eq!(x_ln_x.limit(0., Direction::Right).unwrap(), 0.);
+
+    // x*ln(x) is undefined for x < 0
+    assert!(x_ln_x.limit(0., Direction::Left).is_err());
+
+    let reciprocal = Function::from("1/x");
+    assert!(reciprocal.limit(0., Direction::Left).is_err());
+    assert!(reciprocal.limit(0., Direction::Right).is_err());
+    assert!(reciprocal.limit(0., Direction::Both).is_err());
+
+    let step = Function::from("abs(x)/x");
+    assert_eq!(step.limit(0., Direction::Left).unwrap(), -1.);
+    assert_eq!(step.limit(0., Direction::Right).unwrap(), 1.);
+    assert!(step.limit(0., Direction::Both).is_err());
+}
+
+#[test]
+fn limits_at_infinity() {
+    let rational = Function::from("(2x + 1)/(x + 3)");
+    assert_eq!(rational.limit_at_infinity().unwrap(), 2.);
+    assert_eq!(rational.limit_at_negative_infinity().unwrap(), 2.);
+
+    let compound_interest = Function::from("(1 + 1/x)^x");
+    assert_eq!(
+        compound_interest.limit_at_infinity().unwrap().round_to(8),
+        2.71828183
+    );
+
+    let atan = Function::from("atan(x)");
+    assert_eq!(atan.limit_at_infinity().unwrap(), PI / 2.);
+    assert_eq!(atan.limit_at_negative_infinity().unwrap(), -PI / 2.);
+
+    let decay = Function::from("x^2/e^x");
+    assert_eq!(decay.limit_at_infinity().unwrap(), 0.);
+
+    let damped = Function::from("sin(x)/x");
+    assert_eq!(damped.limit_at_infinity().unwrap(), 0.);
+
+    // e^(-x) x^100 evaluates to 0 * ∞ = NaN far out, even though it is defined there
+    let overflow = Function::from("e^(-x)*x^100");
+    assert_eq!(overflow.limit_at_infinity().unwrap(), 0.);
+
+    // divergent and oscillating limits are reported as errors
+    assert!(Function::from("x^2").limit_at_infinity().is_err());
+    assert!(Function::from("sin(x)").limit_at_infinity().is_err());
+}
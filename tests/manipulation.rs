@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use number_diff::{Elementary, Elementary::*, Function};
+
+// makes sure that the manipulated function yields the same values as the original, in the same way
+// as the simplification methods are checked
+fn assert_equivalent(function: &str, manipulated: &Elementary) {
+    let original = Function::from(function);
+    let callable = Function::from(manipulated.clone());
+    for i in -1000..1000 {
+        let x = i as f64 / 10.;
+        let expected = original.call(x);
+        if expected.is_finite() {
+            let margin = (expected * 0.01).abs() + 1e-5;
+            assert!(
+                (callable.call(x) - expected).abs() < margin,
+                "{function} and {manipulated:?} differ at {x}"
+            );
+        }
+    }
+}
+
+fn canonical(function: &str) -> Elementary {
+    Elementary::from(function).canonical()
+}
+
+#[test]
+fn expand() {
+    for function in [
+        "(x + 1)^3",
+        "(x + 1)*(x - 1)",
+        "(sin(x) + 2)*(x + cos(x))",
+        "(x + 1)^2/x",
+        "ln(x^2*(x + 1)^2)",
+    ] {
+        let expanded = Elementary::from(function).expand().unwrap();
+        assert_equivalent(function, &expanded);
+    }
+
+    assert_eq!(
+        Elementary::from("(x + 1)*(x - 1)").expand().unwrap(),
+        Sub(
+            Arc::new(Pow(Arc::new(X), Arc::new(Con(2.)))),
+            Arc::new(Con(1.))
+        )
+    );
+
+    let mut function = Function::from("(x + 1)^2/x");
+    function.expand().unwrap();
+    assert_eq!(function.elementary(), canonical("x + 2 + 1/x"));
+}
+
+#[test]
+fn factor() {
+    for function in [
+        "x^2 - 1",
+        "(2*x^2 - 8*x) + 8",
+        "x^3 + x",
+        "x*sin(x) + x^2",
+        "4*sin(x) + 6*cos(x)",
+        "(x^2 - 1)/(x + 1)",
+    ] {
+        let factored = Elementary::from(function).factorize().unwrap();
+        assert_equivalent(function, &factored);
+    }
+
+    assert_eq!(
+        Elementary::from("(2*x^2 - 8*x) + 8").factorize().unwrap(),
+        canonical("2*(x - 2)^2")
+    );
+    assert_eq!(
+        Elementary::from("x*sin(x) + x^2").factorize().unwrap(),
+        canonical("x*(x + sin(x))")
+    );
+    // common factors of the numerator and the denominator are cancelled out
+    assert_eq!(
+        Elementary::from("(x^2 - 1)/(x + 1)").factorize().unwrap(),
+        canonical("x - 1")
+    );
+
+    let mut function = Function::from("x^3 + x");
+    function.factor().unwrap();
+    assert_eq!(function.elementary(), canonical("x*(x^2 + 1)"));
+}
+
+#[test]
+fn collect() {
+    for function in [
+        "x*sin(x) + 2*x + x^2",
+        "(x + sin(x))^2",
+        "x^2*sin(x) + x*sin(x)^2 + sin(x)",
+    ] {
+        for variable in [X, Sin(Arc::new(X))] {
+            let collected = Elementary::from(function).collect(&variable).unwrap();
+            assert_equivalent(function, &collected);
+        }
+    }
+
+    assert_eq!(
+        Elementary::from("x*sin(x) + 2*x + x^2")
+            .collect(&X)
+            .unwrap(),
+        canonical("x^2 + x*(sin(x) + 2)")
+    );
+    let mut function = Function::from("x^2*sin(x) + x*sin(x)^2 + sin(x)");
+    function.collect(&Sin(Arc::new(X))).unwrap();
+    assert_eq!(
+        function.elementary(),
+        canonical("x*sin(x)^2 + (x^2 + 1)*sin(x)")
+    );
+}
+
+#[test]
+fn together() {
+    for function in [
+        "1/x + 1/(x + 1)",
+        "1/x^2 - 1/x",
+        "sin(x)/x + 1",
+        "1/(1 + 1/x)",
+    ] {
+        let combined = Elementary::from(function).together().unwrap();
+        assert_equivalent(function, &combined);
+    }
+
+    let mut function = Function::from("sin(x)/x + 1");
+    function.together().unwrap();
+    assert_eq!(function.elementary(), canonical("(x + sin(x))/x"));
+}
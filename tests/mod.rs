@@ -1,11 +1,83 @@
+#[cfg(test)]
+mod arena;
+
+#[cfg(test)]
+mod forward_mode;
+
 #[cfg(test)]
 mod functions;
 
+#[cfg(test)]
+mod canonical;
+
+#[cfg(test)]
+mod checked_evaluation;
+
+#[cfg(test)]
+mod constants;
+
+#[cfg(test)]
+mod curve_analysis;
+
+#[cfg(test)]
+mod domain;
+
+#[cfg(test)]
+mod elementary;
+
+#[cfg(test)]
+mod exact;
+
 #[cfg(test)]
 mod expansion;
 
+#[cfg(test)]
+mod exponential;
+
+#[cfg(test)]
+mod extrema;
+
 #[cfg(test)]
 mod integration;
 
+#[cfg(test)]
+mod interval;
+
+#[cfg(test)]
+mod limits;
+
+#[cfg(test)]
+mod manipulation;
+
+#[cfg(test)]
+mod piecewise;
+
+#[cfg(test)]
+mod polynomial;
+
+#[cfg(test)]
+mod precise;
+
+#[cfg(test)]
+mod range;
+
+#[cfg(test)]
+mod rational;
+
+#[cfg(test)]
+mod roots;
+
+#[cfg(test)]
+mod reverse_mode;
+
 #[cfg(test)]
 mod rounding;
+
+#[cfg(test)]
+mod rules;
+
+#[cfg(test)]
+mod scalar;
+
+#[cfg(test)]
+mod trigonometry;
@@ -0,0 +1,154 @@
+use number_diff::{
+    heaviside, max, min, sign, Condition, Direction, Dual, Elementary, Error, ExpressionArena,
+    Function, Integrate, Interval, Relation, Rule,
+};
+
+#[test]
+fn parsing() {
+    let call = Elementary::from("piecewise(x<0: -x, x^2)").call();
+    assert_eq!(call(-2.), 2.);
+    assert_eq!(call(3.), 9.);
+
+    // the first branch whose condition holds applies
+    let call = Elementary::from("piecewise(x <= 1: 2x, x > 3: 5, 0) + 1").call();
+    assert_eq!(call(1.), 3.);
+    assert_eq!(call(2.), 1.);
+    assert_eq!(call(4.), 6.);
+
+    // without an otherwise value, the function is undefined where no condition holds
+    let call = Elementary::from("piecewise(sin(x) >= 0: 1)").call();
+    assert_eq!(call(1.), 1.);
+    assert!(call(-1.).is_nan());
+
+    assert_eq!(
+        Elementary::from("piecewise(x<0: 0, 1)"),
+        Elementary::piecewise(
+            vec![(
+                Condition::new(Elementary::X, Relation::Less, Elementary::Con(0.)),
+                Elementary::Con(0.)
+            )],
+            Elementary::Con(1.)
+        )
+    );
+
+    // malformed relations and conditions with a missing side are parse errors (rules are parsed
+    // without panicking, so they are used to get at the error)
+    for condition in ["x << 0", "x <> 0", "x >== 0", "x <", "< 0"] {
+        assert!(matches!(
+            Rule::parse(&format!("piecewise({condition}: 1, 2) -> 0")),
+            Err(Error::ParseError(_))
+        ));
+    }
+}
+
+#[test]
+fn helpers() {
+    let relu = max(Function::default(), Function::from("0"))
+        .elementary()
+        .call();
+    assert_eq!(relu(-1.), 0.);
+    assert_eq!(relu(2.), 2.);
+
+    let minimum = min(Function::from("x^2"), Function::from("x"))
+        .elementary()
+        .call();
+    assert_eq!(minimum(0.5), 0.25);
+    assert_eq!(minimum(-1.), -1.);
+
+    let step = heaviside(Function::from("x - 1")).elementary().call();
+    assert_eq!((step(0.), step(1.), step(2.)), (0., 1., 1.));
+
+    let sign = sign(Function::from("x^3")).elementary().call();
+    assert_eq!((sign(-2.), sign(0.), sign(2.)), (-1., 0., 1.));
+}
+
+#[test]
+fn derivatives() {
+    // every branch is differentiated under the same condition
+    let derivative = Elementary::from("piecewise(x<0: -x, x^2)")
+        .derivative()
+        .unwrap()
+        .call();
+    assert_eq!(derivative(-3.), -1.);
+    assert_eq!(derivative(3.), 6.);
+
+    // the derivative of |f(x)| is sign(f(x)) f'(x), which is defined where f(x) = 0
+    let derivative = Elementary::from("abs(x^2 - 1)")
+        .derivative()
+        .unwrap()
+        .call();
+    assert_eq!(derivative(2.), 4.);
+    assert_eq!(derivative(0.), 0.);
+    assert_eq!(derivative(1.), 0.);
+
+    let mut arena = ExpressionArena::new();
+    let function = arena.intern(&Elementary::from("abs(x^2 - 1)"));
+    let derivative = arena.derivative(function);
+    assert_eq!(arena.evaluate(derivative, -2.), -4.);
+    assert_eq!(arena.evaluate(derivative, 1.), 0.);
+
+    let tape = Elementary::from("piecewise(x<1: x^2, 3x)").record_tape();
+    assert_eq!(tape.value_and_gradient(&[0.5]).unwrap(), (0.25, vec![1.]));
+    assert_eq!(tape.value_and_gradient(&[2.]).unwrap(), (6., vec![3.]));
+
+    let dual = Function::from("piecewise(x<1: x^2, 3x)").eval(Dual::variable(0.5));
+    assert_eq!((dual.value, dual.derivative), (0.25, 1.));
+}
+
+#[test]
+fn integration() {
+    // the integrals are split at the jump of the step function and the kink of |x|
+    assert_eq!(
+        heaviside(Function::default()).evaluate_integral(-1., 2.),
+        2.
+    );
+    assert_eq!(Function::from("abs(x)").evaluate_integral(-1., 2.), 2.5);
+    // ∫ from 2 to 0 = -(1/3 + 9/2), rounded to five significant figures
+    assert_eq!(
+        Function::from("piecewise(x<1: x^2, 3x)").evaluate_integral(2., 0.),
+        -4.8333
+    );
+}
+
+#[test]
+fn limits() {
+    let step = Function::from("piecewise(x<0: 0, 1)");
+    assert_eq!(step.limit(0., Direction::Left).unwrap(), 0.);
+    assert_eq!(step.limit(0., Direction::Right).unwrap(), 1.);
+
+    let ramp = Function::from("piecewise(x<0: 0, x)");
+    assert_eq!(ramp.limit(0., Direction::Both).unwrap(), 0.);
+}
+
+#[test]
+fn evaluation() {
+    let step = Function::from("piecewise(x<0: 0, 1)");
+    // enclosures contain every branch that may apply
+    assert_eq!(step.eval_interval(-1., 1.), Interval::new(0., 1.));
+    assert_eq!(step.eval_interval(0.5, 1.), Interval::point(1.));
+    assert_eq!(step.eval(Interval::new(-2., -1.)), Interval::point(0.));
+    assert_eq!(step.eval_precise(-1., 30).unwrap().to_f64(), 0.);
+
+    // the conditions must be defined to tell which branch applies
+    let function = Elementary::from("piecewise(ln(x) < 0: 1, 2)");
+    assert_eq!(function.try_call(0.5).unwrap(), 1.);
+    assert!(function.try_call(-1.).is_err());
+}
+
+#[test]
+fn domains() {
+    // every branch only has to be defined where it applies
+    assert!(Function::from("piecewise(x<0: ln(-x), sqrt(x))")
+        .domain()
+        .is_real_line());
+    assert_eq!(
+        Function::from("piecewise(x<0: 1/(x + 1), sqrt(x - 1))")
+            .domain()
+            .to_string(),
+        "(-∞, -1) ∪ (-1, 0) ∪ [1, ∞)"
+    );
+
+    let domain = Function::from("piecewise(x>0: x, tan(x))").domain();
+    assert!(domain.contains(0.) && domain.contains(std::f64::consts::FRAC_PI_2));
+    assert!(!domain.contains(-std::f64::consts::FRAC_PI_2));
+}
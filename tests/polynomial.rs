@@ -0,0 +1,111 @@
+use number_diff::{Elementary, Function, Polynomial};
+
+#[test]
+fn conversion() {
+    let function = Function::from("3x^3 + 5 - 2x");
+    let polynomial = Polynomial::try_from(&function).unwrap();
+    assert_eq!(polynomial.coefficients(), &[5., -2., 0., 3.]);
+    assert_eq!(polynomial.degree(), 3);
+
+    let expanded = Polynomial::try_from(&Function::from("(x - 1)*(x + 2)^2")).unwrap();
+    assert_eq!(expanded.coefficients(), &[-4., 0., 3., 1.]);
+
+    let constant = Polynomial::try_from(&Function::from("2*3")).unwrap();
+    assert_eq!(constant.coefficients(), &[6.]);
+
+    // converting back yields the same function
+    let back = Function::from(polynomial.clone());
+    for i in -10..10 {
+        assert_eq!(back.call(i as f64), function.call(i as f64));
+    }
+    assert_eq!(Elementary::from(Polynomial::zero()), Elementary::Con(0.));
+
+    // non-polynomials cannot be converted
+    assert!(Polynomial::try_from(&Function::from("sin(x)")).is_err());
+    assert!(Polynomial::try_from(&Function::from("1/(x + 1)")).is_err());
+}
+
+#[test]
+fn arithmetic() {
+    let p = Polynomial::new(vec![1., 2.]); // 1 + 2x
+    let q = Polynomial::new(vec![-1., 0., 1.]); // x² - 1
+
+    assert_eq!((p.clone() + q.clone()).coefficients(), &[0., 2., 1.]);
+    assert_eq!((p.clone() - q.clone()).coefficients(), &[2., 2., -1.]);
+    assert_eq!((p.clone() * q.clone()).coefficients(), &[-1., -2., 1., 2.]);
+    assert_eq!((p.clone() * 3.).coefficients(), &[3., 6.]);
+
+    // leading zeros cancel out
+    assert_eq!((q.clone() - q.clone()), Polynomial::zero());
+    assert!(Polynomial::new(vec![1., 0., 0.]).degree() == 0);
+
+    // Horner's method
+    assert_eq!(q.call(3.), 8.);
+    assert_eq!(Polynomial::zero().call(3.), 0.);
+
+    // p(q(x)) = 1 + 2(x² - 1) = 2x² - 1
+    assert_eq!(p.compose(&q).coefficients(), &[-1., 0., 2.]);
+    // q(p(x)) = (1 + 2x)² - 1 = 4x² + 4x
+    assert_eq!(q.compose(&p).coefficients(), &[0., 4., 4.]);
+}
+
+#[test]
+fn calculus() {
+    let polynomial = Polynomial::new(vec![1., 2., 3., 4.]);
+
+    assert_eq!(polynomial.derivative().coefficients(), &[2., 6., 12.]);
+    assert_eq!(
+        polynomial.derivative().integral().coefficients(),
+        &[0., 2., 3., 4.]
+    );
+    assert_eq!(Polynomial::constant(5.).derivative(), Polynomial::zero());
+    assert_eq!(
+        Polynomial::new(vec![0., 2.]).integral().coefficients(),
+        &[0., 0., 1.]
+    );
+}
+
+#[test]
+fn division() {
+    // x³ - 2x² - 4 = (x - 3)(x² + x + 3) + 5
+    let dividend = Polynomial::new(vec![-4., 0., -2., 1.]);
+    let divisor = Polynomial::new(vec![-3., 1.]);
+    let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+    assert_eq!(quotient.coefficients(), &[3., 1., 1.]);
+    assert_eq!(remainder.coefficients(), &[5.]);
+
+    // exact division leaves no remainder
+    let (quotient, remainder) = Polynomial::new(vec![-1., 0., 1.])
+        .div_rem(&Polynomial::new(vec![1., 1.]))
+        .unwrap();
+    assert_eq!(quotient.coefficients(), &[-1., 1.]);
+    assert!(remainder.is_zero());
+
+    // dividing by a polynomial of higher degree
+    let (quotient, remainder) = divisor.div_rem(&dividend).unwrap();
+    assert!(quotient.is_zero());
+    assert_eq!(remainder, divisor);
+
+    assert!(dividend.div_rem(&Polynomial::zero()).is_err());
+}
+
+#[test]
+fn gcd() {
+    // (x - 1)(x + 2) and (x - 1)(x - 3) share the factor x - 1
+    let p = Polynomial::new(vec![-1., 1.]) * Polynomial::new(vec![2., 1.]);
+    let q = Polynomial::new(vec![-1., 1.]) * Polynomial::new(vec![-3., 1.]) * 2.;
+    assert_eq!(p.gcd(&q).coefficients(), &[-1., 1.]);
+
+    // (x + 1)²(x - 2) and its derivative share the factor x + 1
+    let p = Polynomial::new(vec![1., 1.])
+        * Polynomial::new(vec![1., 1.])
+        * Polynomial::new(vec![-2., 1.]);
+    let gcd = p.gcd(&p.derivative());
+    assert_eq!(gcd.degree(), 1);
+    assert!((gcd.coefficients()[0] - 1.).abs() < 1e-12);
+
+    // coprime polynomials
+    let p = Polynomial::new(vec![1., 0., 1.]);
+    let q = Polynomial::new(vec![-1., 1.]);
+    assert_eq!(p.gcd(&q).coefficients(), &[1.]);
+}
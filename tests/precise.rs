@@ -0,0 +1,166 @@
+use number_diff::{BigFloat, BigUint, Constant, Elementary, Function};
+
+// the first 60 significant digits of each constant
+const DIGITS: [(Constant, &str); 7] = [
+    (
+        Constant::Pi,
+        "3.14159265358979323846264338327950288419716939937510582097494",
+    ),
+    (
+        Constant::E,
+        "2.71828182845904523536028747135266249775724709369995957496697",
+    ),
+    (
+        Constant::Tau,
+        "6.28318530717958647692528676655900576839433879875021164194989",
+    ),
+    (
+        Constant::GoldenRatio,
+        "1.61803398874989484820458683436563811772030917980576286213545",
+    ),
+    (
+        Constant::SilverRatio,
+        "2.41421356237309504880168872420969807856967187537694807317668",
+    ),
+    (
+        Constant::SupergoldenRatio,
+        "1.46557123187676802665673122521993910802557756847228570164318",
+    ),
+    (
+        Constant::EulerMascheroni,
+        "0.577215664901532860606512090082402431042159335939923598805767",
+    ),
+];
+
+#[test]
+fn constants() {
+    for (constant, digits) in DIGITS {
+        assert_eq!(
+            BigFloat::constant(constant, 60).unwrap().to_string(),
+            digits,
+            "{constant:?}"
+        );
+        // the f64 constants of the crate agree with the precise values
+        assert_eq!(
+            BigFloat::constant(constant, 60).unwrap().to_f64(),
+            constant.value(),
+            "{constant:?}"
+        );
+    }
+
+    assert_eq!(
+        BigFloat::constant(Constant::Bernstein, 20)
+            .unwrap()
+            .to_string(),
+        "0.28016949902386913303"
+    );
+    assert!(BigFloat::constant(Constant::Bernstein, 21).is_err());
+}
+
+#[test]
+fn elementary_functions() {
+    for (function, x, value) in [
+        ("sin(x)", 1e6, "-0.3499935021712929521176524867807714690614"),
+        ("ln(x)", 10., "2.302585092994045684017991454684364207601"),
+        ("4*atan(x)", 1., "3.141592653589793238462643383279502884197"),
+        ("acos(x)", -1., "3.141592653589793238462643383279502884197"),
+        ("tanh(x)", 3., "0.9950547536867304513318801852554884750978"),
+        ("x^0.5", 2., "1.414213562373095048801688724209698078570"),
+        (
+            "e^x",
+            -100.,
+            "3.720075976020835962959695803863118337359e-44",
+        ),
+        ("x!", 3., "6.000000000000000000000000000000000000000"),
+    ] {
+        assert_eq!(
+            Function::from(function)
+                .eval_precise(x, 40)
+                .unwrap()
+                .to_string(),
+            value,
+            "{function} at {x}"
+        );
+    }
+}
+
+#[test]
+fn identities() {
+    let x = BigFloat::from_decimal("0.7", 80).unwrap();
+    let one = BigFloat::one(80);
+    let tolerance = BigFloat::from_decimal("1e-75", 80).unwrap();
+
+    let (sin, cos) = x.sin_cos();
+    let pythagoras = sin.clone() * sin + cos.clone() * cos - one.clone();
+    assert!(pythagoras.abs() < tolerance);
+
+    let round_trip = x.exp().unwrap().ln().unwrap() - x.clone();
+    assert!(round_trip.abs() < tolerance);
+
+    let hyperbolic = x.cosh().unwrap().powi(2) - x.sinh().unwrap().powi(2) - one;
+    assert!(hyperbolic.abs() < tolerance);
+
+    // 𝜞(1/2)² = π
+    let half = BigFloat::from_decimal("0.5", 80).unwrap();
+    let gamma = half.gamma().unwrap();
+    let pi = BigFloat::constant(Constant::Pi, 80).unwrap();
+    assert!((gamma.clone() * gamma - pi).abs() < tolerance);
+}
+
+#[test]
+fn exact_arguments() {
+    // 1/3 is exact as a rational, unlike the nearest f64
+    let third = Elementary::from("1/3");
+    let value = Elementary::from("x*3")
+        .eval_precise(&third.eval_precise(&BigFloat::zero(30)).unwrap())
+        .unwrap();
+    assert_eq!(value.to_string(), "1.00000000000000000000000000000");
+}
+
+#[test]
+fn domain_errors() {
+    for (function, x) in [
+        ("ln(x)", 0.),
+        ("sqrt(x)", -1.),
+        ("asin(x)", 2.),
+        ("1/x", 0.),
+    ] {
+        assert!(
+            Function::from(function).eval_precise(x, 20).is_err(),
+            "{function} at {x}"
+        );
+    }
+    assert!(BigFloat::from_integer(-2, 20).gamma().is_err());
+}
+
+#[test]
+fn formatting() {
+    assert_eq!(
+        BigFloat::from_decimal("1.5e-3", 5).unwrap().to_string(),
+        "0.0015000"
+    );
+    assert_eq!(
+        BigFloat::from_decimal("123456789012", 5)
+            .unwrap()
+            .to_string(),
+        "1.2346e11"
+    );
+    assert_eq!(
+        format!("{:.5}", BigFloat::constant(Constant::Pi, 50).unwrap()),
+        "3.1416"
+    );
+    assert_eq!(BigFloat::from_integer(-42, 10).to_string(), "-42.00000000");
+}
+
+#[test]
+fn big_uint() {
+    let a = BigUint::from(u128::MAX);
+    let b = BigUint::from(12345678901234567890_u64);
+    let (quotient, remainder) = a.pow(3).div_rem(&b);
+    assert_eq!(&(&quotient * &b) + &remainder, a.pow(3));
+    assert!(remainder < b);
+    assert_eq!(
+        BigUint::from(2_u64).pow(100).to_string(),
+        "1267650600228229401496703205376"
+    );
+}
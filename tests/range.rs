@@ -0,0 +1,112 @@
+use number_diff::{Elementary, Error, Function, RangeMode};
+
+#[test]
+fn rigorous_bounds() {
+    for (function, a, b) in [
+        ("sin(x) + x^2/10", -3., 3.),
+        ("x^3 - 3x", -2., 2.),
+        ("e^(-x^2)*cos(10x)", -1., 1.),
+        ("x^x", 0.1, 2.),
+    ] {
+        let call = Elementary::from(function).call();
+        let function = Function::from(function);
+        let (min, max) = function.range_on(a, b, RangeMode::Rigorous).unwrap();
+        let (sampled_min, sampled_max) = function.range_on(a, b, RangeMode::Sampled).unwrap();
+
+        // the bounds contain every value and are close to the attained extrema
+        for i in 0..=1000 {
+            let value = call(a + (b - a) * i as f64 / 1000.);
+            assert!(min <= value && value <= max);
+        }
+        let (attained_min, attained_max) = (
+            function.global_min_on(a, b).unwrap().1,
+            function.global_max_on(a, b).unwrap().1,
+        );
+        assert!(attained_min - min < 1e-8 && max - attained_max < 1e-8);
+        assert!(min <= sampled_min && sampled_max <= max);
+    }
+
+    let (min, max) = Function::from("x^3 - 3x")
+        .range_on(-2., 2., RangeMode::Rigorous)
+        .unwrap();
+    assert!((min + 2.).abs() < 1e-8 && (max - 2.).abs() < 1e-8);
+}
+
+#[test]
+fn sampled_estimates() {
+    let function = Function::from("x^2");
+    assert_eq!(
+        function.range_on(-1., 2., RangeMode::Sampled).unwrap(),
+        (0., 4.)
+    );
+
+    // the sampled points miss the peaks of fast oscillations
+    let (_, max) = Function::from("sin(500x)")
+        .range_on(0., 1., RangeMode::Sampled)
+        .unwrap();
+    assert!(max < 1.);
+}
+
+#[test]
+fn singularities() {
+    let (min, max) = Function::from("1/x")
+        .range_on(-1., 1., RangeMode::Rigorous)
+        .unwrap();
+    assert!(min == f64::NEG_INFINITY && max == f64::INFINITY);
+
+    // the points where ln(x) isn't defined are ignored
+    let (min, max) = Function::from("ln(x)")
+        .range_on(-1., 2., RangeMode::Rigorous)
+        .unwrap();
+    assert_eq!(min, f64::NEG_INFINITY);
+    assert!(max >= 2_f64.ln() && max - 2_f64.ln() < 1e-12);
+}
+
+#[test]
+fn step_functions() {
+    // the slope of 0 between the jumps doesn't bound the values across them
+    let (min, max) = Function::from("floor(x)")
+        .range_on(-1., 2., RangeMode::Rigorous)
+        .unwrap();
+    assert!(min <= -1. && max >= 2.);
+    assert!(min > -1. - 1e-8 && max < 2. + 1e-8);
+
+    let (min, max) = Function::from("piecewise(x < 0.4: 0, x < 0.45: -7, 0)")
+        .range_on(0., 1., RangeMode::Rigorous)
+        .unwrap();
+    assert!(min <= -7. && max >= 0.);
+    assert!(min > -7. - 1e-8 && max < 1e-8);
+}
+
+#[test]
+fn derivative_bounds() {
+    // |f⁽⁴⁾| ≤ 1 for f = sin(x), as used in the Lagrange remainder of its Taylor series
+    let mut derivative = Function::from("sin(x)");
+    for _ in 0..4 {
+        derivative.differentiate();
+    }
+    let (min, max) = derivative.range_on(0., 1., RangeMode::Rigorous).unwrap();
+    assert!(min <= 0. && max >= 1_f64.sin());
+    assert!(max < 1_f64.sin() + 1e-8);
+}
+
+#[test]
+fn invalid_input() {
+    let function = Function::from("ln(x)");
+    assert!(matches!(
+        function.range_on(-2., -1., RangeMode::Rigorous),
+        Err(Error::InputError(_))
+    ));
+    assert!(matches!(
+        function.range_on(-2., -1., RangeMode::Sampled),
+        Err(Error::InputError(_))
+    ));
+    assert!(function.range_on(2., 1., RangeMode::Sampled).is_err());
+    assert!(function
+        .range_on(0., f64::INFINITY, RangeMode::Rigorous)
+        .is_err());
+
+    // the bounds are rounded outward even on a single point
+    let (min, max) = function.range_on(1., 1., RangeMode::Rigorous).unwrap();
+    assert!(min <= 0. && 0. <= max && max - min < 1e-300);
+}
@@ -0,0 +1,148 @@
+use number_diff::{Elementary, Function, PartialFraction, Polynomial, RationalFunction};
+
+#[test]
+fn lowest_terms() {
+    // (x² - 1)/(x² + 2x + 1) = (x - 1)/(x + 1)
+    let function = Function::from("(x^2 - 1)/(x^2 + 2x + 1)");
+    let rational = RationalFunction::try_from(&function).unwrap();
+    assert_eq!(rational.numerator(), &Polynomial::new(vec![-1., 1.]));
+    assert_eq!(rational.denominator(), &Polynomial::new(vec![1., 1.]));
+
+    // the denominator is made monic
+    let rational =
+        RationalFunction::new(Polynomial::new(vec![1.]), Polynomial::new(vec![2., 4.])).unwrap();
+    assert_eq!(rational.numerator(), &Polynomial::constant(0.25));
+    assert_eq!(rational.denominator(), &Polynomial::new(vec![0.5, 1.]));
+
+    // sums of fractions are put over a common denominator
+    let sum = RationalFunction::try_from(&Function::from("1/x + 1/(x + 1)")).unwrap();
+    assert_eq!(sum.numerator(), &Polynomial::new(vec![1., 2.]));
+    assert_eq!(sum.denominator(), &Polynomial::new(vec![0., 1., 1.]));
+    assert_eq!(sum.call(1.), 1.5);
+
+    assert!(RationalFunction::new(Polynomial::constant(1.), Polynomial::zero()).is_err());
+    assert!(RationalFunction::try_from(&Function::from("sin(x)/x")).is_err());
+}
+
+#[test]
+fn simplification() {
+    let function = Function::from("(x^3 - x)/(x^2 + x)");
+    let simplified = function.elementary().simplify().unwrap();
+
+    // x(x - 1)(x + 1)/(x(x + 1)) = x - 1
+    assert_eq!(
+        Polynomial::try_from(&simplified).unwrap(),
+        Polynomial::new(vec![-1., 1.])
+    );
+}
+
+#[test]
+fn partial_fractions() {
+    // (x + 3)/(x² - 3x + 2) = 5/(x - 2) - 4/(x - 1)
+    let rational = RationalFunction::try_from(&Function::from("(x + 3)/(x^2 + 2 - 3x)")).unwrap();
+    let decomposition = rational.partial_fractions().unwrap();
+    assert!(decomposition.polynomial.is_zero());
+    let expected = [(-4., 1.), (5., 2.)];
+    assert_eq!(decomposition.terms.len(), 2);
+    for (term, (expected_coefficient, expected_root)) in decomposition.terms.iter().zip(expected) {
+        match term {
+            PartialFraction::Linear {
+                coefficient,
+                root,
+                power,
+            } => {
+                assert!((coefficient - expected_coefficient).abs() < 1e-10);
+                assert!((root - expected_root).abs() < 1e-10);
+                assert_eq!(*power, 1);
+            }
+            _ => panic!("expected a linear term, found {term:?}"),
+        }
+    }
+
+    // repeated and quadratic factors along with a polynomial part
+    // (x⁴ + 1)/((x - 1)²(x² + 1))
+    let numerator = Polynomial::new(vec![1., 0., 0., 0., 1.]);
+    let denominator = Polynomial::new(vec![-1., 1.])
+        * Polynomial::new(vec![-1., 1.])
+        * Polynomial::new(vec![1., 0., 1.]);
+    let rational = RationalFunction::new(numerator, denominator).unwrap();
+    let decomposition = rational.partial_fractions().unwrap();
+    assert_eq!(decomposition.polynomial, Polynomial::constant(1.));
+    assert_eq!(decomposition.terms.len(), 3);
+    // the roots ±i of x² + 1 come first since the roots are sorted by their real parts
+    assert!(matches!(
+        decomposition.terms[0],
+        PartialFraction::Quadratic { power: 1, .. }
+    ));
+    assert!(matches!(
+        decomposition.terms[1],
+        PartialFraction::Linear { power: 1, .. }
+    ));
+    assert!(matches!(
+        decomposition.terms[2],
+        PartialFraction::Linear { power: 2, .. }
+    ));
+
+    // the decomposition represents the same function
+    let recombined = Function::from(Elementary::from(decomposition));
+    for i in -20..20 {
+        let x = i as f64 * 0.37;
+        assert!(
+            (recombined.call(x) - rational.call(x)).abs() < 1e-9 * rational.call(x).abs().max(1.)
+        );
+    }
+}
+
+#[test]
+fn partial_fractions_with_roots_at_zero() {
+    // 1/(x² - x) = 1/(x - 1) - 1/x
+    let rational = RationalFunction::try_from(&Function::from("1/(x^2 - x)")).unwrap();
+    let decomposition = rational.partial_fractions().unwrap();
+    assert!(decomposition.polynomial.is_zero());
+    assert_eq!(decomposition.terms.len(), 2);
+    for (term, (expected_coefficient, expected_root)) in
+        decomposition.terms.iter().zip([(-1., 0.), (1., 1.)])
+    {
+        match term {
+            PartialFraction::Linear {
+                coefficient,
+                root,
+                power,
+            } => {
+                assert!((coefficient - expected_coefficient).abs() < 1e-10);
+                assert!((root - expected_root).abs() < 1e-10);
+                assert_eq!(*power, 1);
+            }
+            _ => panic!("expected a linear term, found {term:?}"),
+        }
+    }
+
+    // 1/(x³ + x) = 1/x - x/(x² + 1)
+    let rational = RationalFunction::try_from(&Function::from("1/(x^3 + x)")).unwrap();
+    let decomposition = rational.partial_fractions().unwrap();
+    assert!(decomposition.polynomial.is_zero());
+    assert_eq!(decomposition.terms.len(), 2);
+    for term in &decomposition.terms {
+        match term {
+            PartialFraction::Linear {
+                coefficient,
+                root,
+                power,
+            } => {
+                assert!((coefficient - 1.).abs() < 1e-10);
+                assert!(root.abs() < 1e-10);
+                assert_eq!(*power, 1);
+            }
+            PartialFraction::Quadratic {
+                linear,
+                constant,
+                power,
+                ..
+            } => {
+                assert!((linear + 1.).abs() < 1e-10);
+                assert!(constant.abs() < 1e-10);
+                assert_eq!(*power, 1);
+            }
+        }
+    }
+}
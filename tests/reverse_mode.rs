@@ -0,0 +1,58 @@
+use number_diff::{Elementary, Error, Function, Tape};
+
+#[test]
+fn gradient() {
+    let tape = Tape::record(&Elementary::from("sin(?a*x)*e^?b + ln(?c)/?a"));
+    assert_eq!(tape.variables(), ["a", "b", "c", "x"]);
+
+    let (a, b, c, x) = (0.7_f64, -0.4_f64, 2.5_f64, 1.3_f64);
+    let (value, gradient) = tape.value_and_gradient(&[a, b, c, x]).unwrap();
+
+    assert!((value - ((a * x).sin() * b.exp() + c.ln() / a)).abs() < 1e-12);
+    let expected = [
+        x * (a * x).cos() * b.exp() - c.ln() / (a * a),
+        (a * x).sin() * b.exp(),
+        1. / (c * a),
+        a * (a * x).cos() * b.exp(),
+    ];
+    for (partial, expected) in gradient.iter().zip(expected) {
+        assert!((partial - expected).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn derivative_with_respect_to_x() {
+    // with x as the only variable, the gradient is the derivative
+    for function in [
+        "tan(x^2)",
+        "x^x",
+        "atan(x)*cosh(x)",
+        "x!/abs(x - 3)",
+        "ln(x^2 + 1)",
+    ] {
+        let tape = Tape::record(&Elementary::from(function));
+        let (_, derivative) = Function::from(function).value_and_derivative(1.7);
+        let (_, gradient) = tape.value_and_gradient(&[1.7]).unwrap();
+        assert!((gradient[0] - derivative).abs() < 1e-9 * derivative.abs().max(1.));
+    }
+}
+
+#[test]
+fn loss_function() {
+    // the squared error of the model ?a*x^2 + ?b*x + ?c at the points (0, 1), (1, 3) and (2, 9)
+    let loss = Elementary::from("(?c - 1)^2 + (?a + ?b + ?c - 3)^2 + (?a*4 + ?b*2 + ?c - 9)^2");
+    let tape = loss.record_tape();
+
+    // the exact fit 2x² + 0x + 1 is a minimum
+    let (value, gradient) = tape.value_and_gradient(&[2., 0., 1.]).unwrap();
+    assert_eq!(value, 0.);
+    assert_eq!(gradient, vec![0., 0., 0.]);
+
+    // identical subexpressions are recorded once
+    assert_eq!(Tape::record(&Elementary::from("sin(?a)*sin(?a)")).len(), 3);
+
+    assert!(matches!(
+        tape.evaluate(&[1., 2.]),
+        Err(Error::InputError(_))
+    ));
+}
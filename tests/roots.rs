@@ -0,0 +1,185 @@
+use std::f64::consts::PI;
+
+use number_diff::{Complex, Function, Polynomial, Root};
+
+#[test]
+fn brent() {
+    let function = Function::from("x^3 + 4x^2 - 10");
+    let root = function.find_root_brent(1., 2., 1e-14).unwrap();
+    assert!((root - 1.3652300134140969).abs() < 1e-12);
+
+    let cos = Function::from("cos(x)");
+    let root = cos.find_root_brent(0., 3., 1e-14).unwrap();
+    assert!((root - PI / 2.).abs() < 1e-12);
+
+    // the function must change sign on the interval
+    let square = Function::from("x^2 + 1");
+    assert!(square.find_root_brent(-1., 1., 1e-12).is_err());
+}
+
+#[test]
+fn newton() {
+    let function = Function::from("x^2 - 2");
+    let root = function.find_root_newton(1., 1e-14).unwrap();
+    assert!((root - 2_f64.sqrt()).abs() < 1e-12);
+
+    let transcendental = Function::from("e^x - 3x");
+    let root = transcendental.find_root_newton(0., 1e-14).unwrap();
+    assert!(transcendental.call(root).abs() < 1e-12);
+
+    // the derivative of x² + 1 vanishes at x = 0
+    let no_roots = Function::from("x^2 + 1");
+    assert!(no_roots.find_root_newton(0., 1e-12).is_err());
+}
+
+#[test]
+fn all_roots() {
+    let sin = Function::from("sin(x)");
+    let roots = sin.find_all_roots(-1., 10.).unwrap();
+    assert_eq!(roots.len(), 4);
+    for (i, root) in roots.iter().enumerate() {
+        assert!((root.value - i as f64 * PI).abs() < 1e-10);
+        assert_eq!(root.multiplicity, 1);
+    }
+
+    // double roots do not change the sign of the function
+    let double_root = Function::from("(x - 1)^2");
+    let roots = double_root.find_all_roots(-3., 3.).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert!((roots[0].value - 1.).abs() < 1e-10);
+    assert_eq!(roots[0].multiplicity, 2);
+
+    let triple_root = Function::from("(x + 0.5)^3 * (x - 2)");
+    let roots = triple_root.find_all_roots(-3., 3.).unwrap();
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].value + 0.5).abs() < 1e-8);
+    assert_eq!(roots[0].multiplicity, 3);
+    assert!((roots[1].value - 2.).abs() < 1e-10);
+    assert_eq!(roots[1].multiplicity, 1);
+
+    // the sign change of 1/x at x = 0 is not a root
+    let reciprocal = Function::from("1/x");
+    assert!(reciprocal.find_all_roots(-1., 1.).unwrap().is_empty());
+
+    // every number is a root of a function that is identically zero
+    assert!(Function::from("0*x").find_all_roots(-1., 1.).is_err());
+    assert!(Function::from("sin(0*x)").find_all_roots(-1., 1.).is_err());
+}
+
+#[test]
+fn polynomial_roots() {
+    // (x - 1)² touches the x-axis without crossing it
+    let double_root = Polynomial::new(vec![1., -2., 1.]);
+    assert_eq!(
+        double_root.real_roots(-10., 10.).unwrap(),
+        vec![Root {
+            value: 1.,
+            multiplicity: 2
+        }]
+    );
+
+    // (x - 1)³(x + 2)²(x - 0.5)
+    let polynomial = Polynomial::new(vec![-1., 1.])
+        * Polynomial::new(vec![-1., 1.])
+        * Polynomial::new(vec![-1., 1.])
+        * Polynomial::new(vec![2., 1.])
+        * Polynomial::new(vec![2., 1.])
+        * Polynomial::new(vec![-0.5, 1.]);
+    let roots = polynomial.real_roots(-5., 5.).unwrap();
+    let expected = [(-2., 2), (0.5, 1), (1., 3)];
+    assert_eq!(roots.len(), expected.len());
+    for (root, (value, multiplicity)) in roots.iter().zip(expected) {
+        assert!((root.value - value).abs() < 1e-12);
+        assert_eq!(root.multiplicity, multiplicity);
+    }
+
+    // roots at the ends of the interval are included, while those just outside of it are not
+    assert_eq!(polynomial.real_roots(0.5, 1.).unwrap().len(), 2);
+    let simple = Polynomial::new(vec![-1.0000001, 1.]);
+    assert!(simple.real_roots(0., 1.).unwrap().is_empty());
+    assert!(simple.real_roots(1.0000002, 2.).unwrap().is_empty());
+    assert!(Polynomial::zero().real_roots(0., 1.).is_err());
+
+    // Sturm's theorem counts distinct roots
+    assert_eq!(polynomial.count_real_roots(-5., 5.), 3);
+    assert_eq!(polynomial.count_real_roots(0., 5.), 2);
+
+    // closely spaced roots are separated (although they are sensitive to rounding errors in the
+    // coefficients)
+    let close = Polynomial::new(vec![-1., 1.]) * Polynomial::new(vec![-1.0001, 1.]);
+    let roots = close.real_roots(0., 2.).unwrap();
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].value - 1.).abs() < 1e-10);
+    assert!((roots[1].value - 1.0001).abs() < 1e-10);
+
+    // roots that are close enough for the square-free factorization to merge them into a double
+    // root are told apart by the Taylor coefficients around it, while (x - 1)² above is not split
+    let closer = Function::from("(x - 1)*(x - 1.0000001)");
+    let roots = closer.find_all_roots(0., 2.).unwrap();
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].value - 1.).abs() < 1e-8);
+    assert!((roots[1].value - 1.0000001).abs() < 1e-8);
+    assert!(roots.iter().all(|root| root.multiplicity == 1));
+
+    // find_all_roots uses the exact method for polynomials
+    let function = Function::from("(x - 1)^2*(x^2 + 1)");
+    let roots = function.find_all_roots(-3., 3.).unwrap();
+    assert_eq!(roots.len(), 1);
+    assert_eq!(roots[0].multiplicity, 2);
+    assert!((roots[0].value - 1.).abs() < 1e-12);
+}
+
+#[test]
+fn complex_roots() {
+    // x³ - 1 has the roots 1 and (-1 ± √3i)/2
+    let polynomial = Polynomial::new(vec![-1., 0., 0., 1.]);
+    let roots = polynomial.complex_roots().unwrap();
+    let expected = [
+        Complex::new(-0.5, -(3_f64.sqrt()) / 2.),
+        Complex::new(-0.5, 3_f64.sqrt() / 2.),
+        Complex::new(1., 0.),
+    ];
+    assert_eq!(roots.len(), 3);
+    for (root, value) in roots.iter().zip(expected) {
+        assert!((root.value - value).abs() < 1e-12);
+        assert_eq!(root.multiplicity, 1);
+    }
+
+    // (x² + 1)² has the double roots ±i
+    let function = Function::from("(x^2 + 1)^2");
+    let roots = function.find_complex_roots().unwrap();
+    assert_eq!(roots.len(), 2);
+    for (root, im) in roots.iter().zip([-1., 1.]) {
+        assert!((root.value - Complex::new(0., im)).abs() < 1e-12);
+        assert_eq!(root.multiplicity, 2);
+    }
+
+    assert!(Function::from("sin(x)").find_complex_roots().is_err());
+}
+
+#[test]
+fn complex_roots_at_zero() {
+    // x² - 5x and x³ - 5x² + 6x
+    for (coefficients, expected) in [
+        (vec![0., -5., 1.], vec![0., 5.]),
+        (vec![0., 6., -5., 1.], vec![0., 2., 3.]),
+    ] {
+        let roots = Polynomial::new(coefficients).complex_roots().unwrap();
+        assert_eq!(roots.len(), expected.len());
+        for (root, value) in roots.iter().zip(expected) {
+            assert!((root.value - Complex::new(value, 0.)).abs() < 1e-12);
+            assert_eq!(root.multiplicity, 1);
+        }
+    }
+
+    // x²(x + 1) has a double root at 0
+    let roots = Polynomial::new(vec![0., 0., 1., 1.])
+        .complex_roots()
+        .unwrap();
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].value - Complex::new(-1., 0.)).abs() < 1e-12);
+    assert_eq!(
+        (roots[1].value, roots[1].multiplicity),
+        (Complex::new(0., 0.), 2)
+    );
+}
@@ -0,0 +1,111 @@
+use std::{f64::consts::E, sync::Arc};
+
+use number_diff::{Dual, Elementary, Elementary::*, Error, Function, Rule, RuleSet};
+
+#[test]
+fn parsing() {
+    let rule = Rule::parse("sin(?a)^2 + cos(?a)^2 -> 1").unwrap();
+    let square = |func: Elementary| Pow(Arc::new(func), Arc::new(Con(2.)));
+    let wildcard = Arc::new(Wildcard(String::from("a")));
+    assert_eq!(
+        rule.pattern(),
+        &Add(
+            Arc::new(square(Cos(wildcard.clone()))),
+            Arc::new(square(Sin(wildcard)))
+        )
+    );
+    assert_eq!(rule.replacement(), &Con(1.));
+
+    // the replacement cannot introduce new wildcards
+    assert!(Rule::parse("sin(?a) -> ?b").is_err());
+    assert!(Rule::parse("sin(?a)").is_err());
+}
+
+#[test]
+fn matching() {
+    let rule = Rule::parse("?a - ?a -> 0").unwrap();
+    // the same wildcard must match the same subexpression
+    let bindings = rule.matches(&Elementary::from("sin(x) - sin(x)")).unwrap();
+    assert_eq!(bindings["a"], Sin(Arc::new(X)));
+    assert!(rule.matches(&Elementary::from("sin(x) - cos(x)")).is_none());
+
+    // multiplication is matched commutatively
+    let rule = Rule::parse("?c * x -> x").unwrap();
+    assert_eq!(rule.apply(&Elementary::from("2*x")), Some(X));
+    assert_eq!(rule.apply(&Elementary::from("x*2")), Some(X));
+    // only the expression as a whole is matched
+    assert_eq!(rule.apply(&Elementary::from("sin(2*x)")), None);
+}
+
+#[test]
+fn rewriting() {
+    let rules = RuleSet::parse(&["sin(?a)^2 + cos(?a)^2 -> 1"]).unwrap();
+
+    // rules are applied to subexpressions
+    let function = Elementary::from("ln(sin(x^2)^2 + cos(x^2)^2)");
+    assert_eq!(
+        function.rewrite(&rules).unwrap(),
+        Log(Arc::new(Con(E)), Arc::new(Con(1.)))
+    );
+
+    // rules are applied until no more rules apply
+    let rules = RuleSet::parse(&["sin(?a) -> cos(?a)", "cos(?a) -> tan(?a)"]).unwrap();
+    let mut function = Function::from("sin(sin(x))");
+    function.rewrite(&rules).unwrap();
+    assert_eq!(function.elementary(), Tan(Arc::new(Tan(Arc::new(X)))));
+}
+
+#[test]
+fn conditions() {
+    // ?a/?a = 1 is only valid for non-zero constants
+    let mut rule = Rule::parse("?a/?a -> 1").unwrap();
+    rule.set_condition(|bindings| {
+        bindings["a"].is_constant() && bindings["a"].clone().call()(0.) != 0.
+    });
+    let mut rules = RuleSet::new();
+    rules.add_rule(rule);
+
+    assert_eq!(
+        Elementary::from("x + 3/3").rewrite(&rules).unwrap(),
+        Add(Arc::new(Con(1.)), Arc::new(X))
+    );
+    let quotient = Elementary::from("x/x");
+    assert_eq!(quotient.rewrite(&rules).unwrap(), quotient);
+}
+
+#[test]
+fn termination() {
+    // cyclic rules
+    let rules = RuleSet::parse(&["sin(?a) -> cos(?a)", "cos(?a) -> sin(?a)"]).unwrap();
+    assert!(Elementary::from("sin(x)").rewrite(&rules).is_err());
+
+    // rules that grow the expression indefinitely
+    let rules = RuleSet::parse(&["?a -> ?a + 0"]).unwrap();
+    assert!(Elementary::from("x").rewrite(&rules).is_err());
+
+    let mut rules = RuleSet::parse(&["?a -> sin(?a)"]).unwrap();
+    rules.set_max_size(usize::MAX).set_max_passes(5);
+    assert!(Elementary::from("x").rewrite(&rules).is_err());
+}
+
+#[test]
+fn patterns_have_no_value() {
+    let pattern = Elementary::from("sin(?a) + x");
+    assert!(matches!(
+        pattern.clone().derivative(),
+        Err(Error::InputError(_))
+    ));
+    assert!(pattern.try_call(1.).is_err());
+}
+
+#[test]
+#[should_panic(expected = "The wildcard ?a has no value")]
+fn patterns_cannot_be_called() {
+    Elementary::from("sin(?a) + x").call()(1.);
+}
+
+#[test]
+#[should_panic(expected = "The wildcard ?a has no value")]
+fn patterns_cannot_be_differentiated_in_forward_mode() {
+    Elementary::from("?a * x").call_dual(Dual::variable(1.));
+}
@@ -0,0 +1,142 @@
+use std::ops::{Add, Div, Mul, Sub};
+
+use number_diff::{Dual, Elementary, Function, Interval, Jet, Scalar};
+
+const FUNCTIONS: [&str; 8] = [
+    "sin(x)*cos(x) + tan(x/4)",
+    "sec(x) - csc(x) + cot(x)",
+    "atan(x) + asin(x/5) - acos(x/5)",
+    "sinh(x)/cosh(x) - tanh(x)",
+    "e^x + ln(x^2 + 1)",
+    "x^3 - 2/x + x^(1/3)",
+    "(x^2 + 1)^x",
+    "abs(x - 1) + ln(x^2)",
+];
+
+#[test]
+fn matches_call() {
+    for name in FUNCTIONS {
+        let call = Elementary::from(name).call();
+        let function = Function::from(name);
+        for x in [-2.5, -0.3, 0.7, 1.9] {
+            let expected = call(x);
+            let value: f64 = function.eval(x);
+            assert!(
+                (value - expected).abs() < 1e-12 * expected.abs().max(1.)
+                    || value.is_nan() && expected.is_nan(),
+                "{name} at {x}: {value} != {expected}"
+            );
+
+            let single: f32 = function.eval(x as f32);
+            assert!(
+                (single as f64 - expected).abs() < 1e-4 * expected.abs().max(1.)
+                    || expected.is_nan(),
+                "{name} at {x}: {single} != {expected}"
+            );
+        }
+    }
+}
+
+#[test]
+fn forward_mode() {
+    let function = Function::from("x^2*sin(x)");
+
+    let dual = function.eval(Dual::variable(2.));
+    assert_eq!(dual, function.elementary().call_dual(Dual::variable(2.)));
+
+    let jet = function.eval(Jet::variable(2., 3));
+    assert_eq!(jet.derivatives(), function.value_and_derivatives(2., 3));
+}
+
+#[test]
+fn intervals() {
+    let function = Function::from("x^2 - 3*x + sin(x)");
+    let enclosure = function.eval(Interval::new(-1., 2.));
+    for i in 0..=100 {
+        let x = -1. + 3. * i as f64 / 100.;
+        assert!(enclosure.contains(function.call(x)));
+    }
+}
+
+// a user-defined number type that only implements the functions without a default
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Wrapped(f64);
+
+impl Add for Wrapped {
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Wrapped(self.0 + rhs.0)
+    }
+}
+impl Sub for Wrapped {
+    type Output = Self;
+    fn sub(self, rhs: Self) -> Self {
+        Wrapped(self.0 - rhs.0)
+    }
+}
+impl Mul for Wrapped {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self {
+        Wrapped(self.0 * rhs.0)
+    }
+}
+impl Div for Wrapped {
+    type Output = Self;
+    fn div(self, rhs: Self) -> Self {
+        Wrapped(self.0 / rhs.0)
+    }
+}
+
+impl Scalar for Wrapped {
+    fn lift(&self, value: f64) -> Self {
+        Wrapped(value)
+    }
+    fn sin(&self) -> Self {
+        Wrapped(self.0.sin())
+    }
+    fn cos(&self) -> Self {
+        Wrapped(self.0.cos())
+    }
+    fn asin(&self) -> Self {
+        Wrapped(self.0.asin())
+    }
+    fn acos(&self) -> Self {
+        Wrapped(self.0.acos())
+    }
+    fn atan(&self) -> Self {
+        Wrapped(self.0.atan())
+    }
+    fn exp(&self) -> Self {
+        Wrapped(self.0.exp())
+    }
+    fn ln(&self) -> Self {
+        Wrapped(self.0.ln())
+    }
+    fn powf(&self, exp: f64) -> Self {
+        Wrapped(self.0.powf(exp))
+    }
+    fn gamma(&self) -> Self {
+        Wrapped(f64::NAN)
+    }
+    fn polygamma(&self, _: usize) -> Self {
+        Wrapped(f64::NAN)
+    }
+    fn abs(&self) -> Self {
+        Wrapped(self.0.abs())
+    }
+}
+
+#[test]
+fn user_types() {
+    for name in FUNCTIONS {
+        let function = Function::from(name);
+        for x in [0.7, 1.9] {
+            let expected = function.call(x);
+            let Wrapped(value) = function.eval(Wrapped(x));
+            assert!(
+                (value - expected).abs() < 1e-12 * expected.abs().max(1.),
+                "{name} at {x}: {value} != {expected}"
+            );
+        }
+    }
+}
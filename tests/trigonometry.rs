@@ -0,0 +1,87 @@
+use std::{f64::consts::PI, sync::Arc};
+
+use number_diff::{Elementary::*, Function};
+
+fn simplify(function: &str) -> number_diff::Elementary {
+    Function::from(function).elementary().simplify().unwrap()
+}
+
+#[test]
+fn identities() {
+    assert_eq!(simplify("sin(x)^2 + cos(x)^2"), Con(1.));
+    assert_eq!(
+        simplify("1 + tan(x)^2"),
+        Pow(Arc::new(Sec(Arc::new(X))), Arc::new(Con(2.)))
+    );
+    assert_eq!(
+        simplify("1 - cos(x)^2"),
+        Pow(Arc::new(Sin(Arc::new(X))), Arc::new(Con(2.)))
+    );
+
+    // reciprocals
+    assert_eq!(simplify("1/cos(x)"), Sec(Arc::new(X)));
+    assert_eq!(simplify("sin(x)/cos(x)"), Tan(Arc::new(X)));
+    assert_eq!(simplify("cos(x)/sin(x)"), Cot(Arc::new(X)));
+
+    // double angle
+    let double = Arc::new(Mul(Arc::new(Con(2.)), Arc::new(X)));
+    assert_eq!(simplify("cos(x)^2 - sin(x)^2"), Cos(double.clone()));
+    assert_eq!(
+        simplify("sin(x)*cos(x)"),
+        Mul(Arc::new(Con(0.5)), Arc::new(Sin(double.clone())))
+    );
+
+    // sin(3x) + sin(x) = 2sin(2x)cos(x)
+    let sum = simplify("sin(3x) + sin(x)");
+    assert_eq!(
+        sum,
+        Mul(
+            Arc::new(Mul(Arc::new(Con(2.)), Arc::new(Sin(double)))),
+            Arc::new(Cos(Arc::new(X)))
+        )
+    );
+}
+
+#[test]
+fn exact_values() {
+    assert_eq!(simplify("sin(pi)"), Con(0.));
+    assert_eq!(simplify("cos(pi)"), Con(-1.));
+
+    let at = |function: fn(Arc<number_diff::Elementary>) -> number_diff::Elementary, x: f64| {
+        function(Arc::new(Con(x))).simplify().unwrap()
+    };
+    assert_eq!(at(Sin, PI / 6.), Con(0.5));
+    assert_eq!(at(Cos, 2. * PI / 3.), Con(-0.5));
+    assert_eq!(at(Tan, PI / 4.), Con(1.));
+    assert_eq!(at(Cos, PI / 2.), Con(0.));
+}
+
+#[test]
+fn readable_derivatives() {
+    let derivative = |function: &str| Function::from(function).elementary().derivative().unwrap();
+
+    assert_eq!(derivative("sin(x)"), Cos(Arc::new(X)));
+    assert_eq!(
+        derivative("tan(x)"),
+        Pow(Arc::new(Sec(Arc::new(X))), Arc::new(Con(2.)))
+    );
+    assert_eq!(derivative("sin(x)^2 + cos(x)^2"), Con(0.));
+    assert_eq!(
+        derivative("sin(x)*cos(x)"),
+        Cos(Arc::new(Mul(Arc::new(Con(2.)), Arc::new(X))))
+    );
+}
+
+#[test]
+fn to_sin_cos() {
+    let function = Function::from("tan(x) + cot(x)").elementary().to_sin_cos();
+    let sin = Arc::new(Sin(Arc::new(X)));
+    let cos = Arc::new(Cos(Arc::new(X)));
+    assert_eq!(
+        function,
+        Add(
+            Arc::new(Div(cos.clone(), sin.clone())),
+            Arc::new(Div(sin, cos))
+        )
+    );
+}